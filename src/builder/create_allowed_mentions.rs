@@ -0,0 +1,151 @@
+use crate::model::id::{RoleId, UserId};
+use serde_json::Value;
+use std::default::Default;
+use crate::utils::VecMap;
+
+/// A type of mention which Discord can parse from the content of a message,
+/// for use with [`CreateAllowedMentions::parse`].
+///
+/// [`CreateAllowedMentions::parse`]: struct.CreateAllowedMentions.html#method.parse
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ParseValue {
+    /// Allows `@everyone` and `@here` mentions to ping.
+    Everyone,
+    /// Allows all role mentions to ping.
+    Roles,
+    /// Allows all user mentions to ping.
+    Users,
+}
+
+impl ParseValue {
+    fn name(&self) -> &'static str {
+        match *self {
+            ParseValue::Everyone => "everyone",
+            ParseValue::Roles => "roles",
+            ParseValue::Users => "users",
+        }
+    }
+}
+
+/// A builder to control which mentions in a message will ping, for use with
+/// [`CreateMessage::allowed_mentions`] and
+/// [`ExecuteWebhook::allowed_mentions`].
+///
+/// By default, no mentions will ping: each kind of mention must be explicitly
+/// allowed. This should be used whenever content from users is echoed.
+///
+/// **Note**: Discord rejects a message which both [parses][`parse`] a type of
+/// mention and lists explicit [`users`] or [`roles`] of the same type.
+///
+/// # Examples
+///
+/// Sending a message where only the mention of a single user will ping:
+///
+/// ```rust,no_run
+/// use serenity::model::id::{ChannelId, UserId};
+///
+/// let _ = ChannelId(7).send_message(|m| m
+///     .content("<@114941315417899012> @everyone")
+///     .allowed_mentions(|am| am.users(vec![UserId(114941315417899012)])));
+/// ```
+///
+/// [`CreateMessage::allowed_mentions`]: struct.CreateMessage.html#method.allowed_mentions
+/// [`ExecuteWebhook::allowed_mentions`]: struct.ExecuteWebhook.html#method.allowed_mentions
+/// [`parse`]: #method.parse
+/// [`roles`]: #method.roles
+/// [`users`]: #method.users
+#[derive(Clone, Debug)]
+pub struct CreateAllowedMentions(pub VecMap<&'static str, Value>);
+
+impl CreateAllowedMentions {
+    /// Allows all mentions of the given type to ping.
+    ///
+    /// This may be called multiple times to allow multiple types.
+    pub fn parse(mut self, value: ParseValue) -> Self {
+        {
+            let entry = self.0
+                .entry("parse")
+                .or_insert_with(|| Value::Array(vec![]));
+
+            if let Value::Array(ref mut inner) = *entry {
+                let name = Value::String(value.name().to_string());
+
+                if !inner.contains(&name) {
+                    inner.push(name);
+                }
+            }
+        }
+
+        self
+    }
+
+    /// Sets the roles whose mentions will ping.
+    pub fn roles<R: Into<RoleId>, It: IntoIterator<Item=R>>(mut self, roles: It) -> Self {
+        let roles = roles
+            .into_iter()
+            .map(|role| Value::String(role.into().0.to_string()))
+            .collect();
+
+        self.0.insert("roles", Value::Array(roles));
+
+        self
+    }
+
+    /// Sets the users whose mentions will ping.
+    pub fn users<U: Into<UserId>, It: IntoIterator<Item=U>>(mut self, users: It) -> Self {
+        let users = users
+            .into_iter()
+            .map(|user| Value::String(user.into().0.to_string()))
+            .collect();
+
+        self.0.insert("users", Value::Array(users));
+
+        self
+    }
+
+    /// Sets whether the author of the message being replied to will be pinged.
+    ///
+    /// Defaults to `false`.
+    pub fn replied_user(mut self, replied_user: bool) -> Self {
+        self.0.insert("replied_user", Value::Bool(replied_user));
+
+        self
+    }
+}
+
+impl Default for CreateAllowedMentions {
+    /// Creates a builder which allows no mentions to ping, setting `parse` to
+    /// an empty list.
+    fn default() -> CreateAllowedMentions {
+        let mut map = VecMap::new();
+        map.insert("parse", Value::Array(vec![]));
+
+        CreateAllowedMentions(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::id::UserId;
+    use super::{CreateAllowedMentions, ParseValue};
+    use crate::utils;
+
+    #[test]
+    fn test_allowed_mentions() {
+        let empty = utils::vecmap_to_json_map(CreateAllowedMentions::default().0);
+        assert_eq!(json!(empty), json!({ "parse": [] }));
+
+        let builder = CreateAllowedMentions::default()
+            .parse(ParseValue::Roles)
+            .parse(ParseValue::Roles)
+            .users(vec![UserId(1)])
+            .replied_user(true);
+        let map = utils::vecmap_to_json_map(builder.0);
+
+        assert_eq!(json!(map), json!({
+            "parse": ["roles"],
+            "replied_user": true,
+            "users": ["1"],
+        }));
+    }
+}
@@ -15,14 +15,20 @@
 //! [`ExecuteWebhook::embeds`]: struct.ExecuteWebhook.html#method.embeds
 //! [here]: https://discordapp.com/developers/docs/resources/channel#embed-object
 
-use chrono::{DateTime, TimeZone};
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use crate::constants;
 use crate::internal::prelude::*;
-use crate::model::channel::Embed;
+use crate::model::{
+    channel::Embed,
+    error::EmbedLimit,
+    ModelError,
+};
 use serde_json::Value;
 use std::{
     default::Default,
     fmt::Display
 };
+use super::validate::{self, BuilderError, Validate};
 use crate::utils::{self, VecMap};
 
 #[cfg(feature = "utils")]
@@ -48,7 +54,36 @@ impl CreateEmbed {
     /// Refer to the documentation for [`CreateEmbedAuthor`] for more
     /// information.
     ///
+    /// **Note**: The author's name is limited to 256 unicode code points,
+    /// which is checked by [`validate`].
+    ///
+    /// # Examples
+    ///
+    /// Execute a webhook with an embed linking back to the user who posted
+    /// it:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http;
+    /// use serenity::model::channel::Embed;
+    ///
+    /// let id = 245037420704169985;
+    /// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    ///
+    /// let webhook = http::get_webhook_with_token(id, token)
+    ///     .expect("valid webhook");
+    ///
+    /// let embed = Embed::fake(|e| e
+    ///     .author(|a| a
+    ///         .name("Posted by @zeyla")
+    ///         .url("https://github.com/serenity-rs/serenity")
+    ///         .icon_url("https://i.imgur.com/XfWpfCV.gif"))
+    ///     .description("Check out the new release!"));
+    ///
+    /// let _ = webhook.execute(false, |w| w.embeds(vec![embed]));
+    /// ```
+    ///
     /// [`CreateEmbedAuthor`]: struct.CreateEmbedAuthor.html
+    /// [`validate`]: #method.validate
     pub fn author<F>(mut self, f: F) -> Self
         where F: FnOnce(CreateEmbedAuthor) -> CreateEmbedAuthor {
         let map = utils::vecmap_to_json_map(f(CreateEmbedAuthor::default()).0);
@@ -180,6 +215,18 @@ impl CreateEmbed {
         self
     }
 
+    /// Set the footer of the embed to the given text and icon.
+    ///
+    /// This is sugar for calling [`footer`] with a [`CreateEmbedFooter`] that
+    /// has both its text and icon URL set.
+    ///
+    /// [`CreateEmbedFooter`]: struct.CreateEmbedFooter.html
+    /// [`footer`]: #method.footer
+    #[inline]
+    pub fn footer_with_icon<D: Display>(self, text: D, icon_url: &str) -> Self {
+        self.footer(|f| f.text(text).icon_url(icon_url))
+    }
+
     fn url_object(mut self, name: &'static str, url: &str) -> Self {
         let obj = json!({
             "url": url.to_string()
@@ -190,7 +237,10 @@ impl CreateEmbed {
         self
     }
 
-    /// Set the image associated with the embed. This only supports HTTP(S).
+    /// Set the image associated with the embed. This only supports HTTP(S) and
+    /// `attachment://` URLs, which is checked by [`validate`].
+    ///
+    /// [`validate`]: #method.validate
     #[inline]
     pub fn image<S: AsRef<str>>(self, url: S) -> Self {
         self._image(url.as_ref())
@@ -200,7 +250,10 @@ impl CreateEmbed {
         self.url_object("image", url)
     }
 
-    /// Set the thumbnail of the embed. This only supports HTTP(S).
+    /// Set the thumbnail of the embed. This only supports HTTP(S) and
+    /// `attachment://` URLs, which is checked by [`validate`].
+    ///
+    /// [`validate`]: #method.validate
     #[inline]
     pub fn thumbnail<S: AsRef<str>>(self, url: S) -> Self {
         self._thumbnail(url.as_ref())
@@ -218,10 +271,19 @@ impl CreateEmbed {
     /// - `2004-06-08T16:04:23`
     /// - `2004-06-08T16:04:23`
     ///
-    /// This timestamp must be in ISO-8601 format. It must also be in UTC format.
+    /// This timestamp must be in ISO-8601 format. A timestamp without an
+    /// offset is assumed to be in UTC.
     ///
-    /// You can also pass an instance of `chrono::DateTime<Utc>`, 
-    /// which will construct the timestamp string out of it.
+    /// You can also pass an instance - or a reference to an instance - of
+    /// `chrono::DateTime`, which will construct the timestamp string out of it.
+    ///
+    /// **Note**: The timestamp is validated before being set. If it is not in
+    /// a valid ISO-8601 format, then it is set as given and reported by
+    /// [`validate`], and sending the embed returns a
+    /// [`ModelError::InvalidEmbedTimestamp`] without making a request.
+    ///
+    /// [`ModelError::InvalidEmbedTimestamp`]: ../model/error/enum.Error.html#variant.InvalidEmbedTimestamp
+    /// [`validate`]: #method.validate
     ///
     /// # Examples
     ///
@@ -305,7 +367,16 @@ impl CreateEmbed {
     }
 
     fn _timestamp(mut self, timestamp: Timestamp) -> Self {
-        self.0.insert("timestamp", Value::String(timestamp.ts));
+        let ts = match timestamp.normalize() {
+            Some(ts) => ts,
+            None => {
+                warn!("Invalid embed timestamp: {}", timestamp.ts);
+
+                timestamp.ts
+            },
+        };
+
+        self.0.insert("timestamp", Value::String(ts));
 
         self
     }
@@ -350,6 +421,171 @@ impl CreateEmbed {
     fn _attachment(self, filename: &str) -> Self {
         self.image(&format!("attachment://{}", filename))
     }
+
+    /// Checks the embed against Discord's limits on the number of fields and
+    /// the lengths of its parts, and that its image URLs can be displayed.
+    ///
+    /// The builder methods do not check these limits themselves, so this can
+    /// be called before sending to catch an embed that Discord would reject
+    /// or display incompletely.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmbedLimitExceeded`] for the first part found
+    /// to be over its limit, containing the number of fields or unicode code
+    /// points over the limit.
+    ///
+    /// Returns a [`ModelError::InvalidEmbedUrl`] if the URL of the image,
+    /// thumbnail, author icon, or footer icon is not an `http://`,
+    /// `https://`, or `attachment://` URL.
+    ///
+    /// Returns a [`ModelError::InvalidEmbedTimestamp`] if the timestamp is not
+    /// in ISO-8601 format.
+    ///
+    /// [`ModelError::EmbedLimitExceeded`]: ../model/error/enum.Error.html#variant.EmbedLimitExceeded
+    /// [`ModelError::InvalidEmbedTimestamp`]: ../model/error/enum.Error.html#variant.InvalidEmbedTimestamp
+    /// [`ModelError::InvalidEmbedUrl`]: ../model/error/enum.Error.html#variant.InvalidEmbedUrl
+    pub fn validate(&self) -> Result<()> {
+        match self.problems().into_iter().next() {
+            Some((_, why)) => Err(Error::Model(why)),
+            None => Ok(()),
+        }
+    }
+
+    /// Collects every part of the embed that Discord would reject or not
+    /// display, along with the name of the field it is in.
+    fn problems(&self) -> Vec<(&'static str, ModelError)> {
+        let mut problems = Vec::new();
+
+        for &(key, field, name) in &[
+            ("author", "icon_url", "author.icon_url"),
+            ("footer", "icon_url", "footer.icon_url"),
+            ("image", "url", "image.url"),
+            ("thumbnail", "url", "thumbnail.url"),
+        ] {
+            if let Some(Value::Object(object)) = self.0.get(&key) {
+                problems.extend(check_url(object.get(field)).map(|why| (name, why)));
+            }
+        }
+
+        if let Some(Value::Object(author)) = self.0.get(&"author") {
+            problems.extend(check_length(
+                EmbedLimit::AuthorName,
+                author.get("name"),
+                constants::EMBED_AUTHOR_NAME_LIMIT,
+            ).map(|why| ("author.name", why)));
+        }
+
+        problems.extend(check_length(
+            EmbedLimit::Title,
+            self.0.get(&"title"),
+            constants::EMBED_TITLE_LIMIT,
+        ).map(|why| ("title", why)));
+        problems.extend(check_length(
+            EmbedLimit::Description,
+            self.0.get(&"description"),
+            constants::EMBED_DESCRIPTION_LIMIT,
+        ).map(|why| ("description", why)));
+
+        if let Some(Value::Object(footer)) = self.0.get(&"footer") {
+            problems.extend(check_length(
+                EmbedLimit::Footer,
+                footer.get("text"),
+                constants::EMBED_FOOTER_LIMIT,
+            ).map(|why| ("footer.text", why)));
+        }
+
+        problems.extend(check_timestamp(self.0.get(&"timestamp")).map(|why| ("timestamp", why)));
+
+        if let Some(Value::Array(fields)) = self.0.get(&"fields") {
+            let limit = constants::EMBED_FIELDS_LIMIT as usize;
+
+            if fields.len() > limit {
+                let overflow = (fields.len() - limit) as u64;
+
+                problems.push(("fields", ModelError::EmbedLimitExceeded(EmbedLimit::Fields, overflow)));
+            }
+
+            for field in fields {
+                problems.extend(check_length(
+                    EmbedLimit::FieldName,
+                    field.get("name"),
+                    constants::EMBED_FIELD_NAME_LIMIT,
+                ).map(|why| ("fields.name", why)));
+                problems.extend(check_length(
+                    EmbedLimit::FieldValue,
+                    field.get("value"),
+                    constants::EMBED_FIELD_VALUE_LIMIT,
+                ).map(|why| ("fields.value", why)));
+            }
+        }
+
+        problems
+    }
+}
+
+impl Validate for CreateEmbed {
+    /// Checks the embed like [`CreateEmbed::validate`], but returns every
+    /// part that Discord would reject rather than only the first.
+    ///
+    /// [`CreateEmbed::validate`]: struct.CreateEmbed.html#method.validate
+    fn validate_all(&self) -> StdResult<(), Vec<BuilderError>> {
+        validate::collect(self.problems()
+            .iter()
+            .map(|&(field, ref why)| BuilderError::from_model(field, why))
+            .collect())
+    }
+}
+
+/// Checks that an embed's timestamp, if it has one, is in ISO-8601 format.
+///
+/// This is also used to check embeds right before they are sent, as
+/// [`CreateEmbed::timestamp`] keeps invalid timestamps as-is.
+///
+/// [`CreateEmbed::timestamp`]: struct.CreateEmbed.html#method.timestamp
+pub(crate) fn check_timestamp(value: Option<&Value>) -> Option<ModelError> {
+    match value {
+        Some(Value::String(ts)) if (Timestamp { ts: ts.clone() }).normalize().is_none() => {
+            Some(ModelError::InvalidEmbedTimestamp(ts.clone()))
+        },
+        _ => None,
+    }
+}
+
+fn check_url(value: Option<&Value>) -> Option<ModelError> {
+    match value {
+        Some(Value::String(url)) => {
+            let supported = ["http://", "https://", "attachment://"].iter().any(|scheme| {
+                match url.get(..scheme.len()) {
+                    Some(start) => start.eq_ignore_ascii_case(scheme),
+                    None => false,
+                }
+            });
+
+            if supported {
+                None
+            } else {
+                Some(ModelError::InvalidEmbedUrl(url.clone()))
+            }
+        },
+        _ => None,
+    }
+}
+
+fn check_length(part: EmbedLimit, value: Option<&Value>, limit: u16) -> Option<ModelError> {
+    match value {
+        Some(Value::String(text)) => {
+            let count = text.chars().count() as u64;
+            let limit = u64::from(limit);
+
+            if count > limit {
+                Some(ModelError::EmbedLimitExceeded(part, count - limit))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
 }
 
 impl Default for CreateEmbed {
@@ -519,9 +755,35 @@ impl<'a, Tz: TimeZone> From<&'a DateTime<Tz>> for Timestamp
     }
 }
 
+impl<Tz: TimeZone> From<DateTime<Tz>> for Timestamp
+    where Tz::Offset: Display {
+    fn from(dt: DateTime<Tz>) -> Self {
+        Timestamp::from(&dt)
+    }
+}
+
+impl Timestamp {
+    /// Validates the timestamp as ISO-8601, returning it in RFC 3339 form.
+    ///
+    /// Timestamps without an offset are assumed to be in UTC. Returns `None`
+    /// if the timestamp is invalid.
+    fn normalize(&self) -> Option<String> {
+        if let Ok(dt) = DateTime::parse_from_rfc3339(&self.ts) {
+            return Some(dt.to_rfc3339());
+        }
+
+        NaiveDateTime::parse_from_str(&self.ts, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()
+            .map(|naive| Utc.from_utc_datetime(&naive).to_rfc3339())
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use chrono::DateTime;
+    use crate::internal::prelude::*;
     use crate::model::channel::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedVideo};
+    use crate::model::{error::EmbedLimit, ModelError};
     use serde_json::Value;
     use super::CreateEmbed;
     use crate::utils::{self, Colour};
@@ -606,4 +868,75 @@ mod test {
 
         assert_eq!(built, obj);
     }
+
+    #[test]
+    fn test_timestamp() {
+        let dt = DateTime::parse_from_rfc3339("2004-06-08T16:04:23Z").unwrap();
+        let expected = Value::String("2004-06-08T16:04:23+00:00".to_string());
+
+        let from_str = CreateEmbed::default().timestamp("2004-06-08T16:04:23");
+        let from_dt = CreateEmbed::default().timestamp(dt);
+        let from_ref = CreateEmbed::default().timestamp(&dt);
+        let invalid = CreateEmbed::default().timestamp("yesterday");
+
+        assert_eq!(from_str.0.get(&"timestamp"), Some(&expected));
+        assert_eq!(from_dt.0.get(&"timestamp"), Some(&expected));
+        assert_eq!(from_ref.0.get(&"timestamp"), Some(&expected));
+        assert_eq!(invalid.0.get(&"timestamp"), Some(&Value::String("yesterday".to_string())));
+        assert!(from_str.validate().is_ok());
+        assert!(match invalid.validate() {
+            Err(Error::Model(ModelError::InvalidEmbedTimestamp(ref ts))) => ts == "yesterday",
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_validate() {
+        let embed = CreateEmbed::default()
+            .title("a".repeat(256))
+            .field("name", "v".repeat(1024), false);
+        assert!(embed.validate().is_ok());
+
+        let embed = CreateEmbed::default().title("é".repeat(258));
+        assert!(match embed.validate() {
+            Err(Error::Model(ModelError::EmbedLimitExceeded(EmbedLimit::Title, 2))) => true,
+            _ => false,
+        });
+
+        let embed = CreateEmbed::default().fields((0..26).map(|i| (i, i, true)));
+        assert!(match embed.validate() {
+            Err(Error::Model(ModelError::EmbedLimitExceeded(EmbedLimit::Fields, 1))) => true,
+            _ => false,
+        });
+
+        let embed = CreateEmbed::default().author(|a| a.name(&"n".repeat(257)));
+        assert!(match embed.validate() {
+            Err(Error::Model(ModelError::EmbedLimitExceeded(EmbedLimit::AuthorName, 1))) => true,
+            _ => false,
+        });
+
+        let embed = CreateEmbed::default().footer(|f| f.text("f".repeat(2049)));
+        assert!(match embed.validate() {
+            Err(Error::Model(ModelError::EmbedLimitExceeded(EmbedLimit::Footer, 1))) => true,
+            _ => false,
+        });
+
+        let embed = CreateEmbed::default()
+            .attachment("image.png")
+            .thumbnail("HTTPS://i.imgur.com/XfWpfCV.gif")
+            .author(|a| a.name("a").icon_url("http://i.imgur.com/XfWpfCV.gif"));
+        assert!(embed.validate().is_ok());
+
+        let embed = CreateEmbed::default().footer(|f| f.text("f").icon_url("file:///tmp/a.png"));
+        assert!(match embed.validate() {
+            Err(Error::Model(ModelError::InvalidEmbedUrl(ref url))) => url == "file:///tmp/a.png",
+            _ => false,
+        });
+
+        let embed = CreateEmbed::default().image("data:image/png;base64,AAAA");
+        assert!(match embed.validate() {
+            Err(Error::Model(ModelError::InvalidEmbedUrl(_))) => true,
+            _ => false,
+        });
+    }
 }
@@ -1,6 +1,10 @@
+use crate::constants;
 use crate::internal::prelude::*;
+use crate::model::id::{ApplicationId, UserId};
+use crate::model::invite::InviteTargetType;
 use serde_json::Value;
 use std::default::Default;
+use super::validate::{self, BuilderError, Validate};
 use crate::utils::VecMap;
 
 /// A builder to create a [`RichInvite`] for use via [`GuildChannel::create_invite`].
@@ -63,6 +67,17 @@ use crate::utils::VecMap;
 /// # }
 /// ```
 ///
+/// Inspecting the payload that would be sent, via [`build`]:
+///
+/// ```rust
+/// use serenity::builder::CreateInvite;
+///
+/// let invite = CreateInvite::default().max_age(3600);
+///
+/// assert_eq!(invite.build()["max_age"], 3600);
+/// ```
+///
+/// [`build`]: #method.build
 /// [`GuildChannel::create_invite`]: ../model/channel/struct.GuildChannel.html#method.create_invite
 /// [`RichInvite`]: ../model/invite/struct.RichInvite.html
 #[derive(Clone, Debug)]
@@ -207,6 +222,100 @@ impl CreateInvite {
 
         self
     }
+
+    /// Makes the invite target the stream of a user in the voice channel,
+    /// setting the `target_type` to [`InviteTargetType::Stream`].
+    ///
+    /// The user must be streaming in the channel.
+    ///
+    /// [`InviteTargetType::Stream`]: ../model/invite/enum.InviteTargetType.html#variant.Stream
+    pub fn target_user<U: Into<UserId>>(self, user_id: U) -> Self {
+        self._target("target_user_id", user_id.into().0, InviteTargetType::Stream)
+    }
+
+    /// Makes the invite target an embedded application, launching a voice
+    /// channel activity for those who accept it. This sets the `target_type` to
+    /// [`InviteTargetType::EmbeddedApplication`].
+    ///
+    /// This can be combined with [`max_age`] and [`max_uses`] as normal.
+    ///
+    /// # Examples
+    ///
+    /// Create an invite to an activity which expires after an hour:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{ApplicationId, ChannelId};
+    /// # use serenity::model::channel::Channel;
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// #     let channel = ChannelId(81384788765712384).to_channel().unwrap();
+    /// #
+    /// #     if let Channel::Guild(channel) = channel {
+    /// #         let channel = channel.read();
+    /// #
+    /// let application_id = ApplicationId(880218394199220334);
+    ///
+    /// let invite = channel.create_invite(|i| i
+    ///     .max_age(3600)
+    ///     .target_application(application_id))?;
+    /// #     }
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`InviteTargetType::EmbeddedApplication`]: ../model/invite/enum.InviteTargetType.html#variant.EmbeddedApplication
+    /// [`max_age`]: #method.max_age
+    /// [`max_uses`]: #method.max_uses
+    pub fn target_application<A: Into<ApplicationId>>(self, application_id: A) -> Self {
+        self._target(
+            "target_application_id",
+            application_id.into().0,
+            InviteTargetType::EmbeddedApplication,
+        )
+    }
+
+    fn _target(mut self, key: &'static str, id: u64, kind: InviteTargetType) -> Self {
+        // Only one target can be set at a time.
+        self.0.remove(&"target_application_id");
+        self.0.remove(&"target_user_id");
+
+        self.0.insert(key, Value::Number(Number::from(id)));
+        self.0.insert("target_type", Value::Number(Number::from(kind.num())));
+
+        self
+    }
+}
+
+impl Validate for CreateInvite {
+    /// Checks that the [`max_age`] and [`max_uses`] are within the ranges
+    /// Discord accepts.
+    ///
+    /// [`max_age`]: #method.max_age
+    /// [`max_uses`]: #method.max_uses
+    fn validate_all(&self) -> StdResult<(), Vec<BuilderError>> {
+        let mut errors = Vec::new();
+
+        for &(field, limit, unit) in &[
+            ("max_age", constants::INVITE_MAX_AGE_LIMIT, "seconds"),
+            ("max_uses", constants::INVITE_MAX_USES_LIMIT, "uses"),
+        ] {
+            if let Some(value) = self.0.get(&field).and_then(Value::as_u64) {
+                if value > limit {
+                    let reason = format!("must be at most {} {}", limit, unit);
+
+                    errors.push(BuilderError::new(field, reason));
+                }
+            }
+        }
+
+        validate::collect(errors)
+    }
 }
 
 impl Default for CreateInvite {
@@ -1,7 +1,11 @@
+#[cfg(feature = "http")]
+use crate::http::AttachmentType;
 use crate::internal::prelude::*;
-use crate::model::channel::ReactionType;
+use crate::model::channel::{MessageFlags, ReactionType};
+use crate::model::id::MessageId;
 use std::fmt::Display;
-use super::CreateEmbed;
+use std::path::{Path, PathBuf};
+use super::{CreateAllowedMentions, CreateEmbed};
 use crate::utils::{self, VecMap};
 
 /// A builder to specify the contents of an [`http::send_message`] request,
@@ -34,15 +38,53 @@ use crate::utils::{self, VecMap};
 ///         .description("With a description")));
 /// ```
 ///
+/// Files can be uploaded along with the message via [`add_file`], in which
+/// case the message is sent in a single multipart request.
+///
 /// [`ChannelId::say`]: ../model/id/struct.ChannelId.html#method.say
 /// [`ChannelId::send_message`]: ../model/id/struct.ChannelId.html#method.send_message
+/// [`add_file`]: #method.add_file
 /// [`content`]: #method.content
 /// [`embed`]: #method.embed
 /// [`http::send_message`]: ../http/fn.send_message.html
 #[derive(Clone, Debug)]
-pub struct CreateMessage(pub VecMap<&'static str, Value>, pub Option<Vec<ReactionType>>);
+pub struct CreateMessage(
+    pub VecMap<&'static str, Value>,
+    pub Option<Vec<ReactionType>>,
+    pub Vec<MessageFile>,
+);
 
 impl CreateMessage {
+    /// Adds a file to upload along with the message.
+    ///
+    /// The message's content, embed, and other fields are sent in the same
+    /// request as the files, so an [embed's image] can refer to a file as
+    /// `attachment://{filename}`.
+    ///
+    /// **Note**: The combined size of the files must be under Discord's
+    /// upload limit of 8 MB, or higher in boosted guilds. Otherwise, sending
+    /// the message returns Discord's error response.
+    ///
+    /// # Examples
+    ///
+    /// Sending a chart along with some text:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::ChannelId;
+    /// use std::path::Path;
+    ///
+    /// let _ = ChannelId(7).send_message(|m| m
+    ///     .content("see chart")
+    ///     .add_file(Path::new("/tmp/chart.png")));
+    /// ```
+    ///
+    /// [embed's image]: struct.CreateEmbed.html#method.attachment
+    pub fn add_file<T: Into<MessageFile>>(mut self, file: T) -> Self {
+        self.2.push(file.into());
+
+        self
+    }
+
     /// Set the content of the message.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -57,6 +99,46 @@ impl CreateMessage {
         self
     }
 
+    /// Sets the flags of the message, such as suppressing its embeds.
+    ///
+    /// This replaces any flags that were previously set.
+    pub fn flags(mut self, flags: MessageFlags) -> Self {
+        self.0.remove(&"flags");
+        self.0.insert("flags", Value::Number(Number::from(flags.bits())));
+
+        self
+    }
+
+    /// Sets whether links in the message are shown without their embeds.
+    ///
+    /// This keeps any other [flags] that were set.
+    ///
+    /// [flags]: #method.flags
+    pub fn suppress_embeds(self, suppress: bool) -> Self {
+        let mut flags = self.0
+            .get(&"flags")
+            .and_then(Value::as_u64)
+            .map_or_else(MessageFlags::empty, MessageFlags::from_bits_truncate);
+        flags.set(MessageFlags::SUPPRESS_EMBEDS, suppress);
+
+        self.flags(flags)
+    }
+
+    /// Sets which mentions in the message will ping.
+    ///
+    /// Refer to the documentation for [`CreateAllowedMentions`] for more
+    /// information.
+    ///
+    /// [`CreateAllowedMentions`]: struct.CreateAllowedMentions.html
+    pub fn allowed_mentions<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateAllowedMentions) -> CreateAllowedMentions {
+        let map = utils::vecmap_to_json_map(f(CreateAllowedMentions::default()).0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+
+        self
+    }
+
     /// Set an embed for the message.
     pub fn embed<F>(mut self, f: F) -> Self
         where F: FnOnce(CreateEmbed) -> CreateEmbed {
@@ -68,6 +150,95 @@ impl CreateMessage {
         self
     }
 
+    /// Set an embed for the message, uploading the given file along with the
+    /// message and showing it as the embed's image.
+    ///
+    /// The file is added as via [`add_file`], and the embed's image is set to
+    /// the file's `attachment://` URL before the closure is called.
+    ///
+    /// **Note**: If the file's name is not valid UTF-8, it can not be referred
+    /// to, so the file is uploaded but the embed's image is not set.
+    ///
+    /// # Examples
+    ///
+    /// Uploading a chart and showing it inside of an embed:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::ChannelId;
+    /// use std::path::Path;
+    ///
+    /// let _ = ChannelId(7).send_message(|m| m
+    ///     .embed_with_image(Path::new("/tmp/chart.png"), |e| e.title("chart")));
+    /// ```
+    ///
+    /// [`add_file`]: #method.add_file
+    pub fn embed_with_image<T, F>(mut self, file: T, f: F) -> Self
+        where T: Into<MessageFile>, F: FnOnce(CreateEmbed) -> CreateEmbed {
+        let file = file.into();
+        let url = file.filename().map(|filename| format!("attachment://{}", filename));
+
+        self.2.push(file);
+
+        match url {
+            Some(url) => self.embed(|e| f(e.image(url))),
+            None => self.embed(f),
+        }
+    }
+
+    /// Sends the message as a reply to the message with the given Id.
+    ///
+    /// The referenced message must be in the channel the message is being
+    /// sent to. Use [`allowed_mentions`] with [`replied_user`] to control
+    /// whether the author of the referenced message is pinged.
+    ///
+    /// # Examples
+    ///
+    /// Replying to a message without pinging its author:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{ChannelId, MessageId};
+    /// #
+    /// # let (channel_id, message_id) = (ChannelId(7), MessageId(8));
+    /// #
+    /// let _ = channel_id.send_message(|m| m
+    ///     .content("hi")
+    ///     .reference_message(message_id)
+    ///     .allowed_mentions(|am| am.replied_user(false)));
+    /// ```
+    ///
+    /// [`allowed_mentions`]: #method.allowed_mentions
+    /// [`replied_user`]: struct.CreateAllowedMentions.html#method.replied_user
+    pub fn reference_message<M: Into<MessageId>>(mut self, message_id: M) -> Self {
+        let id = Value::String(message_id.into().0.to_string());
+        self._message_reference("message_id", id);
+
+        self
+    }
+
+    /// Sets whether sending a [reply][`reference_message`] errors if the
+    /// referenced message no longer exists.
+    ///
+    /// If this is `false`, the message is sent as a normal message instead.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`reference_message`]: #method.reference_message
+    pub fn fail_if_not_exists(mut self, fail_if_not_exists: bool) -> Self {
+        self._message_reference("fail_if_not_exists", Value::Bool(fail_if_not_exists));
+
+        self
+    }
+
+    fn _message_reference(&mut self, key: &str, value: Value) {
+        let entry = self.0
+            .entry("message_reference")
+            .or_insert_with(|| Value::Object(Map::new()));
+
+        if let Value::Object(ref mut map) = *entry {
+            map.insert(key.to_string(), value);
+        }
+    }
+
     /// Set whether the message is text-to-speech.
     ///
     /// Think carefully before setting this to `true`.
@@ -102,6 +273,180 @@ impl Default for CreateMessage {
         let mut map = VecMap::new();
         map.insert("tts", Value::Bool(false));
 
-        CreateMessage(map, None)
+        CreateMessage(map, None, Vec::new())
+    }
+}
+
+/// A file to upload along with a message, added via
+/// [`CreateMessage::add_file`].
+///
+/// Unlike an [`AttachmentType`], this owns its data so that it can be kept
+/// in the builder until the message is sent. Bytes are copied when the file
+/// is added, while paths are only read from when the message is sent.
+///
+/// [`AttachmentType`]: ../http/enum.AttachmentType.html
+/// [`CreateMessage::add_file`]: struct.CreateMessage.html#method.add_file
+#[derive(Clone, Debug)]
+pub enum MessageFile {
+    /// The contents of the file, along with the filename to upload it with.
+    Bytes(Vec<u8>, String),
+    /// The path to read the file from, along with the filename to upload it
+    /// with if not the path's own.
+    Path(PathBuf, Option<String>),
+}
+
+impl MessageFile {
+    /// The filename that the file is uploaded with, if it has one.
+    ///
+    /// This is the name to reference the file by in an embed, as in
+    /// `attachment://{filename}`.
+    pub fn filename(&self) -> Option<&str> {
+        match *self {
+            MessageFile::Bytes(_, ref filename) |
+            MessageFile::Path(_, Some(ref filename)) => Some(filename),
+            MessageFile::Path(ref path, None) => path.file_name().and_then(|name| name.to_str()),
+        }
+    }
+
+    #[cfg(feature = "http")]
+    pub(crate) fn as_attachment(&self) -> AttachmentType<'_> {
+        match *self {
+            MessageFile::Bytes(ref bytes, ref filename) => AttachmentType::Bytes((bytes, filename)),
+            MessageFile::Path(ref path, Some(ref filename)) => {
+                AttachmentType::PathWithName((path, filename))
+            },
+            MessageFile::Path(ref path, None) => AttachmentType::Path(path),
+        }
+    }
+}
+
+impl<'a> From<(&'a [u8], &'a str)> for MessageFile {
+    fn from((bytes, filename): (&'a [u8], &'a str)) -> MessageFile {
+        MessageFile::Bytes(bytes.to_vec(), filename.to_string())
+    }
+}
+
+impl From<(Vec<u8>, String)> for MessageFile {
+    fn from((bytes, filename): (Vec<u8>, String)) -> MessageFile {
+        MessageFile::Bytes(bytes, filename)
+    }
+}
+
+impl<'a> From<&'a str> for MessageFile {
+    fn from(path: &'a str) -> MessageFile { MessageFile::Path(PathBuf::from(path), None) }
+}
+
+impl<'a> From<&'a Path> for MessageFile {
+    fn from(path: &'a Path) -> MessageFile { MessageFile::Path(path.to_path_buf(), None) }
+}
+
+impl From<PathBuf> for MessageFile {
+    fn from(path: PathBuf) -> MessageFile { MessageFile::Path(path, None) }
+}
+
+impl<'a> From<(&'a Path, &'a str)> for MessageFile {
+    fn from((path, filename): (&'a Path, &'a str)) -> MessageFile {
+        MessageFile::Path(path.to_path_buf(), Some(filename.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::channel::MessageFlags;
+    use crate::model::id::MessageId;
+    use super::CreateMessage;
+    use crate::utils;
+
+    #[test]
+    fn test_suppress_embeds() {
+        let builder = CreateMessage::default()
+            .flags(MessageFlags::URGENT)
+            .suppress_embeds(true);
+        let map = utils::vecmap_to_json_map(builder.0);
+        assert_eq!(map["flags"], json!(0b0001_0100));
+
+        let builder = CreateMessage::default()
+            .suppress_embeds(true)
+            .suppress_embeds(false);
+        let map = utils::vecmap_to_json_map(builder.0);
+        assert_eq!(map["flags"], json!(0));
+    }
+
+    #[test]
+    fn test_reference_message() {
+        let builder = CreateMessage::default()
+            .fail_if_not_exists(false)
+            .reference_message(MessageId(5));
+        let map = utils::vecmap_to_json_map(builder.0);
+
+        assert_eq!(json!(map["message_reference"]), json!({
+            "fail_if_not_exists": false,
+            "message_id": "5",
+        }));
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_add_file() {
+        use std::path::Path;
+
+        let builder = CreateMessage::default()
+            .content("see chart")
+            .add_file((&b"a"[..], "chart.png"))
+            .add_file((Path::new("/tmp/data"), "data.csv"))
+            .add_file("/tmp/other.png");
+        let filenames = builder.2
+            .iter()
+            .map(|file| file.as_attachment().filename().map(str::to_string))
+            .collect::<Vec<_>>();
+
+        assert_eq!(filenames, vec![
+            Some("chart.png".to_string()),
+            Some("data.csv".to_string()),
+            Some("other.png".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_embed_with_image() {
+        use super::MessageFile;
+        use std::path::Path;
+
+        let files = vec![
+            MessageFile::from((&b"a"[..], "chart.png")),
+            MessageFile::from(Path::new("/tmp/chart.png")),
+        ];
+
+        for file in files {
+            let builder = CreateMessage::default()
+                .embed_with_image(file, |e| e.title("chart"));
+
+            assert_eq!(builder.2.len(), 1);
+            assert_eq!(builder.2[0].filename(), Some("chart.png"));
+
+            let map = utils::vecmap_to_json_map(builder.0);
+
+            assert_eq!(map["embed"]["image"]["url"], json!("attachment://chart.png"));
+            assert_eq!(map["embed"]["title"], json!("chart"));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_embed_with_image_non_utf8_filename() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::Path;
+
+        let path = Path::new(OsStr::from_bytes(b"/tmp/chart\xff.png"));
+        let builder = CreateMessage::default()
+            .embed_with_image(path, |e| e.title("chart"));
+
+        assert_eq!(builder.2.len(), 1);
+
+        let map = utils::vecmap_to_json_map(builder.0);
+
+        assert!(map["embed"].get("image").is_none());
+        assert_eq!(map["embed"]["title"], json!("chart"));
     }
 }
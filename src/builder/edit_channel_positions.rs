@@ -0,0 +1,81 @@
+use serde_json::Value;
+use crate::model::id::ChannelId;
+
+/// A builder to edit the positions of multiple [`GuildChannel`]s in a single
+/// request, for use with [`GuildId::edit_channel_positions`].
+///
+/// Channels can also be moved into or out of a category at the same time.
+///
+/// # Examples
+///
+/// Move two channels into a category, syncing their permissions with it, and
+/// move another to the top of the channel list:
+///
+/// ```rust,no_run
+/// use serenity::model::id::{ChannelId, GuildId};
+///
+/// let category = Some(ChannelId(3));
+///
+/// let _ = GuildId(1).edit_channel_positions(|p| p
+///     .position(ChannelId(4), 0)
+///     .move_channel(ChannelId(5), 1, category, true)
+///     .move_channel(ChannelId(6), 2, category, true));
+/// ```
+///
+/// [`GuildChannel`]: ../model/channel/struct.GuildChannel.html
+/// [`GuildId::edit_channel_positions`]: ../model/id/struct.GuildId.html#method.edit_channel_positions
+#[derive(Clone, Debug, Default)]
+pub struct EditChannelPositions(pub Vec<Value>);
+
+impl EditChannelPositions {
+    /// Set the position of a channel, leaving its category unchanged.
+    pub fn position<C: Into<ChannelId>>(mut self, channel_id: C, position: u64) -> Self {
+        self.0.push(json!({
+            "id": channel_id.into(),
+            "position": position,
+        }));
+
+        self
+    }
+
+    /// Set the position of a channel and move it into the given category, or
+    /// out of any category if `None` is given.
+    ///
+    /// If `lock_permissions` is `true`, the channel's permission overwrites
+    /// are synced with those of the new category.
+    pub fn move_channel<C>(mut self,
+                           channel_id: C,
+                           position: u64,
+                           parent_id: Option<ChannelId>,
+                           lock_permissions: bool)
+                           -> Self where C: Into<ChannelId> {
+        self.0.push(json!({
+            "id": channel_id.into(),
+            "lock_permissions": lock_permissions,
+            "parent_id": parent_id,
+            "position": position,
+        }));
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::id::ChannelId;
+    use super::EditChannelPositions;
+
+    #[test]
+    fn test_edit_channel_positions() {
+        let positions = EditChannelPositions::default()
+            .position(ChannelId(4), 0)
+            .move_channel(ChannelId(5), 1, Some(ChannelId(3)), true)
+            .move_channel(ChannelId(6), 2, None, false);
+
+        assert_eq!(positions.0, vec![
+            json!({"id": 4, "position": 0}),
+            json!({"id": 5, "lock_permissions": true, "parent_id": 3, "position": 1}),
+            json!({"id": 6, "lock_permissions": false, "parent_id": null, "position": 2}),
+        ]);
+    }
+}
@@ -1,6 +1,8 @@
+use chrono::{DateTime, TimeZone};
 use crate::internal::prelude::*;
 use crate::model::id::{ChannelId, RoleId};
 use crate::utils::VecMap;
+use std::fmt::Display;
 
 /// A builder which edits the properties of a [`Member`], to be used in
 /// conjunction with [`Member::edit`].
@@ -11,6 +13,32 @@ use crate::utils::VecMap;
 pub struct EditMember(pub VecMap<&'static str, Value>);
 
 impl EditMember {
+    /// Disables the member's communication until the given time, preventing
+    /// them from sending messages, reacting, and speaking in voice channels.
+    ///
+    /// The time can be at most 28 days in the future.
+    ///
+    /// Requires the [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags
+    pub fn disable_communication_until<Tz: TimeZone>(mut self, time: &DateTime<Tz>) -> Self
+        where Tz::Offset: Display {
+        self.0.insert("communication_disabled_until", Value::String(time.to_rfc3339()));
+
+        self
+    }
+
+    /// Enables the member's communication again, if it was disabled.
+    ///
+    /// Requires the [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags
+    pub fn enable_communication(mut self) -> Self {
+        self.0.insert("communication_disabled_until", Value::Null);
+
+        self
+    }
+
     /// Whether to deafen the member.
     ///
     /// Requires the [Deafen Members] permission.
@@ -0,0 +1,53 @@
+use crate::internal::prelude::*;
+use crate::model::permissions::Permissions;
+use crate::utils::VecMap;
+
+/// A builder to specify the permissions a channel's [permission overwrite]
+/// allows and denies.
+///
+/// Permissions that are neither allowed nor denied are inherited from the
+/// target's roles. Setting the overwrite replaces any existing overwrite for
+/// the same target, so permissions that are not set will be reset.
+///
+/// This is used via [`ChannelId::edit_permission`] and
+/// [`GuildChannel::edit_permission`].
+///
+/// # Examples
+///
+/// Lock a channel down by denying @everyone the ability to send messages:
+///
+/// ```rust,no_run
+/// # use serenity::model::id::{ChannelId, GuildId};
+/// use serenity::model::channel::PermissionOverwriteType;
+/// use serenity::model::permissions::Permissions;
+///
+/// # let guild_id = GuildId(7);
+/// #
+/// // The @everyone role shares its Id with the guild.
+/// let everyone = PermissionOverwriteType::Role(guild_id.0.into());
+///
+/// let _ = ChannelId(7).edit_permission(everyone, |p| p
+///     .deny(Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS));
+/// ```
+///
+/// [`ChannelId::edit_permission`]: ../model/id/struct.ChannelId.html#method.edit_permission
+/// [`GuildChannel::edit_permission`]: ../model/channel/struct.GuildChannel.html#method.edit_permission
+/// [permission overwrite]: ../model/channel/struct.PermissionOverwrite.html
+#[derive(Clone, Debug, Default)]
+pub struct EditPermissionOverwrite(pub VecMap<&'static str, Value>);
+
+impl EditPermissionOverwrite {
+    /// Sets the permissions explicitly allowed to the target.
+    pub fn allow(mut self, permissions: Permissions) -> Self {
+        self.0.insert("allow", Value::Number(Number::from(permissions.bits())));
+
+        self
+    }
+
+    /// Sets the permissions explicitly denied to the target.
+    pub fn deny(mut self, permissions: Permissions) -> Self {
+        self.0.insert("deny", Value::Number(Number::from(permissions.bits())));
+
+        self
+    }
+}
@@ -86,6 +86,47 @@ impl EditRole {
         self
     }
 
+    /// Sets the icon of the role. Pass `None` to remove the icon.
+    ///
+    /// The icon must be a base64-encoded image data URI, which
+    /// [`utils::read_image`] produces from a file.
+    ///
+    /// **Note**: The guild must have the `ROLE_ICONS` feature, otherwise
+    /// Discord will reject the request.
+    ///
+    /// # Examples
+    ///
+    /// Set the icon of a role from a file:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{GuildId, RoleId};
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// #     let (guild_id, role_id) = (GuildId(1), RoleId(2));
+    /// use serenity::utils;
+    ///
+    /// let base64_icon = utils::read_image("./role_icon.png")?;
+    ///
+    /// guild_id.edit_role(role_id, |r| r.icon(Some(&base64_icon)))?;
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`utils::read_image`]: ../utils/fn.read_image.html
+    pub fn icon(mut self, icon: Option<&str>) -> Self {
+        self.0.insert(
+            "icon",
+            icon.map_or_else(|| Value::Null, |x| Value::String(x.to_string())),
+        );
+
+        self
+    }
+
     /// Whether or not to make the role mentionable, notifying its users.
     pub fn mentionable(mut self, mentionable: bool) -> Self {
         self.0.insert("mentionable", Value::Bool(mentionable));
@@ -108,6 +149,20 @@ impl EditRole {
         self
     }
 
+    /// Sets a unicode emoji, such as `"🔥"`, as the icon of the role. Pass
+    /// `None` to remove it.
+    ///
+    /// **Note**: The guild must have the `ROLE_ICONS` feature, otherwise
+    /// Discord will reject the request.
+    pub fn unicode_emoji(mut self, unicode_emoji: Option<&str>) -> Self {
+        self.0.insert(
+            "unicode_emoji",
+            unicode_emoji.map_or_else(|| Value::Null, |x| Value::String(x.to_string())),
+        );
+
+        self
+    }
+
     /// The position to assign the role in the role list. This correlates to the
     /// role's position in the user list.
     pub fn position(mut self, position: u8) -> Self {
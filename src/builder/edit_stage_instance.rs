@@ -0,0 +1,42 @@
+use crate::internal::prelude::*;
+use crate::model::channel::StagePrivacyLevel;
+use crate::utils::VecMap;
+
+/// A builder to specify the fields to edit in a [`StageInstance`].
+///
+/// This is used via [`ChannelId::edit_stage_instance`] and
+/// [`GuildChannel::edit_stage_instance`].
+///
+/// # Examples
+///
+/// Change the topic of the stage held in a channel:
+///
+/// ```rust,no_run
+/// # use serenity::model::id::ChannelId;
+/// #
+/// let _ = ChannelId(7).edit_stage_instance(|s| s.topic("Q&A"));
+/// ```
+///
+/// [`ChannelId::edit_stage_instance`]: ../model/id/struct.ChannelId.html#method.edit_stage_instance
+/// [`GuildChannel::edit_stage_instance`]: ../model/channel/struct.GuildChannel.html#method.edit_stage_instance
+/// [`StageInstance`]: ../model/channel/struct.StageInstance.html
+#[derive(Clone, Debug, Default)]
+pub struct EditStageInstance(pub VecMap<&'static str, Value>);
+
+impl EditStageInstance {
+    /// Sets who the stage instance is visible to.
+    pub fn privacy_level(mut self, privacy_level: StagePrivacyLevel) -> Self {
+        self.0.insert("privacy_level", Value::Number(Number::from(privacy_level.num())));
+
+        self
+    }
+
+    /// Sets the topic of the stage instance.
+    ///
+    /// **Note**: Must be between 1 and 120 characters long.
+    pub fn topic(mut self, topic: &str) -> Self {
+        self.0.insert("topic", Value::String(topic.to_string()));
+
+        self
+    }
+}
@@ -0,0 +1,82 @@
+use crate::internal::prelude::*;
+use serde_json::Value;
+use std::result::Result as StdResult;
+use super::validate::{self, BuilderError, Validate};
+use crate::utils::VecMap;
+
+/// A builder to specify the fields to edit in a message previously sent by a
+/// [`Webhook`].
+///
+/// # Examples
+///
+/// Editing the content of a message sent via a webhook to `"hello"`:
+///
+/// ```rust,no_run
+/// use serenity::http;
+/// use serenity::model::id::MessageId;
+///
+/// let id = 245037420704169985;
+/// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+///
+/// let webhook = http::get_webhook_with_token(id, token)
+///     .expect("valid webhook");
+///
+/// let _ = webhook.edit_message(MessageId(7), |m| m.content("hello"));
+/// ```
+///
+/// [`Webhook`]: ../model/webhook/struct.Webhook.html
+#[derive(Clone, Debug, Default)]
+pub struct EditWebhookMessage(pub VecMap<&'static str, Value>);
+
+impl EditWebhookMessage {
+    /// Set the content of the message.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    pub fn content(mut self, content: &str) -> Self {
+        self.0.insert("content", Value::String(content.to_string()));
+
+        self
+    }
+
+    /// Set the embeds associated with the message, replacing the existing
+    /// ones.
+    ///
+    /// This should be used in combination with [`Embed::fake`], creating one
+    /// or more fake embeds to send to the API.
+    ///
+    /// **Note**: A message can have at most 10 embeds.
+    ///
+    /// [`Embed::fake`]: ../model/channel/struct.Embed.html#method.fake
+    pub fn embeds(mut self, embeds: Vec<Value>) -> Self {
+        self.0.insert("embeds", Value::Array(embeds));
+
+        self
+    }
+
+    /// Checks the edit against Discord's restriction on the number of embeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyEmbeds`] if more than 10 embeds are set.
+    ///
+    /// [`ModelError::TooManyEmbeds`]: ../model/error/enum.Error.html#variant.TooManyEmbeds
+    pub fn validate(&self) -> Result<()> {
+        if let Some(Value::Array(embeds)) = self.0.get(&"embeds") {
+            validate::validate_embed_count(embeds)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for EditWebhookMessage {
+    /// Checks the number of embeds like [`EditWebhookMessage::validate`].
+    ///
+    /// [`EditWebhookMessage::validate`]: struct.EditWebhookMessage.html#method.validate
+    fn validate_all(&self) -> StdResult<(), Vec<BuilderError>> {
+        match self.validate() {
+            Err(Error::Model(why)) => Err(vec![BuilderError::from_model("embeds", &why)]),
+            _ => Ok(()),
+        }
+    }
+}
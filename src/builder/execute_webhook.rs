@@ -1,6 +1,17 @@
+use crate::constants;
+use crate::internal::prelude::*;
+use crate::model::{
+    error::WebhookUsernameError,
+    ModelError,
+};
 use serde_json::Value;
 use std::default::Default;
-use crate::utils::VecMap;
+use super::{
+    check_timestamp,
+    validate::{self, BuilderError, Validate},
+    CreateAllowedMentions,
+};
+use crate::utils::{self, VecMap};
 
 /// A builder to create the inner content of a [`Webhook`]'s execution.
 ///
@@ -50,6 +61,21 @@ use crate::utils::VecMap;
 pub struct ExecuteWebhook(pub VecMap<&'static str, Value>);
 
 impl ExecuteWebhook {
+    /// Sets which mentions in the content of the execution will ping.
+    ///
+    /// Refer to the documentation for [`CreateAllowedMentions`] for more
+    /// information.
+    ///
+    /// [`CreateAllowedMentions`]: struct.CreateAllowedMentions.html
+    pub fn allowed_mentions<F>(mut self, f: F) -> Self
+        where F: FnOnce(CreateAllowedMentions) -> CreateAllowedMentions {
+        let map = utils::vecmap_to_json_map(f(CreateAllowedMentions::default()).0);
+
+        self.0.insert("allowed_mentions", Value::Object(map));
+
+        self
+    }
+
     /// Override the default avatar of the webhook with an image URL.
     ///
     /// # Examples
@@ -106,6 +132,8 @@ impl ExecuteWebhook {
     /// This should be used in combination with [`Embed::fake`], creating one
     /// or more fake embeds to send to the API.
     ///
+    /// **Note**: A message can have at most 10 embeds.
+    ///
     /// # Examples
     ///
     /// Refer to the [struct-level documentation] for an example on how to use
@@ -145,6 +173,12 @@ impl ExecuteWebhook {
 
     /// Override the default username of the webhook.
     ///
+    /// Leading and trailing whitespace is trimmed from the username.
+    ///
+    /// **Note**: Discord rejects usernames which are blank, longer than 80
+    /// unicode code points, or contain "clyde". This is checked by
+    /// [`validate`], which [`Webhook::execute`] calls before executing.
+    ///
     /// # Examples
     ///
     /// Overriding the username to `"hakase"`:
@@ -160,11 +194,107 @@ impl ExecuteWebhook {
     /// }
     /// # }
     /// ```
+    ///
+    /// [`Webhook::execute`]: ../model/webhook/struct.Webhook.html#method.execute
+    /// [`validate`]: #method.validate
     pub fn username(mut self, username: &str) -> Self {
-        self.0.insert("username", Value::String(username.to_string()));
+        self.0.insert("username", Value::String(username.trim().to_string()));
 
         self
     }
+
+    /// Checks the execution against Discord's restrictions on the username,
+    /// if one is set, and on the number of embeds and their timestamps.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidWebhookUsername`] if the username is
+    /// blank, too long, or contains "clyde".
+    ///
+    /// Returns a [`ModelError::TooManyEmbeds`] if more than 10 embeds are set.
+    ///
+    /// Returns a [`ModelError::InvalidEmbedTimestamp`] if an embed's timestamp
+    /// is not in ISO-8601 format.
+    ///
+    /// [`ModelError::InvalidEmbedTimestamp`]: ../model/error/enum.Error.html#variant.InvalidEmbedTimestamp
+    /// [`ModelError::InvalidWebhookUsername`]: ../model/error/enum.Error.html#variant.InvalidWebhookUsername
+    /// [`ModelError::TooManyEmbeds`]: ../model/error/enum.Error.html#variant.TooManyEmbeds
+    pub fn validate(&self) -> Result<()> {
+        if let Some(Value::String(username)) = self.0.get(&"username") {
+            validate_username(username)?;
+        }
+
+        if let Some(Value::Array(embeds)) = self.0.get(&"embeds") {
+            validate::validate_embed_count(embeds)?;
+            validate::validate_embed_timestamps(embeds)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Validate for ExecuteWebhook {
+    /// Checks the username and number of embeds like
+    /// [`ExecuteWebhook::validate`], as well as the length of the content.
+    ///
+    /// [`ExecuteWebhook::validate`]: struct.ExecuteWebhook.html#method.validate
+    fn validate_all(&self) -> StdResult<(), Vec<BuilderError>> {
+        let mut errors = Vec::new();
+
+        if let Some(Value::String(username)) = self.0.get(&"username") {
+            if let Err(Error::Model(why)) = validate_username(username) {
+                errors.push(BuilderError::from_model("username", &why));
+            }
+        }
+
+        if let Some(Value::String(content)) = self.0.get(&"content") {
+            let count = content.chars().count() as u64;
+            let limit = u64::from(constants::MESSAGE_CODE_LIMIT);
+
+            if count > limit {
+                let why = ModelError::MessageTooLong(count - limit);
+
+                errors.push(BuilderError::from_model("content", &why));
+            }
+        }
+
+        if let Some(Value::Array(embeds)) = self.0.get(&"embeds") {
+            if let Err(Error::Model(why)) = validate::validate_embed_count(embeds) {
+                errors.push(BuilderError::from_model("embeds", &why));
+            }
+
+            for embed in embeds {
+                if let Some(why) = check_timestamp(embed.get("timestamp")) {
+                    errors.push(BuilderError::from_model("embeds.timestamp", &why));
+                }
+            }
+        }
+
+        validate::collect(errors)
+    }
+}
+
+/// Checks a webhook username against Discord's restrictions, after trimming
+/// it of whitespace.
+pub(crate) fn validate_username(username: &str) -> Result<()> {
+    let username = username.trim();
+
+    let reason = if username.is_empty() {
+        WebhookUsernameError::Blank
+    } else if username.to_lowercase().contains("clyde") {
+        WebhookUsernameError::ContainsClyde
+    } else {
+        let count = username.chars().count() as u64;
+        let limit = u64::from(constants::WEBHOOK_USERNAME_LIMIT);
+
+        if count <= limit {
+            return Ok(());
+        }
+
+        WebhookUsernameError::TooLong(count - limit)
+    };
+
+    Err(Error::Model(ModelError::InvalidWebhookUsername(reason)))
 }
 
 impl Default for ExecuteWebhook {
@@ -191,3 +321,60 @@ impl Default for ExecuteWebhook {
         ExecuteWebhook(map)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::builder::{CreateEmbed, Validate};
+    use crate::model::{error::WebhookUsernameError, ModelError};
+    use crate::utils;
+    use crate::Error;
+    use super::ExecuteWebhook;
+
+    fn reason(username: &str) -> Option<WebhookUsernameError> {
+        match ExecuteWebhook::default().username(username).validate() {
+            Err(Error::Model(ModelError::InvalidWebhookUsername(reason))) => Some(reason),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_validate_username() {
+        assert!(ExecuteWebhook::default().validate().is_ok());
+        assert_eq!(reason("  hakase "), None);
+        assert_eq!(reason(&"a".repeat(80)), None);
+
+        assert_eq!(reason("   "), Some(WebhookUsernameError::Blank));
+        assert_eq!(reason("Not CLYDE"), Some(WebhookUsernameError::ContainsClyde));
+        assert_eq!(reason(&"a".repeat(82)), Some(WebhookUsernameError::TooLong(2)));
+    }
+
+    #[test]
+    fn test_validate_embed_timestamps() {
+        let embed = |timestamp: &str| utils::vecmap_to_json_map(CreateEmbed::default()
+            .timestamp(timestamp)
+            .0).into();
+        let valid = ExecuteWebhook::default().embeds(vec![embed("2004-06-08T16:04:23")]);
+        assert!(valid.validate().is_ok());
+
+        let invalid = ExecuteWebhook::default().embeds(vec![embed("yesterday")]);
+        assert!(match invalid.validate() {
+            Err(Error::Model(ModelError::InvalidEmbedTimestamp(ref ts))) => ts == "yesterday",
+            _ => false,
+        });
+
+        let errors = invalid.validate_all().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "embeds.timestamp");
+    }
+
+    #[test]
+    fn test_build() {
+        let webhook = ExecuteWebhook::default().content("hi").username("hakase");
+
+        assert_eq!(webhook.build(), json!({
+            "content": "hi",
+            "tts": false,
+            "username": "hakase",
+        }));
+    }
+}
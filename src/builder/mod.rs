@@ -5,28 +5,84 @@
 //! optional, and/or sane default values for required parameters can be applied
 //! by a builder.
 
+mod create_allowed_mentions;
 mod create_embed;
 mod create_invite;
 mod create_message;
 mod edit_channel;
+mod edit_channel_positions;
 mod edit_guild;
 mod edit_member;
 mod edit_message;
+mod edit_permission_overwrite;
 mod edit_profile;
 mod edit_role;
+mod edit_stage_instance;
+mod edit_webhook_message;
 mod execute_webhook;
 mod get_messages;
+mod validate;
 
 pub use self::{
+    create_allowed_mentions::{CreateAllowedMentions, ParseValue},
     create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter},
     create_invite::CreateInvite,
-    create_message::CreateMessage,
+    create_message::{CreateMessage, MessageFile},
     edit_channel::EditChannel,
+    edit_channel_positions::EditChannelPositions,
     edit_guild::EditGuild,
     edit_member::EditMember,
     edit_message::EditMessage,
+    edit_permission_overwrite::EditPermissionOverwrite,
     edit_profile::EditProfile,
     edit_role::EditRole,
+    edit_stage_instance::EditStageInstance,
+    edit_webhook_message::EditWebhookMessage,
     execute_webhook::ExecuteWebhook,
-    get_messages::GetMessages
+    get_messages::GetMessages,
+    validate::{BuilderError, Validate}
 };
+
+pub(crate) use self::create_embed::check_timestamp;
+
+#[cfg(feature = "model")]
+pub(crate) use self::execute_webhook::validate_username;
+
+use serde_json::Value;
+use crate::utils;
+
+macro_rules! impl_build {
+    ($($builder:ident,)*) => {
+        $(
+            impl $builder {
+                /// Returns the JSON payload that the builder has built, as it
+                /// would be sent to Discord.
+                ///
+                /// This does not issue a request, so it can be used to log a
+                /// payload, or to assert on it in tests.
+                pub fn build(&self) -> Value {
+                    Value::Object(utils::vecmap_to_json_map(self.0.clone()))
+                }
+            }
+        )*
+    };
+}
+
+impl_build! {
+    CreateAllowedMentions,
+    CreateEmbed,
+    CreateEmbedAuthor,
+    CreateEmbedFooter,
+    CreateInvite,
+    CreateMessage,
+    EditChannel,
+    EditGuild,
+    EditMember,
+    EditMessage,
+    EditPermissionOverwrite,
+    EditProfile,
+    EditRole,
+    EditStageInstance,
+    EditWebhookMessage,
+    ExecuteWebhook,
+}
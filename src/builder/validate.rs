@@ -0,0 +1,196 @@
+use crate::constants;
+use crate::internal::prelude::*;
+use crate::model::{
+    error::{EmbedLimit, WebhookUsernameError},
+    ModelError,
+};
+use std::{
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    result::Result as StdResult,
+};
+
+/// Checks a builder's payload against Discord's restrictions before it is
+/// sent.
+///
+/// Unlike the API, which rejects a payload on its first problem, this
+/// collects every field that would be rejected. This is useful for asserting
+/// that payloads are well-formed, such as in a bot's test suite.
+///
+/// Some builders also have an inherent `validate` method, which returns only
+/// the first problem as an [`Error`].
+///
+/// # Examples
+///
+/// Catching an invite that would expire later than Discord allows:
+///
+/// ```rust
+/// use serenity::builder::{CreateInvite, Validate};
+///
+/// let invite = CreateInvite::default().max_age(604_801).max_uses(10);
+/// let errors = invite.validate_all().unwrap_err();
+///
+/// assert_eq!(errors.len(), 1);
+/// assert_eq!(errors[0].field, "max_age");
+/// ```
+///
+/// [`Error`]: ../enum.Error.html
+pub trait Validate {
+    /// Checks the payload, returning every field that would be rejected.
+    fn validate_all(&self) -> StdResult<(), Vec<BuilderError>>;
+}
+
+/// A field of a builder's payload that Discord would reject.
+///
+/// This is provided by [`Validate::validate_all`].
+///
+/// [`Validate::validate_all`]: trait.Validate.html#tymethod.validate_all
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BuilderError {
+    /// The name of the field in the payload, such as `"max_age"` or
+    /// `"footer.icon_url"`.
+    pub field: &'static str,
+    /// A human-readable reason for why the field would be rejected.
+    pub reason: String,
+}
+
+impl BuilderError {
+    pub(crate) fn new<S: Into<String>>(field: &'static str, reason: S) -> Self {
+        BuilderError {
+            field,
+            reason: reason.into(),
+        }
+    }
+
+    /// Creates an error for a field from the error that the builder's
+    /// inherent checks would return for it.
+    pub(crate) fn from_model(field: &'static str, error: &ModelError) -> Self {
+        let reason = match *error {
+            ModelError::EmbedLimitExceeded(EmbedLimit::Fields, over) => {
+                format!("{} fields over the limit", over)
+            },
+            ModelError::EmbedLimitExceeded(_, over) |
+            ModelError::InvalidWebhookUsername(WebhookUsernameError::TooLong(over)) |
+            ModelError::MessageTooLong(over) => {
+                format!("{} unicode code points over the limit", over)
+            },
+            ModelError::InvalidEmbedUrl(ref url) => {
+                format!("`{}` is not an http, https, or attachment URL", url)
+            },
+            ModelError::InvalidEmbedTimestamp(ref ts) => {
+                format!("`{}` is not an ISO-8601 timestamp", ts)
+            },
+            ModelError::TooManyEmbeds(over) => {
+                format!("{} embeds over the limit of {}", over, constants::EMBED_COUNT_LIMIT)
+            },
+            ModelError::InvalidWebhookUsername(WebhookUsernameError::Blank) => {
+                "must not be blank".to_string()
+            },
+            ModelError::InvalidWebhookUsername(WebhookUsernameError::ContainsClyde) => {
+                "must not contain \"clyde\"".to_string()
+            },
+            ref other => other.to_string(),
+        };
+
+        BuilderError::new(field, reason)
+    }
+}
+
+impl Display for BuilderError {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+impl StdError for BuilderError {
+    fn description(&self) -> &str { &self.reason }
+}
+
+/// Checks the number of embeds of a message against Discord's limit, shared
+/// by the builders which send multiple embeds.
+pub(crate) fn validate_embed_count(embeds: &[Value]) -> Result<()> {
+    let count = embeds.len() as u64;
+    let limit = u64::from(constants::EMBED_COUNT_LIMIT);
+
+    if count > limit {
+        return Err(Error::Model(ModelError::TooManyEmbeds(count - limit)));
+    }
+
+    Ok(())
+}
+
+/// Checks the timestamps of the embeds of a message, shared by the builders
+/// which send multiple embeds.
+pub(crate) fn validate_embed_timestamps(embeds: &[Value]) -> Result<()> {
+    for embed in embeds {
+        if let Some(why) = super::check_timestamp(embed.get("timestamp")) {
+            return Err(Error::Model(why));
+        }
+    }
+
+    Ok(())
+}
+
+/// Turns a list of problems into the result of [`Validate::validate_all`].
+///
+/// [`Validate::validate_all`]: trait.Validate.html#tymethod.validate_all
+pub(crate) fn collect(errors: Vec<BuilderError>) -> StdResult<(), Vec<BuilderError>> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::builder::{CreateEmbed, CreateInvite, EditWebhookMessage, ExecuteWebhook};
+    use serde_json::Value;
+    use super::Validate;
+
+    fn fields<V: Validate>(builder: &V) -> Vec<&'static str> {
+        match builder.validate_all() {
+            Ok(()) => vec![],
+            Err(errors) => errors.into_iter().map(|error| error.field).collect(),
+        }
+    }
+
+    #[test]
+    fn test_invite() {
+        assert!(fields(&CreateInvite::default()).is_empty());
+        assert!(fields(&CreateInvite::default().max_age(604_800).max_uses(100)).is_empty());
+        assert_eq!(fields(&CreateInvite::default().max_age(604_801)), vec!["max_age"]);
+        assert_eq!(
+            fields(&CreateInvite::default().max_age(604_801).max_uses(101)),
+            vec!["max_age", "max_uses"],
+        );
+    }
+
+    #[test]
+    fn test_collects_every_problem() {
+        let embed = CreateEmbed::default()
+            .title("a".repeat(257))
+            .image("ftp://example.com/a.png")
+            .footer(|f| f.text("a".repeat(2049)))
+            .timestamp("yesterday");
+        assert_eq!(fields(&embed), vec!["image.url", "title", "footer.text", "timestamp"]);
+
+        let execute = ExecuteWebhook::default()
+            .username("clyde")
+            .content(&"a".repeat(2001));
+        assert_eq!(fields(&execute), vec!["username", "content"]);
+    }
+
+    #[test]
+    fn test_embed_count() {
+        let embeds = |n| vec![Value::Object(Default::default()); n];
+
+        assert!(fields(&ExecuteWebhook::default().embeds(embeds(10))).is_empty());
+        assert_eq!(fields(&ExecuteWebhook::default().embeds(embeds(11))), vec!["embeds"]);
+        assert!(ExecuteWebhook::default().embeds(embeds(11)).validate().is_err());
+
+        assert!(fields(&EditWebhookMessage::default().embeds(embeds(10))).is_empty());
+        assert_eq!(fields(&EditWebhookMessage::default().embeds(embeds(11))), vec!["embeds"]);
+        assert!(EditWebhookMessage::default().embeds(embeds(11)).validate().is_err());
+    }
+}
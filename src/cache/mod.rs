@@ -107,7 +107,11 @@ pub struct Cache {
     ///
     /// This is a map of channel IDs to another map of message IDs to messages.
     ///
-    /// This keeps only the ten most recent messages.
+    /// Only the most recent messages of each channel are kept, up to the
+    /// maximum set via [`Settings::max_messages`]. No messages are cached by
+    /// default.
+    ///
+    /// [`Settings::max_messages`]: struct.Settings.html#method.max_messages
     pub messages: MessageCache,
     /// A map of notes that a user has made for individual users.
     ///
@@ -343,6 +347,32 @@ impl Cache {
             .collect()
     }
 
+    /// Counts the guilds in the cache that belong to the given shard,
+    /// including unavailable guilds.
+    ///
+    /// The guilds' shards are calculated using the cache's [`shard_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::cache::Cache;
+    ///
+    /// let cache = Cache::new();
+    ///
+    /// assert_eq!(cache.guild_count_for_shard(0), 0);
+    /// ```
+    ///
+    /// [`shard_count`]: #structfield.shard_count
+    pub fn guild_count_for_shard(&self, shard_id: u64) -> usize {
+        let shard_count = self.shard_count.max(1);
+
+        self.guilds
+            .keys()
+            .chain(self.unavailable_guilds.iter())
+            .filter(|guild_id| (guild_id.0 >> 22) % shard_count == shard_id)
+            .count()
+    }
+
     /// Retrieves a [`Channel`] from the cache based on the given Id.
     ///
     /// This will search the [`channels`] map, the [`private_channels`] map, and
@@ -766,6 +796,20 @@ impl Cache {
         self.settings.cache_lock_time
     }
 
+    pub(crate) fn remove_message(
+        &mut self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Option<Message> {
+        let message = self.messages.get_mut(&channel_id)?.remove(&message_id)?;
+
+        if let Some(queue) = self.message_queue.get_mut(&channel_id) {
+            queue.retain(|id| *id != message_id);
+        }
+
+        Some(message)
+    }
+
     pub(crate) fn update_user_entry(&mut self, user: &User) {
         match self.users.entry(user.id) {
             Entry::Vacant(e) => {
@@ -880,6 +924,33 @@ mod test {
             assert!(!channel.contains_key(&MessageId(3)));
         }
 
+        // Deleting a cached message removes it, while deleting an evicted one
+        // does nothing.
+        let mut delete = MessageDeleteEvent {
+            channel_id: event.message.channel_id,
+            message_id: MessageId(4),
+        };
+        assert_eq!(cache.update(&mut delete).unwrap().id, MessageId(4));
+        delete.message_id = MessageId(3);
+        assert!(cache.update(&mut delete).is_none());
+        assert_eq!(cache.message_queue[&event.message.channel_id].len(), 1);
+
+        // Lowering the maximum evicts the older messages on the next insert.
+        event.message.id = MessageId(6);
+        assert!(event.update(&mut cache).is_none());
+        cache.settings_mut().max_messages(1);
+        event.message.id = MessageId(7);
+        assert_eq!(event.update(&mut cache).unwrap().id, MessageId(6));
+        assert_eq!(cache.messages[&event.message.channel_id].len(), 1);
+
+        let mut delete_bulk = MessageDeleteBulkEvent {
+            channel_id: event.message.channel_id,
+            ids: vec![MessageId(6), MessageId(7)],
+        };
+        assert_eq!(cache.update(&mut delete_bulk).unwrap().len(), 1);
+        assert!(cache.messages[&event.message.channel_id].is_empty());
+        cache.settings_mut().max_messages(2);
+
         let guild_channel = GuildChannel {
             id: event.message.channel_id,
             bitrate: None,
@@ -970,4 +1041,16 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId(2)));
     }
+
+    #[test]
+    fn test_guild_count_for_shard() {
+        let mut cache = Cache::default();
+        cache.shard_count = 2;
+        cache.unavailable_guilds.insert(GuildId(0));
+        cache.unavailable_guilds.insert(GuildId(1 << 22));
+        cache.unavailable_guilds.insert(GuildId(2 << 22));
+
+        assert_eq!(cache.guild_count_for_shard(0), 2);
+        assert_eq!(cache.guild_count_for_shard(1), 1);
+    }
 }
@@ -16,6 +16,10 @@ use std::time::Duration;
 pub struct Settings {
     /// The maximum number of messages to store in a channel's message cache.
     ///
+    /// When a channel's message cache is full, its oldest message is evicted
+    /// to make room for a new one. Setting this to 0 disables the message
+    /// cache.
+    ///
     /// Defaults to 0.
     pub max_messages: usize,
 
@@ -27,6 +31,18 @@ pub struct Settings {
     /// If set to `None`, cache updates will acquire write-lock until available,
     /// potentially deadlocking.
     pub cache_lock_time: Option<Duration>,
+
+    /// The number of guilds a shard may hold before
+    /// [`EventHandler::shard_guild_threshold_exceeded`] is dispatched.
+    ///
+    /// Discord requires bots to use more shards once a shard holds 2500
+    /// guilds, so this allows resharding before the gateway closes the
+    /// connection.
+    ///
+    /// Defaults to 2000.
+    ///
+    /// [`EventHandler::shard_guild_threshold_exceeded`]: ../client/trait.EventHandler.html#method.shard_guild_threshold_exceeded
+    pub shard_guild_threshold: usize,
     __nonexhaustive: (),
 }
 
@@ -35,6 +51,7 @@ impl Default for Settings {
         Settings {
             max_messages: usize::default(),
             cache_lock_time: Some(Duration::from_millis(10)),
+            shard_guild_threshold: 2000,
             __nonexhaustive: (),
         }
     }
@@ -110,4 +127,25 @@ impl Settings {
 
         self
     }
+
+    /// Sets the number of guilds a shard may hold before a warning is
+    /// dispatched.
+    ///
+    /// Refer to [`shard_guild_threshold`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.shard_guild_threshold(2200);
+    /// ```
+    ///
+    /// [`shard_guild_threshold`]: #structfield.shard_guild_threshold
+    pub fn shard_guild_threshold(&mut self, threshold: usize) -> &mut Self {
+        self.shard_guild_threshold = threshold;
+
+        self
+    }
 }
@@ -0,0 +1,224 @@
+use crate::gateway::InterMessage;
+use crate::model::event::Event;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+    },
+    time::Duration,
+};
+use super::{ShardClientMessage, ShardRunnerMessage};
+
+static NEXT_COLLECTOR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// An item carried by gateway events which a [`Collector`] can receive, such
+/// as a created [`Message`].
+///
+/// [`Collector`]: struct.Collector.html
+/// [`Message`]: ../../../model/channel/struct.Message.html
+pub trait Collectable: Clone + Send + 'static {
+    /// Returns the item carried by the event, if it is of the kind of event
+    /// carrying one.
+    fn from_event(event: &Event) -> Option<&Self>;
+}
+
+/// A filter deciding which items a [`Collector`] receives.
+///
+/// [`Collector`]: struct.Collector.html
+pub trait Filter<T>: Clone + Send + 'static {
+    /// Whether the item matches all of the filter's criteria.
+    fn matches(&self, item: &T) -> bool;
+}
+
+/// Receives the items matching a [`Filter`], as they are received by a
+/// shard.
+///
+/// This is created via [`ShardMessenger::collect`], or one of its
+/// specialised forms, which registers the collector with the shard's
+/// [`ShardRunner`]. The collector is unregistered when dropped.
+///
+/// [`Filter`]: trait.Filter.html
+/// [`ShardMessenger::collect`]: struct.ShardMessenger.html#method.collect
+/// [`ShardRunner`]: struct.ShardRunner.html
+#[derive(Debug)]
+pub struct Collector<T: Collectable, F: Filter<T>> {
+    filter: PhantomData<F>,
+    id: usize,
+    receiver: Receiver<T>,
+    runner_tx: Sender<InterMessage>,
+}
+
+impl<T: Collectable, F: Filter<T>> Collector<T, F> {
+    pub(super) fn new(runner_tx: Sender<InterMessage>, filter: F) -> Self {
+        let id = NEXT_COLLECTOR_ID.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = mpsc::channel();
+
+        let registration = CollectorSender(Box::new(FilteredSender(filter, sender)));
+        let msg = ShardRunnerMessage::AddCollector(id, registration);
+        let _ = runner_tx.send(InterMessage::Client(ShardClientMessage::Runner(msg)));
+
+        Self {
+            filter: PhantomData,
+            id,
+            receiver,
+            runner_tx,
+        }
+    }
+
+    /// Waits for the next matching item for at most the given duration.
+    ///
+    /// Returns `None` if no item matched in time, or if the shard runner has
+    /// shut down.
+    pub fn receive(&self, timeout: Duration) -> Option<T> {
+        match self.receiver.recv_timeout(timeout) {
+            Ok(item) => Some(item),
+            Err(RecvTimeoutError::Timeout) | Err(RecvTimeoutError::Disconnected) => None,
+        }
+    }
+
+    /// Returns the next matching item if one was already received, without
+    /// waiting.
+    pub fn try_receive(&self) -> Option<T> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl<T: Collectable, F: Filter<T>> Drop for Collector<T, F> {
+    fn drop(&mut self) {
+        let msg = ShardRunnerMessage::RemoveCollector(self.id);
+        let _ = self.runner_tx.send(InterMessage::Client(ShardClientMessage::Runner(msg)));
+    }
+}
+
+trait Sink: Send {
+    /// Sends the event's item if it matches, returning whether the collector
+    /// is still receiving.
+    fn send(&self, event: &Event) -> bool;
+
+    fn clone_box(&self) -> Box<dyn Sink>;
+}
+
+struct FilteredSender<T, F>(F, Sender<T>);
+
+impl<T: Collectable, F: Filter<T>> Sink for FilteredSender<T, F> {
+    fn send(&self, event: &Event) -> bool {
+        match T::from_event(event) {
+            Some(item) if self.0.matches(item) => self.1.send(item.clone()).is_ok(),
+            _ => true,
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn Sink> {
+        Box::new(FilteredSender(self.0.clone(), self.1.clone()))
+    }
+}
+
+/// The filter of a [`Collector`] along with the sender to send it matching
+/// items over, as registered with a [`ShardRunner`].
+///
+/// [`Collector`]: struct.Collector.html
+/// [`ShardRunner`]: struct.ShardRunner.html
+pub struct CollectorSender(Box<dyn Sink>);
+
+impl Clone for CollectorSender {
+    fn clone(&self) -> Self {
+        CollectorSender(self.0.clone_box())
+    }
+}
+
+impl Debug for CollectorSender {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_tuple("CollectorSender")
+            .field(&"<sink>")
+            .finish()
+    }
+}
+
+/// The collectors registered with a shard, keyed by Id.
+///
+/// A registry is kept by the [`ShardQueuer`] for each shard and given to each
+/// of the shard's [`ShardRunner`]s, so that collectors keep receiving after
+/// the shard's runner is restarted.
+///
+/// [`ShardQueuer`]: struct.ShardQueuer.html
+/// [`ShardRunner`]: struct.ShardRunner.html
+#[derive(Debug, Default)]
+pub struct CollectorRegistry(Vec<(usize, CollectorSender)>);
+
+impl CollectorRegistry {
+    /// Registers the collector with the given Id.
+    pub fn add(&mut self, id: usize, sender: CollectorSender) {
+        self.0.push((id, sender));
+    }
+
+    /// Unregisters the collector with the given Id.
+    pub fn remove(&mut self, id: usize) {
+        self.0.retain(|&(other, _)| other != id);
+    }
+
+    /// Sends the event's item to each of the collectors whose filter it
+    /// matches, dropping the collectors that are no longer receiving.
+    pub fn collect(&mut self, event: &Event) {
+        self.0.retain(|(_, sender)| (sender.0).send(event));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gateway::InterMessage;
+    use crate::model::{
+        channel::{Reaction, ReactionType},
+        event::{Event, ReactionAddEvent, TypingStartEvent},
+        id::{ChannelId, MessageId, UserId},
+    };
+    use std::{sync::mpsc, time::Duration};
+    use super::super::{ShardClientMessage, ShardMessenger, ShardRunnerMessage, TypingFilter};
+    use super::CollectorRegistry;
+
+    fn typing(channel_id: u64) -> Event {
+        Event::TypingStart(TypingStartEvent {
+            channel_id: ChannelId(channel_id),
+            timestamp: 0,
+            user_id: UserId(2),
+        })
+    }
+
+    #[test]
+    fn test_registry() {
+        let (tx, rx) = mpsc::channel();
+        let collector = ShardMessenger::new(tx).collect_typing(TypingFilter::new().channel_id(1));
+
+        let mut registry = CollectorRegistry::default();
+        match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::AddCollector(id, sender),
+            ))) => registry.add(id, sender),
+            other => panic!("Unexpected message: {:?}", other),
+        }
+
+        // Neither a typing start in another channel nor another kind of event
+        // is received.
+        registry.collect(&typing(3));
+        registry.collect(&Event::ReactionAdd(ReactionAddEvent {
+            reaction: Reaction {
+                channel_id: ChannelId(1),
+                emoji: ReactionType::Unicode("✅".to_string()),
+                message_id: MessageId(4),
+                user_id: UserId(2),
+            },
+        }));
+        assert!(collector.try_receive().is_none());
+
+        registry.collect(&typing(1));
+        assert_eq!(collector.receive(Duration::from_millis(1)).unwrap().channel_id, 1);
+        assert_eq!(registry.0.len(), 1);
+
+        // A dropped collector is no longer sent to, even if its removal has
+        // not been received yet.
+        drop(collector);
+        registry.collect(&typing(1));
+        assert!(registry.0.is_empty());
+    }
+}
@@ -3,12 +3,32 @@
 
 use super::ShardId;
 use crate::gateway::ConnectionStage;
+use websocket::message::CloseData;
 
 #[derive(Clone, Debug)]
 pub(crate) enum ClientEvent {
+    ShardDisconnect(ShardDisconnectEvent),
     ShardStageUpdate(ShardStageUpdateEvent),
 }
 
+/// An event denoting that a shard's connection was closed by the gateway.
+///
+/// # Examples
+///
+/// This might happen when the gateway closes the connection with a close code
+/// of [`AUTHENTICATION_FAILED`] or [`SHARDING_REQUIRED`].
+///
+/// [`AUTHENTICATION_FAILED`]: ../../../../constants/close_codes/constant.AUTHENTICATION_FAILED.html
+/// [`SHARDING_REQUIRED`]: ../../../../constants/close_codes/constant.SHARDING_REQUIRED.html
+#[derive(Clone, Debug)]
+pub struct ShardDisconnectEvent {
+    /// The status code and reason that the connection was closed with, if the
+    /// gateway provided any.
+    pub close_data: Option<CloseData>,
+    /// The ID of the shard that was disconnected.
+    pub shard_id: ShardId,
+}
+
 /// An event denoting that a shard's connection stage was changed.
 ///
 /// # Examples
@@ -0,0 +1,125 @@
+use crate::model::{
+    channel::Message,
+    event::Event,
+    id::{ChannelId, UserId},
+};
+use super::{Collectable, Collector, Filter};
+
+/// A filter deciding which created messages a [`MessageCollector`] receives.
+///
+/// An unset or empty criterion matches any message, so a default filter
+/// matches every message the shard receives.
+///
+/// [`MessageCollector`]: struct.MessageCollector.html
+#[derive(Clone, Debug, Default)]
+pub struct MessageFilter {
+    channel_id: Option<ChannelId>,
+    users: Vec<UserId>,
+}
+
+impl MessageFilter {
+    /// Creates a filter matching every message.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches messages sent in the given channel.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Adds a user to the set of users whose messages to match.
+    pub fn user<U: Into<UserId>>(mut self, user_id: U) -> Self {
+        self.users.push(user_id.into());
+
+        self
+    }
+}
+
+impl Filter<Message> for MessageFilter {
+    fn matches(&self, message: &Message) -> bool {
+        if let Some(channel_id) = self.channel_id {
+            if message.channel_id != channel_id {
+                return false;
+            }
+        }
+
+        self.users.is_empty() || self.users.contains(&message.author.id)
+    }
+}
+
+/// Receives the messages that match a [`MessageFilter`], as they are received
+/// by a shard.
+///
+/// This is created via [`ShardMessenger::collect_messages`], which registers
+/// the collector with the shard's [`ShardRunner`]. The collector is
+/// unregistered when dropped, so giving up on a reply does not leave the
+/// filter behind.
+///
+/// To wait for a single reply, [`ShardMessenger::await_reply`] can be used
+/// instead.
+///
+/// [`MessageFilter`]: struct.MessageFilter.html
+/// [`ShardMessenger::await_reply`]: struct.ShardMessenger.html#method.await_reply
+/// [`ShardMessenger::collect_messages`]: struct.ShardMessenger.html#method.collect_messages
+/// [`ShardRunner`]: struct.ShardRunner.html
+pub type MessageCollector = Collector<Message, MessageFilter>;
+
+impl Collectable for Message {
+    fn from_event(event: &Event) -> Option<&Self> {
+        match *event {
+            Event::MessageCreate(ref event) => Some(&event.message),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gateway::InterMessage;
+    use crate::model::id::{ChannelId, UserId};
+    use std::{sync::mpsc, time::Duration};
+    use super::super::{ShardClientMessage, ShardMessenger, ShardRunnerMessage};
+
+    #[test]
+    fn test_await_reply_unregisters() {
+        let (tx, rx) = mpsc::channel();
+        let messenger = ShardMessenger::new(tx);
+
+        let reply = messenger.await_reply(ChannelId(1), UserId(2), Duration::from_millis(1));
+        assert!(reply.is_none());
+
+        let id = match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::AddCollector(id, _),
+            ))) => id,
+            other => panic!("Unexpected message: {:?}", other),
+        };
+
+        assert!(match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::RemoveCollector(removed),
+            ))) => removed == id,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_prompt_reply_registers_first() {
+        let (tx, rx) = mpsc::channel();
+        let messenger = ShardMessenger::new(tx);
+
+        let reply = messenger.prompt_reply(ChannelId(1), UserId(2), Duration::from_millis(1), || {
+            match rx.try_recv() {
+                Ok(InterMessage::Client(ShardClientMessage::Runner(
+                    ShardRunnerMessage::AddCollector(_, _),
+                ))) => Ok(()),
+                other => panic!("Prompted before registering: {:?}", other),
+            }
+        });
+        assert!(reply.unwrap().is_none());
+    }
+}
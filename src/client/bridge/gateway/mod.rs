@@ -50,19 +50,39 @@
 
 pub mod event;
 
+mod collector;
+mod message_collector;
+#[cfg(feature = "model")]
+mod paginator;
+mod presence_builder;
+mod reaction_collector;
 mod shard_manager;
 mod shard_manager_monitor;
 mod shard_messenger;
 mod shard_queuer;
 mod shard_runner;
 mod shard_runner_message;
+mod typing_collector;
 
+pub use self::collector::{
+    Collectable,
+    Collector,
+    CollectorRegistry,
+    CollectorSender,
+    Filter,
+};
+pub use self::message_collector::{MessageCollector, MessageFilter};
+#[cfg(feature = "model")]
+pub use self::paginator::Paginator;
+pub use self::presence_builder::PresenceBuilder;
+pub use self::reaction_collector::{ReactionCollector, ReactionFilter};
 pub use self::shard_manager::{ShardManager, ShardManagerOptions};
 pub use self::shard_manager_monitor::ShardManagerMonitor;
 pub use self::shard_messenger::ShardMessenger;
 pub use self::shard_queuer::ShardQueuer;
 pub use self::shard_runner::{ShardRunner, ShardRunnerOptions};
 pub use self::shard_runner_message::ShardRunnerMessage;
+pub use self::typing_collector::{TypingCollector, TypingFilter};
 
 use std::{
     fmt::{
@@ -100,6 +120,18 @@ pub enum ShardManagerMessage {
     ///
     /// [`ShardManagerMonitor`]: struct.ShardManagerMonitor.html
     Restart(ShardId),
+    /// Indicator that a shard's connection was closed because the session
+    /// start limit was reached, in which case the [`ShardManagerMonitor`]
+    /// restarts the shard once the limit has reset.
+    ///
+    /// [`ShardManagerMonitor`]: struct.ShardManagerMonitor.html
+    SessionStartLimitReached(ShardId),
+    /// Indicator that a shard failed with an error that it can not recover
+    /// from, such as invalid authentication, in which case the
+    /// [`ShardManagerMonitor`] shuts down all shards.
+    ///
+    /// [`ShardManagerMonitor`]: struct.ShardManagerMonitor.html
+    ShardFailure(ShardId),
     /// An update from a shard runner,
     ShardUpdate {
         id: ShardId,
@@ -0,0 +1,240 @@
+use crate::builder::CreateEmbed;
+use crate::internal::prelude::*;
+use crate::model::{
+    channel::{Reaction, ReactionType},
+    id::{ChannelId, UserId},
+};
+use std::time::Duration;
+use super::{Filter, ReactionFilter, ShardMessenger};
+
+#[cfg(feature = "cache")]
+use crate::CACHE;
+
+/// Posts a list of embeds as a single message whose pages are navigated with
+/// reactions.
+///
+/// The first page is sent, and the previous, next, and stop reactions are
+/// added to the message. Each matching reaction then edits the message to
+/// show the previous or next page, wrapping around at either end, until the
+/// stop reaction is added or no reaction is added within the [`timeout`].
+///
+/// When the current user has the [Manage Messages] permission in the channel,
+/// users' navigation reactions are removed as they are handled, and all
+/// reactions are removed once the paginator stops. Otherwise only the current
+/// user's own reactions are removed at the end.
+///
+/// # Examples
+///
+/// Paginate a leaderboard, letting only the author navigate it:
+///
+/// ```rust,no_run
+/// # #[macro_use] extern crate serenity;
+/// #
+/// # #[cfg(all(feature = "client", feature = "model", feature = "standard_framework"))]
+/// # mod inner {
+/// use serenity::builder::CreateEmbed;
+/// use serenity::client::bridge::gateway::Paginator;
+///
+/// command!(leaderboard(ctx, msg) {
+///     let pages = (1..=5).map(|page| {
+///         CreateEmbed::default()
+///             .title("Leaderboard")
+///             .description(format!("Ranks {} to {}", page * 10 - 9, page * 10))
+///             .footer(|f| f.text(format!("Page {}/5", page)))
+///     }).collect();
+///
+///     Paginator::new(pages)
+///         .user(msg.author.id)
+///         .run(&ctx.shard, msg.channel_id)?;
+/// });
+/// # }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// [`timeout`]: #method.timeout
+/// [Manage Messages]: ../../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+#[derive(Clone, Debug)]
+pub struct Paginator {
+    next: ReactionType,
+    pages: Vec<CreateEmbed>,
+    previous: ReactionType,
+    stop: ReactionType,
+    timeout: Duration,
+    users: Vec<UserId>,
+}
+
+impl Paginator {
+    /// Creates a paginator over the given pages.
+    ///
+    /// By default, `⏪` and `⏩` navigate the pages, `❌` stops the
+    /// paginator, anyone may navigate, and the paginator stops after 60
+    /// seconds without a navigation reaction.
+    pub fn new(pages: Vec<CreateEmbed>) -> Self {
+        Self {
+            next: '⏩'.into(),
+            pages,
+            previous: '⏪'.into(),
+            stop: '❌'.into(),
+            timeout: Duration::from_secs(60),
+            users: Vec::new(),
+        }
+    }
+
+    /// Sets the emoji showing the next page.
+    pub fn next<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.next = emoji.into();
+
+        self
+    }
+
+    /// Sets the emoji showing the previous page.
+    pub fn previous<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.previous = emoji.into();
+
+        self
+    }
+
+    /// Sets the emoji stopping the paginator.
+    pub fn stop<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.stop = emoji.into();
+
+        self
+    }
+
+    /// Sets how long to wait for a navigation reaction before stopping.
+    ///
+    /// The wait restarts after each handled reaction.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Adds a user to the set of users allowed to navigate the pages.
+    ///
+    /// If no users are added, anyone may navigate.
+    pub fn user<U: Into<UserId>>(mut self, user_id: U) -> Self {
+        self.users.push(user_id.into());
+
+        self
+    }
+
+    /// Sends the first page to the channel and handles navigation reactions
+    /// received by the shard, blocking until the paginator stops.
+    ///
+    /// Nothing is sent if there are no pages, and no reactions are added if
+    /// there is only one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if sending or editing the message, or adding or
+    /// removing its reactions, fails.
+    pub fn run(self, shard: &ShardMessenger, channel_id: ChannelId) -> Result<()> {
+        let Paginator { next, pages, previous, stop, timeout, users } = self;
+
+        let mut message = match pages.first() {
+            Some(page) => channel_id.send_message(|m| m.embed(|_| page.clone()))?,
+            None => return Ok(()),
+        };
+
+        if pages.len() == 1 {
+            return Ok(());
+        }
+
+        let filter = users.into_iter().fold(ReactionFilter::new(), |filter, user_id| {
+            filter.user(user_id)
+        });
+        let collector = shard.collect_reactions(filter
+            .message_id(message.id)
+            .emoji(previous.clone())
+            .emoji(next.clone())
+            .emoji(stop.clone()));
+
+        message.react(previous.clone())?;
+        message.react(next.clone())?;
+        message.react(stop.clone())?;
+
+        // The message's author is the current user, whose own reactions are
+        // also received by the collector.
+        let current_user_id = message.author.id;
+        let can_manage = can_manage_messages(channel_id, current_user_id);
+        let mut index = 0;
+
+        while let Some(reaction) = collector.receive(timeout) {
+            if reaction.user_id == current_user_id {
+                continue;
+            }
+
+            if is_emoji(&reaction, &stop) {
+                break;
+            }
+
+            if can_manage {
+                let _ = channel_id.delete_reaction(
+                    message.id,
+                    Some(reaction.user_id),
+                    reaction.emoji.clone(),
+                );
+            }
+
+            let forward = !is_emoji(&reaction, &previous);
+            index = turn_page(index, pages.len(), forward);
+
+            let page = &pages[index];
+            message.edit(|m| m.embed(|_| page.clone()))?;
+        }
+
+        if can_manage {
+            return message.delete_reactions();
+        }
+
+        channel_id.delete_reaction(message.id, None, previous)?;
+        channel_id.delete_reaction(message.id, None, next)?;
+        channel_id.delete_reaction(message.id, None, stop)
+    }
+}
+
+fn is_emoji(reaction: &Reaction, emoji: &ReactionType) -> bool {
+    ReactionFilter::new().emoji(emoji.clone()).matches(reaction)
+}
+
+fn turn_page(index: usize, len: usize, forward: bool) -> usize {
+    if forward {
+        (index + 1) % len
+    } else if index == 0 {
+        len - 1
+    } else {
+        index - 1
+    }
+}
+
+#[cfg(feature = "cache")]
+fn can_manage_messages(channel_id: ChannelId, user_id: UserId) -> bool {
+    let channel = match CACHE.read().guild_channel(channel_id) {
+        Some(channel) => channel,
+        None => return false,
+    };
+
+    let permissions = channel.read().permissions_for(user_id);
+
+    permissions.map(|permissions| permissions.manage_messages()).unwrap_or(false)
+}
+
+#[cfg(not(feature = "cache"))]
+fn can_manage_messages(_: ChannelId, _: UserId) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::turn_page;
+
+    #[test]
+    fn test_turn_page() {
+        assert_eq!(turn_page(0, 3, true), 1);
+        assert_eq!(turn_page(2, 3, true), 0);
+        assert_eq!(turn_page(1, 3, false), 0);
+        assert_eq!(turn_page(0, 3, false), 2);
+    }
+}
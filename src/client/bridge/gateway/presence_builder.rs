@@ -0,0 +1,137 @@
+use crate::gateway::Shard;
+use crate::model::{gateway::Game, user::OnlineStatus};
+
+/// A set of changes to a shard's presence, applied all at once.
+///
+/// Only the parts of the presence that are set on the builder are changed;
+/// the rest of the shard's presence is maintained.
+///
+/// This is used via [`ShardMessenger::update_presence`].
+///
+/// [`ShardMessenger::update_presence`]: struct.ShardMessenger.html#method.update_presence
+#[derive(Clone, Debug, Default)]
+pub struct PresenceBuilder {
+    afk: Option<bool>,
+    game: Option<Option<Game>>,
+    since: Option<Option<u64>>,
+    status: Option<OnlineStatus>,
+}
+
+impl PresenceBuilder {
+    /// Sets whether the presence is marked as AFK.
+    pub fn afk(mut self, afk: bool) -> Self {
+        self.afk = Some(afk);
+
+        self
+    }
+
+    /// Sets the game being played, or clears it if `None` is given.
+    pub fn game<T: Into<Game>>(mut self, game: Option<T>) -> Self {
+        self.game = Some(game.map(Into::into));
+
+        self
+    }
+
+    /// Sets the time in milliseconds since the Unix epoch since which the user
+    /// has been idle.
+    ///
+    /// If this is `None` and the status is [`Idle`], the time the presence is
+    /// sent is used.
+    ///
+    /// [`Idle`]: ../../../model/user/enum.OnlineStatus.html#variant.Idle
+    pub fn since(mut self, since: Option<u64>) -> Self {
+        self.since = Some(since);
+
+        self
+    }
+
+    /// Sets the online status.
+    ///
+    /// Note that [`Offline`] is not a valid online status, so it is
+    /// automatically converted to [`Invisible`].
+    ///
+    /// [`Invisible`]: ../../../model/user/enum.OnlineStatus.html#variant.Invisible
+    /// [`Offline`]: ../../../model/user/enum.OnlineStatus.html#variant.Offline
+    pub fn status(mut self, status: OnlineStatus) -> Self {
+        self.status = Some(status);
+
+        self
+    }
+
+    /// Sets every part of the presence back to its default: online, with no
+    /// game, and not AFK.
+    pub fn reset(self) -> Self {
+        self.status(OnlineStatus::Online)
+            .game(None::<Game>)
+            .afk(false)
+            .since(None)
+    }
+
+    /// Applies the changes to the shard's presence, without sending it.
+    pub(super) fn apply(self, shard: &mut Shard) {
+        if let Some(afk) = self.afk {
+            shard.set_afk(afk);
+        }
+
+        if let Some(game) = self.game {
+            shard.set_game(game);
+        }
+
+        if let Some(since) = self.since {
+            shard.set_since(since);
+        }
+
+        if let Some(status) = self.status {
+            shard.set_status(status);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gateway::InterMessage;
+    use crate::model::user::OnlineStatus;
+    use std::sync::mpsc;
+    use super::super::{ShardClientMessage, ShardMessenger, ShardRunnerMessage};
+
+    #[test]
+    fn test_update_presence_sends_once() {
+        let (tx, rx) = mpsc::channel();
+        ShardMessenger::new(tx).update_presence(|p| p.status(OnlineStatus::Idle).afk(true));
+
+        let builder = match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::UpdatePresence(builder),
+            ))) => builder,
+            other => panic!("Unexpected message: {:?}", other),
+        };
+
+        assert_eq!(builder.status, Some(OnlineStatus::Idle));
+        assert_eq!(builder.afk, Some(true));
+        assert!(builder.game.is_none());
+        assert!(builder.since.is_none());
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_reset_presence() {
+        let (tx, rx) = mpsc::channel();
+        ShardMessenger::new(tx).reset_presence();
+
+        let builder = match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::UpdatePresence(builder),
+            ))) => builder,
+            other => panic!("Unexpected message: {:?}", other),
+        };
+
+        assert_eq!(builder.status, Some(OnlineStatus::Online));
+        assert_eq!(builder.afk, Some(false));
+        assert!(match builder.game {
+            Some(None) => true,
+            _ => false,
+        });
+        assert_eq!(builder.since, Some(None));
+        assert!(rx.try_recv().is_err());
+    }
+}
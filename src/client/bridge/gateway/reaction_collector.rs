@@ -0,0 +1,208 @@
+use crate::model::{
+    channel::{Reaction, ReactionType},
+    event::Event,
+    id::{MessageId, UserId},
+};
+use super::{Collectable, Collector, Filter};
+
+/// A filter deciding which added reactions a [`ReactionCollector`] receives.
+///
+/// An unset or empty criterion matches any reaction, so a default filter
+/// matches every reaction the shard receives.
+///
+/// [`ReactionCollector`]: struct.ReactionCollector.html
+#[derive(Clone, Debug, Default)]
+pub struct ReactionFilter {
+    emojis: Vec<ReactionType>,
+    message_id: Option<MessageId>,
+    users: Vec<UserId>,
+}
+
+impl ReactionFilter {
+    /// Creates a filter matching every reaction.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an emoji to the set of emojis to match.
+    ///
+    /// Custom emojis are matched by their Id alone.
+    pub fn emoji<R: Into<ReactionType>>(mut self, emoji: R) -> Self {
+        self.emojis.push(emoji.into());
+
+        self
+    }
+
+    /// Only matches reactions to the given message.
+    pub fn message_id<M: Into<MessageId>>(mut self, message_id: M) -> Self {
+        self.message_id = Some(message_id.into());
+
+        self
+    }
+
+    /// Adds a user to the set of users whose reactions to match.
+    pub fn user<U: Into<UserId>>(mut self, user_id: U) -> Self {
+        self.users.push(user_id.into());
+
+        self
+    }
+}
+
+impl Filter<Reaction> for ReactionFilter {
+    fn matches(&self, reaction: &Reaction) -> bool {
+        if let Some(message_id) = self.message_id {
+            if reaction.message_id != message_id {
+                return false;
+            }
+        }
+
+        if !self.users.is_empty() && !self.users.contains(&reaction.user_id) {
+            return false;
+        }
+
+        self.emojis.is_empty() || self.emojis.iter().any(|emoji| {
+            match (emoji, &reaction.emoji) {
+                (ReactionType::Custom { id, .. }, ReactionType::Custom { id: other, .. }) => {
+                    id == other
+                },
+                (ReactionType::Unicode(name), ReactionType::Unicode(other)) => name == other,
+                _ => false,
+            }
+        })
+    }
+}
+
+/// Receives the reactions added to messages that match a [`ReactionFilter`],
+/// as they are received by a shard.
+///
+/// This is created via [`ShardMessenger::collect_reactions`], which registers
+/// the collector with the shard's [`ShardRunner`]. The collector is
+/// unregistered when dropped.
+///
+/// # Examples
+///
+/// Ask for confirmation, waiting up to 30 seconds for the author to react:
+///
+/// ```rust,no_run
+/// # #[macro_use] extern crate serenity;
+/// #
+/// # #[cfg(all(feature = "client", feature = "standard_framework"))]
+/// # mod inner {
+/// use serenity::client::bridge::gateway::ReactionFilter;
+/// use std::time::Duration;
+///
+/// command!(confirm(ctx, msg) {
+///     let prompt = msg.channel_id.say("Are you sure?")?;
+///     prompt.react('✅')?;
+///     prompt.react('❌')?;
+///
+///     let collector = ctx.shard.collect_reactions(ReactionFilter::new()
+///         .message_id(prompt.id)
+///         .user(msg.author.id)
+///         .emoji('✅')
+///         .emoji('❌'));
+///
+///     match collector.receive(Duration::from_secs(30)) {
+///         Some(ref reaction) if reaction.emoji == '✅'.into() => {
+///             msg.channel_id.say("Confirmed.")?;
+///         },
+///         Some(_) => {
+///             msg.channel_id.say("Cancelled.")?;
+///         },
+///         None => {
+///             msg.channel_id.say("Timed out.")?;
+///         },
+///     }
+/// });
+/// # }
+/// #
+/// # fn main() {}
+/// ```
+///
+/// [`ReactionFilter`]: struct.ReactionFilter.html
+/// [`ShardMessenger::collect_reactions`]: struct.ShardMessenger.html#method.collect_reactions
+/// [`ShardRunner`]: struct.ShardRunner.html
+pub type ReactionCollector = Collector<Reaction, ReactionFilter>;
+
+impl Collectable for Reaction {
+    fn from_event(event: &Event) -> Option<&Self> {
+        match *event {
+            Event::ReactionAdd(ref event) => Some(&event.reaction),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gateway::InterMessage;
+    use crate::model::prelude::*;
+    use std::{sync::mpsc, time::Duration};
+    use super::super::collector::CollectorRegistry;
+    use super::super::{Filter, ShardClientMessage, ShardMessenger, ShardRunnerMessage};
+    use super::ReactionFilter;
+
+    fn reaction(message_id: u64, user_id: u64, emoji: ReactionType) -> Reaction {
+        Reaction {
+            channel_id: ChannelId(1),
+            emoji,
+            message_id: MessageId(message_id),
+            user_id: UserId(user_id),
+        }
+    }
+
+    #[test]
+    fn test_filter() {
+        assert!(ReactionFilter::new().matches(&reaction(2, 3, '✅'.into())));
+
+        let filter = ReactionFilter::new()
+            .message_id(2)
+            .user(3)
+            .emoji('✅')
+            .emoji(EmojiId(4));
+        assert!(filter.matches(&reaction(2, 3, '✅'.into())));
+        assert!(filter.matches(&reaction(2, 3, ReactionType::Custom {
+            animated: false,
+            id: EmojiId(4),
+            name: Some("custom".to_string()),
+        })));
+        assert!(!filter.matches(&reaction(2, 3, '❌'.into())));
+        assert!(!filter.matches(&reaction(5, 3, '✅'.into())));
+        assert!(!filter.matches(&reaction(2, 5, '✅'.into())));
+    }
+
+    #[test]
+    fn test_collector_registration() {
+        let (tx, rx) = mpsc::channel();
+        let collector = ShardMessenger::new(tx).collect_reactions(ReactionFilter::new());
+
+        let mut registry = CollectorRegistry::default();
+        let id = match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::AddCollector(id, sender),
+            ))) => {
+                registry.add(id, sender);
+
+                id
+            },
+            other => panic!("Unexpected message: {:?}", other),
+        };
+
+        assert!(collector.receive(Duration::from_millis(1)).is_none());
+
+        registry.collect(&Event::ReactionAdd(ReactionAddEvent {
+            reaction: reaction(2, 3, '✅'.into()),
+        }));
+        assert_eq!(collector.receive(Duration::from_millis(1)).unwrap().message_id, 2);
+
+        drop(collector);
+
+        assert!(match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::RemoveCollector(removed),
+            ))) => removed == id,
+            _ => false,
+        });
+    }
+}
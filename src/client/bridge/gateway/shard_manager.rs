@@ -1,13 +1,17 @@
-use crate::gateway::InterMessage;
+use crate::gateway::{CurrentPresence, InterMessage};
 use crate::internal::prelude::*;
+use crate::model::gateway::{Game, SessionStartLimit};
+use crate::model::user::OnlineStatus;
 use parking_lot::Mutex;
 use std::{
     collections::{HashMap, VecDeque},
     sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         mpsc::{self, Sender},
         Arc
     },
-    thread
+    thread,
+    time::{Duration, Instant}
 };
 use super::super::super::EventHandler;
 use super::{
@@ -15,6 +19,7 @@ use super::{
     ShardId,
     ShardManagerMessage,
     ShardManagerMonitor,
+    ShardMessenger,
     ShardQueuer,
     ShardQueuerMessage,
     ShardRunnerInfo,
@@ -22,11 +27,17 @@ use super::{
 use threadpool::ThreadPool;
 use typemap::ShareMap;
 
+#[cfg(feature = "cache")]
+use crate::CACHE;
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 #[cfg(feature = "voice")]
 use client::bridge::voice::ClientVoiceManager;
 
+/// The default maximum number of consecutive restarts of a shard before the
+/// shard manager gives up on it.
+const DEFAULT_MAX_RESTART_ATTEMPTS: u32 = 10;
+
 /// A manager for handling the status of shards by starting them, restarting
 /// them, and stopping them when required.
 ///
@@ -110,7 +121,22 @@ use client::bridge::voice::ClientVoiceManager;
 /// [`Client`]: ../../struct.Client.html
 #[derive(Debug)]
 pub struct ShardManager {
+    /// The number of identify buckets shards may be started in concurrently,
+    /// shared with the shard queuer.
+    max_concurrency: Arc<AtomicUsize>,
+    /// The shard whose failure shut down all shards, if any.
+    failed_shard: Option<ShardId>,
+    /// The maximum number of consecutive restarts of a shard, if any.
+    max_restart_attempts: Option<u32>,
     monitor_tx: Sender<ShardManagerMessage>,
+    /// The presence set for all shards via `set_presence_all`, which is given
+    /// to shards as they are started.
+    presence: Arc<Mutex<Option<CurrentPresence>>>,
+    /// The number of consecutive restarts of each shard since it was last
+    /// connected.
+    restart_attempts: HashMap<ShardId, u32>,
+    /// The last known session start limit, shared with the shard queuer.
+    session_start_limit: Arc<Mutex<Option<(SessionStartLimit, Instant)>>>,
     /// The shard runners currently managed.
     ///
     /// **Note**: It is highly unrecommended to mutate this yourself unless you
@@ -124,6 +150,11 @@ pub struct ShardManager {
     /// The total shards in use, 1-indexed.
     shard_total: u64,
     shard_queuer: Sender<ShardQueuerMessage>,
+    /// Whether a shutdown of all shards has been initiated, in which case no
+    /// shards will be restarted.
+    shutting_down: Arc<AtomicBool>,
+    /// The number of shard runner threads that are still running.
+    running: Arc<AtomicUsize>,
 }
 
 impl ShardManager {
@@ -136,17 +167,28 @@ impl ShardManager {
         let (shard_queue_tx, shard_queue_rx) = mpsc::channel();
 
         let runners = Arc::new(Mutex::new(HashMap::new()));
+        let shutting_down = Arc::new(AtomicBool::new(false));
+        let running = Arc::new(AtomicUsize::new(0));
+        let presence = Arc::new(Mutex::new(None));
+        let max_concurrency = Arc::new(AtomicUsize::new(1));
+        let session_start_limit = Arc::new(Mutex::new(None));
 
         let mut shard_queuer = ShardQueuer {
+            collectors: HashMap::new(),
             data: Arc::clone(opt.data),
             event_handler: Arc::clone(opt.event_handler),
             #[cfg(feature = "framework")]
             framework: Arc::clone(opt.framework),
-            last_start: None,
+            last_starts: HashMap::new(),
             manager_tx: thread_tx.clone(),
+            max_concurrency: Arc::clone(&max_concurrency),
+            presence: Arc::clone(&presence),
             queue: VecDeque::new(),
             runners: Arc::clone(&runners),
+            running: Arc::clone(&running),
             rx: shard_queue_rx,
+            session_start_limit: Arc::clone(&session_start_limit),
+            shutting_down: Arc::clone(&shutting_down),
             threadpool: opt.threadpool,
             token: Arc::clone(opt.token),
             #[cfg(feature = "voice")]
@@ -159,12 +201,20 @@ impl ShardManager {
         });
 
         let manager = Arc::new(Mutex::new(Self {
+            max_concurrency,
+            failed_shard: None,
+            max_restart_attempts: Some(DEFAULT_MAX_RESTART_ATTEMPTS),
             monitor_tx: thread_tx,
+            presence,
+            restart_attempts: HashMap::new(),
+            session_start_limit,
             shard_index: opt.shard_index,
             shard_init: opt.shard_init,
             shard_queuer: shard_queue_tx,
             shard_total: opt.shard_total,
             runners,
+            shutting_down,
+            running,
         }));
 
         (Arc::clone(&manager), ShardManagerMonitor {
@@ -185,6 +235,28 @@ impl ShardManager {
         self.runners.lock().contains_key(&shard_id)
     }
 
+    /// Returns the number of guilds in the cache that belong to the given
+    /// shard.
+    ///
+    /// Refer to [`Shard::guild_count`] for more information.
+    ///
+    /// [`Shard::guild_count`]: ../../../gateway/struct.Shard.html#method.guild_count
+    #[cfg(feature = "cache")]
+    pub fn guild_count(&self, shard_id: ShardId) -> usize {
+        CACHE.read().guild_count_for_shard(shard_id.0)
+    }
+
+    /// Returns whether a shutdown of all shards has been initiated via
+    /// [`shutdown_all`].
+    ///
+    /// While this is the case, shards are not restarted, even when a shard
+    /// runner requests that its shard be reconnected.
+    ///
+    /// [`shutdown_all`]: #method.shutdown_all
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutting_down.load(Ordering::SeqCst)
+    }
+
     /// Initializes all shards that the manager is responsible for.
     ///
     /// This will communicate shard boots with the [`ShardQueuer`] so that they
@@ -210,6 +282,7 @@ impl ShardManager {
     /// This will _not_ instantiate the new shards.
     pub fn set_shards(&mut self, index: u64, init: u64, total: u64) {
         self.shutdown_all();
+        self.shutting_down.store(false, Ordering::SeqCst);
 
         self.shard_index = index;
         self.shard_init = init;
@@ -250,6 +323,12 @@ impl ShardManager {
     /// [`ShardRunner`]: struct.ShardRunner.html
     /// [`initialize`]: #method.initialize
     pub fn restart(&mut self, shard_id: ShardId) {
+        if self.is_shutting_down() {
+            info!("Not restarting shard {} during shutdown", shard_id);
+
+            return;
+        }
+
         info!("Restarting shard {}", shard_id);
         self.shutdown(shard_id);
 
@@ -258,6 +337,223 @@ impl ShardManager {
         self.boot([shard_id, ShardId(shard_total)]);
     }
 
+    /// Restarts a shard whose connection was closed because the session start
+    /// limit was reached.
+    ///
+    /// No session starts are assumed to remain, so the [`ShardQueuer`] waits
+    /// for the limit to reset before starting the shard, or any other.
+    ///
+    /// [`ShardQueuer`]: struct.ShardQueuer.html
+    pub(super) fn restart_after_session_start_limit(&mut self, shard_id: ShardId) {
+        if let Some((ref mut limit, _)) = *self.session_start_limit.lock() {
+            limit.remaining = 0;
+        }
+
+        self.restart(shard_id);
+    }
+
+    /// Sets the presence of every shard the manager is responsible for.
+    ///
+    /// Shards which are reconnecting update their presence once they are
+    /// connected again, and shards which are (re)started afterwards are
+    /// started with this presence.
+    ///
+    /// **Note**: [`Offline`] is not a valid online status, so it is
+    /// automatically converted to [`Invisible`].
+    ///
+    /// # Examples
+    ///
+    /// Showing the number of guilds the bot is in, from an [`EventHandler`]:
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "cache")]
+    /// # fn main() {
+    /// use serenity::model::gateway::{Game, Ready};
+    /// use serenity::model::user::OnlineStatus;
+    /// use serenity::prelude::*;
+    /// use serenity::CACHE;
+    /// use std::env;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {}
+    ///
+    /// let token = env::var("DISCORD_TOKEN").unwrap();
+    /// let client = Client::new(&token, Handler).unwrap();
+    ///
+    /// let name = format!("with {} servers", CACHE.read().guilds.len());
+    /// client.shard_manager.lock().set_presence_all(Some(Game::playing(&name)), OnlineStatus::Online);
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "cache"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`EventHandler`]: ../../trait.EventHandler.html
+    /// [`Invisible`]: ../../../model/user/enum.OnlineStatus.html#variant.Invisible
+    /// [`Offline`]: ../../../model/user/enum.OnlineStatus.html#variant.Offline
+    pub fn set_presence_all<T: Into<Game>>(&self, game: Option<T>, status: OnlineStatus) {
+        self._set_presence_all(game.map(Into::into), status)
+    }
+
+    fn _set_presence_all(&self, game: Option<Game>, mut status: OnlineStatus) {
+        if status == OnlineStatus::Offline {
+            status = OnlineStatus::Invisible;
+        }
+
+        *self.presence.lock() = Some((game.clone(), status));
+
+        for runner in self.runners.lock().values() {
+            ShardMessenger::new(runner.runner_tx.clone()).set_presence(game.clone(), status);
+        }
+    }
+
+    /// Sets the presence of only the given shards, leaving the presence of
+    /// every other shard untouched.
+    ///
+    /// Ids of shards that the manager is not currently running are ignored.
+    ///
+    /// Unlike [`set_presence_all`], the presence is not remembered: a shard
+    /// that is restarted afterwards starts with the presence set for all
+    /// shards, if any.
+    ///
+    /// **Note**: [`Offline`] is not a valid online status, so it is
+    /// automatically converted to [`Invisible`].
+    ///
+    /// # Examples
+    ///
+    /// Mark the first two shards as being under maintenance:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::gateway::Game;
+    /// use serenity::model::user::OnlineStatus;
+    /// use serenity::prelude::*;
+    /// use std::env;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {}
+    ///
+    /// let token = env::var("DISCORD_TOKEN").unwrap();
+    /// let client = Client::new(&token, Handler).unwrap();
+    ///
+    /// client.shard_manager.lock().set_status_for_shards(
+    ///     &[0, 1],
+    ///     OnlineStatus::DoNotDisturb,
+    ///     Some(Game::playing("maintenance")),
+    /// );
+    /// ```
+    ///
+    /// [`Invisible`]: ../../../model/user/enum.OnlineStatus.html#variant.Invisible
+    /// [`Offline`]: ../../../model/user/enum.OnlineStatus.html#variant.Offline
+    /// [`set_presence_all`]: #method.set_presence_all
+    pub fn set_status_for_shards<T: Into<Game>>(
+        &self,
+        shard_ids: &[u64],
+        status: OnlineStatus,
+        game: Option<T>,
+    ) {
+        self._set_status_for_shards(shard_ids, status, game.map(Into::into))
+    }
+
+    fn _set_status_for_shards(&self, shard_ids: &[u64], status: OnlineStatus, game: Option<Game>) {
+        let runners = self.runners.lock();
+
+        for shard_id in shard_ids {
+            if let Some(runner) = runners.get(&ShardId(*shard_id)) {
+                ShardMessenger::new(runner.runner_tx.clone()).set_presence(game.clone(), status);
+            }
+        }
+    }
+
+    /// Sets the number of identify buckets that shards may be started in
+    /// concurrently, as given by the [`SessionStartLimit`] of
+    /// [`http::get_bot_gateway`].
+    ///
+    /// Shards are sorted into buckets by `shard_id % max_concurrency`. Shards
+    /// in the same bucket are started 5 seconds apart, while shards in
+    /// different buckets are started without waiting on each other.
+    ///
+    /// **Note**: Defaults to 1, starting every shard 5 seconds apart. A value
+    /// of 0 is treated as 1. [`Client::start_autosharded`] sets this
+    /// automatically.
+    ///
+    /// [`Client::start_autosharded`]: ../../struct.Client.html#method.start_autosharded
+    /// [`SessionStartLimit`]: ../../../model/gateway/struct.SessionStartLimit.html
+    /// [`http::get_bot_gateway`]: ../../../http/fn.get_bot_gateway.html
+    pub fn set_max_concurrency(&mut self, max_concurrency: u64) {
+        self.max_concurrency.store(max_concurrency.max(1) as usize, Ordering::SeqCst);
+    }
+
+    /// Sets the session start limit, as given by [`http::get_bot_gateway`].
+    ///
+    /// Each shard start counts against the limit. Once no session starts
+    /// remain, shards are not started until the limit has reset, rather than
+    /// identifying and risking the token being reset.
+    ///
+    /// **Note**: If this is not set, the [`ShardQueuer`] retrieves the limit
+    /// before starting the first shard.
+    /// [`Client::start_autosharded`] sets this automatically.
+    ///
+    /// [`Client::start_autosharded`]: ../../struct.Client.html#method.start_autosharded
+    /// [`ShardQueuer`]: struct.ShardQueuer.html
+    /// [`http::get_bot_gateway`]: ../../../http/fn.get_bot_gateway.html
+    pub fn set_session_start_limit(&mut self, session_start_limit: SessionStartLimit) {
+        *self.session_start_limit.lock() = Some((session_start_limit, Instant::now()));
+    }
+
+    /// Sets the maximum number of consecutive times a shard may request to be
+    /// restarted without successfully connecting in between. Once a shard
+    /// exceeds this, all shards are shut down and the shard is recorded as the
+    /// [`failed_shard`], rather than restarting the shard endlessly.
+    ///
+    /// Pass `None` to restart shards without a limit.
+    ///
+    /// **Note**: Defaults to 10 attempts.
+    ///
+    /// [`failed_shard`]: #method.failed_shard
+    pub fn set_max_restart_attempts(&mut self, max_restart_attempts: Option<u32>) {
+        self.max_restart_attempts = max_restart_attempts;
+    }
+
+    /// Records a restart requested by a shard, returning whether the shard is
+    /// still within its maximum restart attempts.
+    pub(super) fn record_restart_attempt(&mut self, shard_id: ShardId) -> bool {
+        let attempts = self.restart_attempts.entry(shard_id).or_insert(0);
+        *attempts += 1;
+
+        match self.max_restart_attempts {
+            Some(max) => *attempts <= max,
+            None => true,
+        }
+    }
+
+    /// Resets the count of consecutive restarts of a shard, such as after it
+    /// has successfully connected.
+    pub(super) fn reset_restart_attempts(&mut self, shard_id: ShardId) {
+        self.restart_attempts.remove(&shard_id);
+    }
+
+    /// Returns the Id of the shard that failed with an unrecoverable error or
+    /// exceeded its [maximum restart attempts], if one did, in which case all
+    /// shards were shut down.
+    ///
+    /// This can be checked once the [`ShardManagerMonitor`] stops running to
+    /// tell a failure apart from a requested shutdown.
+    ///
+    /// [`ShardManagerMonitor`]: struct.ShardManagerMonitor.html
+    /// [maximum restart attempts]: #method.set_max_restart_attempts
+    pub fn failed_shard(&self) -> Option<ShardId> {
+        self.failed_shard
+    }
+
+    /// Records the shard as having failed and shuts down all shards.
+    pub(super) fn fail(&mut self, shard_id: ShardId) {
+        self.failed_shard = Some(shard_id);
+
+        self.shutdown_all();
+    }
+
     /// Returns the [`ShardId`]s of the shards that have been instantiated and
     /// currently have a valid [`ShardRunner`].
     ///
@@ -300,11 +596,19 @@ impl ShardManager {
     /// Sends a shutdown message for all shards that the manager is responsible
     /// for that are still known to be running.
     ///
+    /// Each shard closes its connection with a close code of 1000. Once this
+    /// has been called, the manager no longer restarts shards, so shards that
+    /// disconnect while shutting down are not re-established.
+    ///
     /// If you only need to shutdown a select number of shards, prefer looping
-    /// over the [`shutdown`] method.
+    /// over the [`shutdown`] method. To also wait for the shards to finish
+    /// shutting down, use [`shutdown_all_timeout`].
     ///
     /// [`shutdown`]: #method.shutdown
+    /// [`shutdown_all_timeout`]: #method.shutdown_all_timeout
     pub fn shutdown_all(&mut self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+
         let keys = {
             let runners = self.runners.lock();
 
@@ -325,6 +629,67 @@ impl ShardManager {
         let _ = self.monitor_tx.send(ShardManagerMessage::ShutdownInitiated);
     }
 
+    /// Shuts down all shards via [`shutdown_all`], and then waits for up to
+    /// the given duration for every shard runner to finish.
+    ///
+    /// The manager is only locked to shut down the shards, and not while
+    /// waiting, so that it remains usable by the shard manager monitor and
+    /// other threads in the meantime.
+    ///
+    /// Returns whether all shard runners finished within the timeout.
+    ///
+    /// # Examples
+    ///
+    /// Shutting down all shards, giving them 5 seconds to close their
+    /// connections:
+    ///
+    /// ```rust,no_run
+    /// use serenity::client::bridge::gateway::ShardManager;
+    /// use serenity::client::{Client, EventHandler};
+    /// use std::env;
+    /// use std::sync::Arc;
+    /// use std::time::Duration;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler { }
+    ///
+    /// let token = env::var("DISCORD_TOKEN").unwrap();
+    /// let client = Client::new(&token, Handler).unwrap();
+    /// let shard_manager = Arc::clone(&client.shard_manager);
+    ///
+    /// // in a signal handler, for example:
+    /// let timeout = Duration::from_secs(5);
+    ///
+    /// if !ShardManager::shutdown_all_timeout(&shard_manager, timeout) {
+    ///     println!("Not all shards shut down in time");
+    /// }
+    /// ```
+    ///
+    /// [`shutdown_all`]: #method.shutdown_all
+    pub fn shutdown_all_timeout(manager: &Mutex<Self>, timeout: Duration) -> bool {
+        let running = {
+            let mut manager = manager.lock();
+            manager.shutdown_all();
+
+            Arc::clone(&manager.running)
+        };
+
+        let start = Instant::now();
+
+        while running.load(Ordering::SeqCst) > 0 {
+            if start.elapsed() >= timeout {
+                warn!("Shards did not shut down within {:?}", timeout);
+
+                return false;
+            }
+
+            thread::sleep(Duration::from_millis(50));
+        }
+
+        true
+    }
+
     fn boot(&mut self, shard_info: [ShardId; 2]) {
         info!("Telling shard queuer to start shard {}", shard_info[0]);
 
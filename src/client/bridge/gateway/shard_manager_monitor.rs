@@ -1,3 +1,4 @@
+use crate::gateway::ConnectionStage;
 use parking_lot::Mutex;
 use std::sync::{
     mpsc::Receiver,
@@ -33,17 +34,53 @@ impl ShardManagerMonitor {
     /// - an error is returned while receiving a message from the
     /// channel (probably indicating that the shard manager should stop anyway)
     ///
+    /// - a shard has failed with an unrecoverable error, or has exceeded its
+    ///   [maximum restart attempts], in which case all shards are shut down
+    ///   and the shard is recorded as the manager's [`failed_shard`]
+    ///
     /// [`ShardManagerMessage::ShutdownAll`]: enum.ShardManagerMessage.html#variant.ShutdownAll
+    /// [`failed_shard`]: struct.ShardManager.html#method.failed_shard
+    /// [maximum restart attempts]: struct.ShardManager.html#method.set_max_restart_attempts
     pub fn run(&mut self) {
         debug!("Starting shard manager worker");
 
         while let Ok(value) = self.rx.recv() {
             match value {
                 ShardManagerMessage::Restart(shard_id) => {
-                    self.manager.lock().restart(shard_id);
+                    let mut manager = self.manager.lock();
+
+                    if manager.record_restart_attempt(shard_id) {
+                        manager.restart(shard_id);
+                    } else {
+                        error!(
+                            "Shard {} exceeded its maximum restart attempts",
+                            shard_id,
+                        );
+
+                        manager.fail(shard_id);
+
+                        break;
+                    }
+                },
+                ShardManagerMessage::SessionStartLimitReached(shard_id) => {
+                    warn!("Shard {} reached the session start limit", shard_id);
+
+                    self.manager.lock().restart_after_session_start_limit(shard_id);
+                },
+                ShardManagerMessage::ShardFailure(shard_id) => {
+                    error!("Shard {} failed unrecoverably", shard_id);
+
+                    self.manager.lock().fail(shard_id);
+
+                    break;
                 },
                 ShardManagerMessage::ShardUpdate { id, latency, stage } => {
-                    let manager = self.manager.lock();
+                    let mut manager = self.manager.lock();
+
+                    if stage == ConnectionStage::Connected {
+                        manager.reset_restart_attempts(id);
+                    }
+
                     let mut runners = manager.runners.lock();
 
                     if let Some(runner) = runners.get_mut(&id) {
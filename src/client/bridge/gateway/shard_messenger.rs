@@ -1,7 +1,23 @@
 use crate::gateway::InterMessage;
 use crate::model::prelude::*;
-use super::{ShardClientMessage, ShardRunnerMessage};
-use std::sync::mpsc::{SendError, Sender};
+use super::{
+    Collectable,
+    Collector,
+    Filter,
+    MessageCollector,
+    MessageFilter,
+    PresenceBuilder,
+    ReactionCollector,
+    ReactionFilter,
+    ShardClientMessage,
+    ShardRunnerMessage,
+    TypingCollector,
+    TypingFilter,
+};
+use std::{
+    sync::mpsc::{SendError, Sender},
+    time::Duration,
+};
 use websocket::message::OwnedMessage;
 
 /// A lightweight wrapper around an mpsc sender.
@@ -124,6 +140,182 @@ impl ShardMessenger {
         });
     }
 
+    /// Waits for the next message sent by a user in a channel, for at most
+    /// the given duration.
+    ///
+    /// Returns `None` if the user did not reply in time. The underlying
+    /// [`MessageCollector`] is unregistered either way.
+    ///
+    /// Only messages received after this is called are waited for, so a reply
+    /// to a prompt sent beforehand can be missed. Use [`prompt_reply`] to send
+    /// a prompt instead.
+    ///
+    /// [`MessageCollector`]: struct.MessageCollector.html
+    /// [`prompt_reply`]: #method.prompt_reply
+    pub fn await_reply<C, U>(&self, channel_id: C, user_id: U, timeout: Duration)
+        -> Option<Message> where C: Into<ChannelId>, U: Into<UserId> {
+        let filter = MessageFilter::new()
+            .channel_id(channel_id)
+            .user(user_id);
+
+        self.collect_messages(filter).receive(timeout)
+    }
+
+    /// Sends a prompt and waits for the next message sent by a user in a
+    /// channel in reply, for at most the given duration.
+    ///
+    /// The underlying [`MessageCollector`] is registered before the prompt is
+    /// sent, so that a reply received before the prompt returns is not
+    /// missed. Returns `None` if the user did not reply in time.
+    ///
+    /// # Examples
+    ///
+    /// Ask for a name, waiting up to a minute for the author to reply:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate serenity;
+    /// #
+    /// # #[cfg(all(feature = "client", feature = "standard_framework"))]
+    /// # mod inner {
+    /// use std::time::Duration;
+    ///
+    /// command!(rename(ctx, msg) {
+    ///     let timeout = Duration::from_secs(60);
+    ///     let prompt = || msg.channel_id.say("What should the new name be?");
+    ///
+    ///     match ctx.shard.prompt_reply(msg.channel_id, msg.author.id, timeout, prompt)? {
+    ///         Some(reply) => {
+    ///             msg.channel_id.say(&format!("Renamed to {}.", reply.content))?;
+    ///         },
+    ///         None => {
+    ///             msg.channel_id.say("No name given in time.")?;
+    ///         },
+    ///     }
+    /// });
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the prompt's error if sending it failed, in which case no reply
+    /// is waited for.
+    ///
+    /// [`MessageCollector`]: struct.MessageCollector.html
+    pub fn prompt_reply<C, U, F, T>(
+        &self,
+        channel_id: C,
+        user_id: U,
+        timeout: Duration,
+        prompt: F,
+    ) -> crate::Result<Option<Message>> where C: Into<ChannelId>,
+                                              U: Into<UserId>,
+                                              F: FnOnce() -> crate::Result<T> {
+        let filter = MessageFilter::new()
+            .channel_id(channel_id)
+            .user(user_id);
+        let collector = self.collect_messages(filter);
+
+        prompt()?;
+
+        Ok(collector.receive(timeout))
+    }
+
+    /// Waits for the user to start typing in the channel, for at most the
+    /// given duration.
+    ///
+    /// Returns `None` if the user did not start typing in time. This creates a
+    /// [`TypingCollector`] for the duration of the wait.
+    ///
+    /// # Examples
+    ///
+    /// Checking whether the author is still composing a reply:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate serenity;
+    /// #
+    /// # #[cfg(all(feature = "client", feature = "standard_framework"))]
+    /// # mod inner {
+    /// use std::time::Duration;
+    ///
+    /// command!(wait(ctx, msg) {
+    ///     let timeout = Duration::from_secs(15);
+    ///
+    ///     if ctx.shard.await_typing(msg.channel_id, msg.author.id, timeout).is_some() {
+    ///         msg.channel_id.say("Take your time.")?;
+    ///     }
+    /// });
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`TypingCollector`]: struct.TypingCollector.html
+    pub fn await_typing<C, U>(&self, channel_id: C, user_id: U, timeout: Duration)
+        -> Option<TypingStartEvent> where C: Into<ChannelId>, U: Into<UserId> {
+        let filter = TypingFilter::new()
+            .channel_id(channel_id)
+            .user(user_id);
+
+        self.collect_typing(filter).receive(timeout)
+    }
+
+    /// Registers a [`Collector`] with the shard, receiving the items that
+    /// match the filter.
+    ///
+    /// [`Collector`]: struct.Collector.html
+    pub fn collect<T: Collectable, F: Filter<T>>(&self, filter: F) -> Collector<T, F> {
+        Collector::new(self.tx.clone(), filter)
+    }
+
+    /// Registers a [`MessageCollector`] with the shard, receiving the
+    /// messages that match the filter.
+    ///
+    /// [`MessageCollector`]: struct.MessageCollector.html
+    pub fn collect_messages(&self, filter: MessageFilter) -> MessageCollector {
+        self.collect(filter)
+    }
+
+    /// Registers a [`ReactionCollector`] with the shard, receiving the
+    /// reactions added to messages that match the filter.
+    ///
+    /// Refer to the collector's documentation for an example.
+    ///
+    /// [`ReactionCollector`]: struct.ReactionCollector.html
+    pub fn collect_reactions(&self, filter: ReactionFilter) -> ReactionCollector {
+        self.collect(filter)
+    }
+
+    /// Registers a [`TypingCollector`] with the shard, receiving the typing
+    /// starts that match the filter.
+    ///
+    /// [`TypingCollector`]: struct.TypingCollector.html
+    pub fn collect_typing(&self, filter: TypingFilter) -> TypingCollector {
+        self.collect(filter)
+    }
+
+    /// Resets the user's presence to being online with no game, and not AFK,
+    /// sending a single presence update.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate serenity;
+    /// #
+    /// # #[cfg(all(feature = "client", feature = "standard_framework"))]
+    /// # mod inner {
+    /// command!(clearstatus(ctx) {
+    ///     ctx.shard.reset_presence();
+    /// });
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    pub fn reset_presence(&self) {
+        self.update_presence(PresenceBuilder::reset);
+    }
+
     /// Sets the user's current game, if any.
     ///
     /// Other presence settings are maintained.
@@ -277,6 +469,36 @@ impl ShardMessenger {
         let _ = self.send(ShardRunnerMessage::Close(1000, None));
     }
 
+    /// Changes parts of the user's presence, sending a single presence update
+    /// once all changes are applied.
+    ///
+    /// Unlike calling several of the individual setters, this does not send
+    /// intermediate presence updates with only some of the changes applied.
+    /// Parts of the presence not set on the builder are maintained.
+    ///
+    /// # Examples
+    ///
+    /// Going idle while keeping the current game:
+    ///
+    /// ```rust,no_run
+    /// # #[macro_use] extern crate serenity;
+    /// #
+    /// # #[cfg(all(feature = "client", feature = "standard_framework"))]
+    /// # mod inner {
+    /// use serenity::model::user::OnlineStatus;
+    ///
+    /// command!(away(ctx) {
+    ///     ctx.shard.update_presence(|p| p.status(OnlineStatus::Idle).afk(true));
+    /// });
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    pub fn update_presence<F>(&self, f: F)
+        where F: FnOnce(PresenceBuilder) -> PresenceBuilder {
+        let _ = self.send(ShardRunnerMessage::UpdatePresence(f(PresenceBuilder::default())));
+    }
+
     /// Sends a raw message over the WebSocket.
     ///
     /// The given message is not mutated in any way, and is sent as-is.
@@ -1,9 +1,12 @@
-use crate::gateway::Shard;
+use crate::gateway::{CurrentPresence, Shard};
+use crate::http;
 use crate::internal::prelude::*;
+use crate::model::gateway::SessionStartLimit;
 use parking_lot::Mutex;
 use std::{
     collections::{HashMap, VecDeque},
     sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         mpsc::{
             Receiver,
             RecvTimeoutError,
@@ -15,6 +18,7 @@ use std::{
 };
 use super::super::super::EventHandler;
 use super::{
+    CollectorRegistry,
     ShardId,
     ShardManagerMessage,
     ShardQueuerMessage,
@@ -39,7 +43,16 @@ const WAIT_BETWEEN_BOOTS_IN_SECONDS: u64 = 5;
 /// A shard queuer instance _should_ be run in its own thread, due to the
 /// blocking nature of the loop itself as well as a 5 second thread sleep
 /// between shard starts.
+///
+/// Shards are started in identify buckets of `shard_id % max_concurrency`,
+/// as Discord only ratelimits IDENTIFYs within the same bucket. Shards in
+/// different buckets are started without waiting on each other.
 pub struct ShardQueuer<H: EventHandler + Send + Sync + 'static> {
+    /// The collectors registered with each shard, keyed by shard Id.
+    ///
+    /// These are given to each runner started for the shard, so that the
+    /// collectors are kept when the shard is restarted.
+    pub collectors: HashMap<u64, Arc<Mutex<CollectorRegistry>>>,
     /// A copy of [`Client::data`] to be given to runners for contextual
     /// dispatching.
     ///
@@ -53,23 +66,46 @@ pub struct ShardQueuer<H: EventHandler + Send + Sync + 'static> {
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
-    /// The instant that a shard was last started.
+    /// The instants that a shard in each identify bucket was last started,
+    /// keyed by bucket.
     ///
     /// This is used to determine how long to wait between shard IDENTIFYs.
-    pub last_start: Option<Instant>,
+    pub last_starts: HashMap<u64, Instant>,
     /// A copy of the sender channel to communicate with the
     /// [`ShardManagerMonitor`].
     ///
     /// [`ShardManagerMonitor`]: struct.ShardManagerMonitor.html
     pub manager_tx: Sender<ShardManagerMessage>,
+    /// The number of identify buckets which shards may be started in
+    /// concurrently, as given by the [`SessionStartLimit`].
+    ///
+    /// [`SessionStartLimit`]: ../../../model/gateway/struct.SessionStartLimit.html
+    pub max_concurrency: Arc<AtomicUsize>,
+    /// The presence to start shards with, if one was set for all shards.
+    pub presence: Arc<Mutex<Option<CurrentPresence>>>,
     /// The shards that are queued for booting.
     ///
     /// This will typically be filled with previously failed boots.
     pub queue: VecDeque<(u64, u64)>,
     /// A copy of the map of shard runners.
     pub runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+    /// The last known session start limit, along with the instant it was
+    /// retrieved, if one has been given to the [`ShardManager`].
+    ///
+    /// When no session starts remain, shards are not started until the limit
+    /// has reset.
+    ///
+    /// [`ShardManager`]: struct.ShardManager.html
+    pub session_start_limit: Arc<Mutex<Option<(SessionStartLimit, Instant)>>>,
+    /// The number of shard runner threads that are still running.
+    pub running: Arc<AtomicUsize>,
     /// A receiver channel for the shard queuer to be told to start shards.
     pub rx: Receiver<ShardQueuerMessage>,
+    /// Whether the [`ShardManager`] is shutting down all shards, in which
+    /// case no more shards are started.
+    ///
+    /// [`ShardManager`]: struct.ShardManager.html
+    pub shutting_down: Arc<AtomicBool>,
     /// A copy of a threadpool to give shard runners.
     ///
     /// For example, when using the [`Client`], this will be a copy of
@@ -95,13 +131,16 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
     ///
     /// If a [`ShardQueuerMessage::Start`] is received, this will:
     ///
-    /// 1. Check how much time has passed since the last shard was started
+    /// 1. Check how much time has passed since the last shard in the same
+    ///    identify bucket was started
     /// 2. If the amount of time is less than the ratelimit, it will sleep until
-    /// that time has passed
+    ///    that time has passed
     /// 3. Start the shard by ID
     ///
     /// If a [`ShardQueuerMessage::Shutdown`] is received, this will return and
-    /// the loop will be over.
+    /// the loop will be over. This is also the case while waiting for the
+    /// session start limit to reset; any shard starts received during that
+    /// wait are queued.
     ///
     /// **Note**: This should be run in its own thread due to the blocking
     /// nature of the loop.
@@ -120,7 +159,9 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
             match self.rx.recv_timeout(wait_duration) {
                 Ok(ShardQueuerMessage::Shutdown) => break,
                 Ok(ShardQueuerMessage::Start(id, total)) => {
-                    self.checked_start(id.0, total.0);
+                    if !self.checked_start(id.0, total.0) {
+                        break;
+                    }
                 },
                 Err(RecvTimeoutError::Disconnected) => {
                     // If the sender half has disconnected then the queuer's
@@ -129,16 +170,24 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
                 },
                 Err(RecvTimeoutError::Timeout) => {
                     if let Some((id, total)) = self.queue.pop_front() {
-                        self.checked_start(id, total);
+                        if !self.checked_start(id, total) {
+                            break;
+                        }
                     }
                 }
             }
         }
     }
 
-    fn check_last_start(&mut self) {
-        let instant = match self.last_start {
-            Some(instant) => instant,
+    fn bucket(&self, shard_id: u64) -> u64 {
+        let max_concurrency = self.max_concurrency.load(Ordering::SeqCst).max(1);
+
+        shard_id % max_concurrency as u64
+    }
+
+    fn check_last_start(&mut self, bucket: u64) {
+        let instant = match self.last_starts.get(&bucket) {
+            Some(&instant) => instant,
             None => return,
         };
 
@@ -156,8 +205,132 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
         thread::sleep(to_sleep);
     }
 
-    fn checked_start(&mut self, id: u64, total: u64) {
-        self.check_last_start();
+    /// Waits for the session start limit to reset if no session starts
+    /// remain, otherwise uses one of them.
+    ///
+    /// If no limit is known yet, it is retrieved first. Once the limit has
+    /// reset, it is retrieved again, waiting anew if there are still no
+    /// session starts remaining.
+    ///
+    /// Returns `false` if the queuer was told to shutdown while waiting.
+    fn check_session_start_limit(&mut self) -> bool {
+        loop {
+            let (limit, fetched) = {
+                let mut guard = self.session_start_limit.lock();
+
+                match *guard {
+                    Some((ref mut limit, _)) if limit.remaining > 0 => {
+                        limit.remaining -= 1;
+
+                        return true;
+                    },
+                    Some((ref limit, fetched)) => (limit.clone(), fetched),
+                    None => {
+                        drop(guard);
+
+                        // Without a known limit, shards are started without
+                        // being limited.
+                        if !self.refresh_session_start_limit(None) {
+                            return true;
+                        }
+
+                        continue;
+                    },
+                }
+            };
+
+            // No session starts remain, so wait for the limit to reset instead
+            // of identifying, which would result in the token being reset.
+            let reset = session_start_limit_reset(&limit, fetched);
+            let now = Instant::now();
+
+            if now < reset {
+                warn!(
+                    "No session starts remaining; waiting {:?} to identify",
+                    reset - now,
+                );
+
+                if !self.wait_until(reset) {
+                    return false;
+                }
+            }
+
+            self.refresh_session_start_limit(Some(limit));
+        }
+    }
+
+    /// Retrieves the current session start limit, along with the number of
+    /// identify buckets.
+    ///
+    /// If retrieving it fails and a limit that has since reset is given, all
+    /// of its session starts are assumed to be available again. Returns
+    /// whether a limit is now known.
+    fn refresh_session_start_limit(&mut self, reset: Option<SessionStartLimit>) -> bool {
+        let limit = match http::get_bot_gateway() {
+            Ok(gateway) => gateway.session_start_limit,
+            Err(why) => {
+                warn!("Err retrieving session start limit: {:?}", why);
+
+                match reset {
+                    Some(mut limit) => {
+                        limit.remaining = limit.total;
+
+                        limit
+                    },
+                    None => return false,
+                }
+            },
+        };
+
+        let max_concurrency = limit.max_concurrency.max(1) as usize;
+        self.max_concurrency.store(max_concurrency, Ordering::SeqCst);
+
+        *self.session_start_limit.lock() = Some((limit, Instant::now()));
+
+        true
+    }
+
+    /// Waits until the given instant while still receiving over the [`rx`],
+    /// queueing any shard starts received in the meantime.
+    ///
+    /// Returns `false` if the queuer was told to shutdown while waiting.
+    ///
+    /// [`rx`]: #structfield.rx
+    fn wait_until(&mut self, deadline: Instant) -> bool {
+        loop {
+            let now = Instant::now();
+
+            if now >= deadline {
+                return true;
+            }
+
+            match self.rx.recv_timeout(deadline - now) {
+                Ok(ShardQueuerMessage::Shutdown) |
+                Err(RecvTimeoutError::Disconnected) => return false,
+                Ok(ShardQueuerMessage::Start(id, total)) => {
+                    self.queue.push_back((id.0, total.0));
+                },
+                Err(RecvTimeoutError::Timeout) => return true,
+            }
+        }
+    }
+
+    fn checked_start(&mut self, id: u64, total: u64) -> bool {
+        // The session start limit also gives the number of identify buckets,
+        // so it is checked first.
+        if !self.check_session_start_limit() {
+            return false;
+        }
+
+        let bucket = self.bucket(id);
+
+        self.check_last_start(bucket);
+
+        if self.shutting_down.load(Ordering::SeqCst) {
+            info!("Not starting shard {} during shutdown", id);
+
+            return true;
+        }
 
         if let Err(why) = self.start(id, total) {
             warn!("Err starting shard {}: {:?}", id, why);
@@ -166,19 +339,30 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
             self.queue.push_back((id, total));
         }
 
-        self.last_start = Some(Instant::now());
+        self.last_starts.insert(bucket, Instant::now());
+
+        true
     }
 
     fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
         let shard_info = [shard_id, shard_total];
 
-        let shard = Shard::new(
+        let mut shard = Shard::new(
             Arc::clone(&self.ws_url),
             Arc::clone(&self.token),
             shard_info,
         )?;
 
+        if let Some((ref game, status)) = *self.presence.lock() {
+            shard.set_presence(status, game.clone());
+        }
+
+        let collectors = self.collectors
+            .entry(shard_id)
+            .or_default();
+
         let mut runner = ShardRunner::new(ShardRunnerOptions {
+            collectors: Arc::clone(collectors),
             data: Arc::clone(&self.data),
             event_handler: Arc::clone(&self.event_handler),
             #[cfg(feature = "framework")]
@@ -196,7 +380,13 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
             stage: ConnectionStage::Disconnected,
         };
 
+        let running = Arc::clone(&self.running);
+        running.fetch_add(1, Ordering::SeqCst);
+
         thread::spawn(move || {
+            // Counts the runner down even if it panics.
+            let _running = RunningGuard(running);
+
             let _ = runner.run();
         });
 
@@ -205,3 +395,72 @@ impl<H: EventHandler + Send + Sync + 'static> ShardQueuer<H> {
         Ok(())
     }
 }
+
+/// The instant at which an exhausted session start limit, retrieved at the
+/// given instant, is to be retrieved again.
+///
+/// This is at least 5 seconds after it was retrieved, so that a limit which
+/// has reset but is still exhausted is not retrieved repeatedly.
+fn session_start_limit_reset(limit: &SessionStartLimit, fetched: Instant) -> Instant {
+    let reset_after = Duration::from_millis(limit.reset_after);
+
+    fetched + reset_after.max(Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS))
+}
+
+/// Decrements the number of running shard runners when dropped.
+struct RunningGuard(Arc<AtomicUsize>);
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::gateway::SessionStartLimit;
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+        time::{Duration, Instant},
+    };
+    use super::{session_start_limit_reset, RunningGuard};
+
+    #[test]
+    fn test_session_start_limit_reset() {
+        let mut limit = SessionStartLimit {
+            max_concurrency: 1,
+            remaining: 0,
+            reset_after: 60_000,
+            total: 1000,
+        };
+        let fetched = Instant::now();
+
+        let reset = session_start_limit_reset(&limit, fetched);
+        assert_eq!(reset - fetched, Duration::from_secs(60));
+
+        // A limit that has reset yet is still exhausted is retrieved again
+        // after a short wait.
+        limit.reset_after = 0;
+        let reset = session_start_limit_reset(&limit, fetched);
+        assert_eq!(reset - fetched, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_running_guard_counts_down_on_panic() {
+        let running = Arc::new(AtomicUsize::new(1));
+        let guard = RunningGuard(Arc::clone(&running));
+
+        let result = thread::spawn(move || {
+            let _running = guard;
+
+            panic!("runner failed");
+        }).join();
+
+        assert!(result.is_err());
+        assert_eq!(running.load(Ordering::SeqCst), 0);
+    }
+}
@@ -1,7 +1,8 @@
-use crate::gateway::{InterMessage, ReconnectType, Shard, ShardAction};
+use crate::gateway::{ConnectionStage, GatewayError, InterMessage, ReconnectType, Shard, ShardAction};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::{ReceiverExt, SenderExt};
 use crate::model::event::{Event, GatewayEvent};
+use crate::model::user::OnlineStatus;
 use parking_lot::Mutex;
 use serde::Deserialize;
 use std::sync::{
@@ -15,8 +16,14 @@ use std::sync::{
 };
 use super::super::super::dispatch::{DispatchEvent, dispatch};
 use super::super::super::EventHandler;
-use super::event::{ClientEvent, ShardStageUpdateEvent};
-use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
+use super::event::{ClientEvent, ShardDisconnectEvent, ShardStageUpdateEvent};
+use super::{
+    CollectorRegistry,
+    ShardClientMessage,
+    ShardId,
+    ShardManagerMessage,
+    ShardRunnerMessage,
+};
 use threadpool::ThreadPool;
 use typemap::ShareMap;
 use websocket::{
@@ -37,6 +44,7 @@ pub struct ShardRunner<H: EventHandler + Send + Sync + 'static> {
     event_handler: Arc<H>,
     #[cfg(feature = "framework")]
     framework: Arc<Mutex<Option<Box<dyn Framework + Send>>>>,
+    collectors: Arc<Mutex<CollectorRegistry>>,
     manager_tx: Sender<ShardManagerMessage>,
     // channel to receive messages from the shard manager and dispatches
     runner_rx: Receiver<InterMessage>,
@@ -60,6 +68,7 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
             event_handler: opt.event_handler,
             #[cfg(feature = "framework")]
             framework: opt.framework,
+            collectors: opt.collectors,
             manager_tx: opt.manager_tx,
             shard: opt.shard,
             threadpool: opt.threadpool,
@@ -125,6 +134,17 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
                     shard_id: ShardId(self.shard.shard_info()[0]),
                 });
                 self.dispatch(DispatchEvent::Client(e));
+
+                // A presence set while the shard was not connected is only
+                // sent once connected. An identify already carries it, but a
+                // resume does not.
+                let (ref game, status) = *self.shard.current_presence();
+                let custom_presence = game.is_some() || status != OnlineStatus::Online;
+                let resumed = pre != ConnectionStage::Identifying;
+
+                if post == ConnectionStage::Connected && custom_presence && resumed {
+                    let _ = self.shard.update_presence();
+                }
             }
 
             match action {
@@ -138,6 +158,8 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
             }
 
             if let Some(event) = event {
+                self.collectors.lock().collect(&event);
+
                 self.dispatch(DispatchEvent::Model(event));
             }
 
@@ -207,7 +229,7 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
             &self.event_handler,
             &self.runner_tx,
             &self.threadpool,
-            self.shard.shard_info()[0],
+            self.shard.shard_info(),
         );
     }
 
@@ -233,7 +255,9 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
 
                     true
                 },
-                ShardManagerMessage::ShardUpdate { .. }
+                ShardManagerMessage::SessionStartLimitReached(_)
+                    | ShardManagerMessage::ShardFailure(_)
+                    | ShardManagerMessage::ShardUpdate { .. }
                     | ShardManagerMessage::ShutdownInitiated => {
                     // nb: not sent here
 
@@ -241,6 +265,11 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
                 },
             },
             InterMessage::Client(ShardClientMessage::Runner(x)) => match x {
+                ShardRunnerMessage::AddCollector(id, sender) => {
+                    self.collectors.lock().add(id, sender);
+
+                    true
+                },
                 ShardRunnerMessage::ChunkGuilds { guild_ids, limit, query } => {
                     self.shard.chunk_guilds(
                         guild_ids,
@@ -258,6 +287,11 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
                 ShardRunnerMessage::Message(msg) => {
                     self.shard.client.send_message(&msg).is_ok()
                 },
+                ShardRunnerMessage::RemoveCollector(id) => {
+                    self.collectors.lock().remove(id);
+
+                    true
+                },
                 ShardRunnerMessage::SetGame(game) => {
                     // To avoid a clone of `game`, we do a little bit of
                     // trickery here:
@@ -274,17 +308,22 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
                     // - the original game we received over the channel
                     self.shard.set_game(game);
 
-                    self.shard.update_presence().is_ok()
+                    self.update_presence()
                 },
                 ShardRunnerMessage::SetPresence(status, game) => {
                     self.shard.set_presence(status, game);
 
-                    self.shard.update_presence().is_ok()
+                    self.update_presence()
                 },
                 ShardRunnerMessage::SetStatus(status) => {
                     self.shard.set_status(status);
 
-                    self.shard.update_presence().is_ok()
+                    self.update_presence()
+                },
+                ShardRunnerMessage::UpdatePresence(builder) => {
+                    builder.apply(&mut self.shard);
+
+                    self.update_presence()
                 },
             },
             InterMessage::Json(value) => {
@@ -423,12 +462,35 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
             Err(why) => Err(why),
         };
 
+        if let Err(Error::Gateway(GatewayError::Closed(ref data))) = event {
+            let e = ClientEvent::ShardDisconnect(ShardDisconnectEvent {
+                close_data: data.clone(),
+                shard_id: ShardId(self.shard.shard_info()[0]),
+            });
+            self.dispatch(DispatchEvent::Client(e));
+        }
+
         let action = match self.shard.handle_event(&event) {
             Ok(Some(action)) => Some(action),
             Ok(None) => None,
             Err(why) => {
                 error!("Shard handler received err: {:?}", why);
 
+                match why {
+                    Error::Gateway(GatewayError::InvalidAuthentication) |
+                    Error::Gateway(GatewayError::InvalidShardData) |
+                    Error::Gateway(GatewayError::OverloadedShard) => {
+                        let shard_id = ShardId(self.shard.shard_info()[0]);
+                        let _ = self.manager_tx.send(ShardManagerMessage::ShardFailure(shard_id));
+                    },
+                    Error::Gateway(GatewayError::SessionStartLimitReached) => {
+                        let shard_id = ShardId(self.shard.shard_info()[0]);
+                        let msg = ShardManagerMessage::SessionStartLimitReached(shard_id);
+                        let _ = self.manager_tx.send(msg);
+                    },
+                    _ => {},
+                }
+
                 return (None, None, true);
             },
         };
@@ -471,6 +533,18 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
         Ok(())
     }
 
+    // Sends the shard's current presence, unless the shard is not connected -
+    // such as while reconnecting - in which case it is sent once connected.
+    //
+    // Returns whether the shard runner can continue.
+    fn update_presence(&mut self) -> bool {
+        if self.shard.stage() != ConnectionStage::Connected {
+            return true;
+        }
+
+        self.shard.update_presence().is_ok()
+    }
+
     fn update_manager(&self) {
         let _ = self.manager_tx.send(ShardManagerMessage::ShardUpdate {
             id: ShardId(self.shard.shard_info()[0]),
@@ -484,6 +558,7 @@ impl<H: EventHandler + Send + Sync + 'static> ShardRunner<H> {
 ///
 /// [`ShardRunner::new`]: struct.ShardRunner.html#method.new
 pub struct ShardRunnerOptions<H: EventHandler + Send + Sync + 'static> {
+    pub collectors: Arc<Mutex<CollectorRegistry>>,
     pub data: Arc<Mutex<ShareMap>>,
     pub event_handler: Arc<H>,
     #[cfg(feature = "framework")]
@@ -3,11 +3,17 @@ use crate::model::{
     user::OnlineStatus,
     id::GuildId
 };
+use super::{CollectorSender, PresenceBuilder};
 use websocket::message::OwnedMessage;
 
 /// A message to send from a shard over a WebSocket.
 #[derive(Clone, Debug)]
 pub enum ShardRunnerMessage {
+    /// Indicates that the runner is to send the items matching a
+    /// [`Collector`]'s filter to it, identified by the given Id.
+    ///
+    /// [`Collector`]: struct.Collector.html
+    AddCollector(usize, CollectorSender),
     /// Indicates that the client is to send a member chunk message.
     ChunkGuilds {
         /// The IDs of the [`Guild`]s to chunk.
@@ -38,6 +44,11 @@ pub enum ShardRunnerMessage {
     Close(u16, Option<String>),
     /// Indicates that the client is to send a custom WebSocket message.
     Message(OwnedMessage),
+    /// Indicates that the runner is to stop sending items to the
+    /// [`Collector`] with the given Id.
+    ///
+    /// [`Collector`]: struct.Collector.html
+    RemoveCollector(usize),
     /// Indicates that the client is to update the shard's presence's game.
     SetGame(Option<Game>),
     /// Indicates that the client is to update the shard's presence in its
@@ -45,4 +56,7 @@ pub enum ShardRunnerMessage {
     SetPresence(OnlineStatus, Option<Game>),
     /// Indicates that the client is to update the shard's presence's status.
     SetStatus(OnlineStatus),
+    /// Indicates that the client is to apply the changes to the shard's
+    /// presence, sending a single presence update.
+    UpdatePresence(PresenceBuilder),
 }
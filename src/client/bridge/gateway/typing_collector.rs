@@ -0,0 +1,129 @@
+use crate::model::{
+    event::{Event, TypingStartEvent},
+    id::{ChannelId, UserId},
+};
+use super::{Collectable, Collector, Filter};
+
+/// A filter deciding which typing starts a [`TypingCollector`] receives.
+///
+/// An unset or empty criterion matches any typing start, so a default filter
+/// matches every typing start the shard receives.
+///
+/// [`TypingCollector`]: struct.TypingCollector.html
+#[derive(Clone, Debug, Default)]
+pub struct TypingFilter {
+    channel_id: Option<ChannelId>,
+    users: Vec<UserId>,
+}
+
+impl TypingFilter {
+    /// Creates a filter matching every typing start.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only matches users typing in the given channel.
+    pub fn channel_id<C: Into<ChannelId>>(mut self, channel_id: C) -> Self {
+        self.channel_id = Some(channel_id.into());
+
+        self
+    }
+
+    /// Adds a user to the set of users whose typing to match.
+    pub fn user<U: Into<UserId>>(mut self, user_id: U) -> Self {
+        self.users.push(user_id.into());
+
+        self
+    }
+}
+
+impl Filter<TypingStartEvent> for TypingFilter {
+    fn matches(&self, typing: &TypingStartEvent) -> bool {
+        if let Some(channel_id) = self.channel_id {
+            if typing.channel_id != channel_id {
+                return false;
+            }
+        }
+
+        self.users.is_empty() || self.users.contains(&typing.user_id)
+    }
+}
+
+/// Receives the [`TypingStartEvent`]s that match a [`TypingFilter`], as they
+/// are received by a shard.
+///
+/// This is created via [`ShardMessenger::collect_typing`], which registers
+/// the collector with the shard's [`ShardRunner`]. The collector is
+/// unregistered when dropped.
+///
+/// Discord sends a typing start when a user starts typing, and again around
+/// every 10 seconds while they keep typing. To wait for a single typing
+/// start, [`ShardMessenger::await_typing`] can be used instead.
+///
+/// [`ShardMessenger::await_typing`]: struct.ShardMessenger.html#method.await_typing
+/// [`ShardMessenger::collect_typing`]: struct.ShardMessenger.html#method.collect_typing
+/// [`ShardRunner`]: struct.ShardRunner.html
+/// [`TypingFilter`]: struct.TypingFilter.html
+/// [`TypingStartEvent`]: ../../../model/event/struct.TypingStartEvent.html
+pub type TypingCollector = Collector<TypingStartEvent, TypingFilter>;
+
+impl Collectable for TypingStartEvent {
+    fn from_event(event: &Event) -> Option<&Self> {
+        match *event {
+            Event::TypingStart(ref event) => Some(event),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gateway::InterMessage;
+    use crate::model::{event::TypingStartEvent, id::{ChannelId, UserId}};
+    use std::{sync::mpsc, time::Duration};
+    use super::super::{ShardClientMessage, ShardMessenger, ShardRunnerMessage};
+    use super::super::Filter;
+    use super::TypingFilter;
+
+    fn typing(channel_id: u64, user_id: u64) -> TypingStartEvent {
+        TypingStartEvent {
+            channel_id: ChannelId(channel_id),
+            timestamp: 0,
+            user_id: UserId(user_id),
+        }
+    }
+
+    #[test]
+    fn test_filter() {
+        assert!(TypingFilter::new().matches(&typing(1, 2)));
+
+        let filter = TypingFilter::new().channel_id(1).user(2);
+        assert!(filter.matches(&typing(1, 2)));
+        assert!(!filter.matches(&typing(3, 2)));
+        assert!(!filter.matches(&typing(1, 3)));
+    }
+
+    #[test]
+    fn test_await_typing_unregisters() {
+        let (tx, rx) = mpsc::channel();
+        let messenger = ShardMessenger::new(tx);
+
+        let typing = messenger.await_typing(ChannelId(1), UserId(2), Duration::from_millis(1));
+        assert!(typing.is_none());
+
+        let id = match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::AddCollector(id, _),
+            ))) => id,
+            other => panic!("Unexpected message: {:?}", other),
+        };
+
+        assert!(match rx.try_recv() {
+            Ok(InterMessage::Client(ShardClientMessage::Runner(
+                ShardRunnerMessage::RemoveCollector(removed),
+            ))) => removed == id,
+            _ => false,
+        });
+    }
+}
@@ -45,6 +45,11 @@ pub struct Context {
     pub shard: ShardMessenger,
     /// The ID of the shard this context is related to.
     pub shard_id: u64,
+    /// The ID of the shard this context is related to and the total number of
+    /// shards, in the form `[shard_id, shard_count]`.
+    ///
+    /// A bot running a single shard reports `[0, 1]`.
+    pub shard_info: [u64; 2],
 }
 
 impl Context {
@@ -52,11 +57,12 @@ impl Context {
     pub(crate) fn new(
         data: Arc<Mutex<ShareMap>>,
         runner_tx: Sender<InterMessage>,
-        shard_id: u64,
+        shard_info: [u64; 2],
     ) -> Context {
         Context {
             shard: ShardMessenger::new(runner_tx),
-            shard_id,
+            shard_id: shard_info[0],
+            shard_info,
             data,
         }
     }
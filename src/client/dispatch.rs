@@ -59,9 +59,9 @@ macro_rules! update {
 fn context(
     data: &Arc<Mutex<ShareMap>>,
     runner_tx: &Sender<InterMessage>,
-    shard_id: u64,
+    shard_info: [u64; 2],
 ) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id)
+    Context::new(Arc::clone(data), runner_tx.clone(), shard_info)
 }
 
 pub(crate) enum DispatchEvent {
@@ -78,13 +78,13 @@ pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
     event_handler: &Arc<H>,
     runner_tx: &Sender<InterMessage>,
     threadpool: &ThreadPool,
-    shard_id: u64,
+    shard_info: [u64; 2],
 ) {
     match event {
         DispatchEvent::Model(Event::MessageCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             dispatch_message(
                 context.clone(),
                 event.message.clone(),
@@ -102,7 +102,7 @@ pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
             event_handler,
             runner_tx,
             threadpool,
-            shard_id,
+            shard_info,
         ),
     }
 }
@@ -115,13 +115,13 @@ pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
     event_handler: &Arc<H>,
     runner_tx: &Sender<InterMessage>,
     threadpool: &ThreadPool,
-    shard_id: u64,
+    shard_info: [u64; 2],
 ) {
     match event {
         DispatchEvent::Model(Event::MessageCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             dispatch_message(context, event.message, event_handler, threadpool);
         },
         other => handle_event(
@@ -130,7 +130,7 @@ pub(crate) fn dispatch<H: EventHandler + Send + Sync + 'static>(
             event_handler,
             runner_tx,
             threadpool,
-            shard_id,
+            shard_info,
         ),
     }
 }
@@ -154,6 +154,44 @@ fn dispatch_message<H>(
     });
 }
 
+/// Dispatches [`EventHandler::shard_guild_threshold_exceeded`] if the number
+/// of guilds the context's shard holds grew past the threshold.
+///
+/// [`EventHandler::shard_guild_threshold_exceeded`]: trait.EventHandler.html#method.shard_guild_threshold_exceeded
+#[cfg(feature = "cache")]
+fn check_shard_guild_count<H>(
+    previous: usize,
+    context: Context,
+    event_handler: &Arc<H>,
+    threadpool: &ThreadPool,
+) where H: EventHandler + Send + Sync + 'static {
+    let (guild_count, threshold) = {
+        let cache = CACHE.read();
+
+        (
+            cache.guild_count_for_shard(context.shard_id),
+            cache.settings().shard_guild_threshold,
+        )
+    };
+
+    if previous > threshold || guild_count <= threshold {
+        return;
+    }
+
+    warn!(
+        "[dispatch] Shard {} holds {} guilds, over the threshold of {}",
+        context.shard_id,
+        guild_count,
+        threshold,
+    );
+
+    let event_handler = Arc::clone(event_handler);
+
+    threadpool.execute(move || {
+        event_handler.shard_guild_threshold_exceeded(context, guild_count);
+    });
+}
+
 #[allow(cyclomatic_complexity, unused_assignments, unused_mut)]
 fn handle_event<H: EventHandler + Send + Sync + 'static>(
     event: DispatchEvent,
@@ -161,11 +199,19 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
     event_handler: &Arc<H>,
     runner_tx: &Sender<InterMessage>,
     threadpool: &ThreadPool,
-    shard_id: u64,
+    shard_info: [u64; 2],
 ) {
     match event {
+        DispatchEvent::Client(ClientEvent::ShardDisconnect(event)) => {
+            let context = context(data, runner_tx, shard_info);
+            let event_handler = Arc::clone(event_handler);
+
+            threadpool.execute(move || {
+                event_handler.shard_disconnect(context, event);
+            });
+        },
         DispatchEvent::Client(ClientEvent::ShardStageUpdate(event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -175,7 +221,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
 
             // Discord sends both a MessageCreate and a ChannelCreate upon a new message in a private channel.
             // This could potentially be annoying to handle when otherwise wanting to normally take care of a new channel.
@@ -208,7 +254,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelDelete(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
 
             match event.channel {
                 Channel::Private(_) | Channel::Group(_) => {},
@@ -229,7 +275,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             }
         },
         DispatchEvent::Model(Event::ChannelPinsUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -239,7 +285,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelRecipientAdd(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
 
             let event_handler = Arc::clone(event_handler);
 
@@ -254,7 +300,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelRecipientRemove(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -268,7 +314,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::ChannelUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -282,7 +328,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::GuildBanAdd(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -290,7 +336,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::GuildBanRemove(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -299,20 +345,31 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildCreate(mut event)) => {
             #[cfg(feature = "cache")]
-            let _is_new = {
+            let (_is_new, guild_count) = {
                 let cache = CACHE.read();
 
-                !cache.unavailable_guilds.contains(&event.guild.id)
+                (
+                    !cache.unavailable_guilds.contains(&event.guild.id),
+                    cache.guild_count_for_shard(shard_info[0]),
+                )
             };
 
             update!(event);
 
+            #[cfg(feature = "cache")]
+            check_shard_guild_count(
+                guild_count,
+                context(data, runner_tx, shard_info),
+                event_handler,
+                threadpool,
+            );
+
             #[cfg(feature = "cache")]
             {
                 let cache = CACHE.read();
 
                 if cache.unavailable_guilds.is_empty() {
-                    let context = context(data, runner_tx, shard_id);
+                    let context = context(data, runner_tx, shard_info);
 
                     let guild_amount = cache
                         .guilds
@@ -327,7 +384,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
                 }
             }
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -340,7 +397,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildDelete(mut event)) => {
             let _full = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -354,7 +411,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildEmojisUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -362,7 +419,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::GuildIntegrationsUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -372,7 +429,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildMemberAdd(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -381,7 +438,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildMemberRemove(mut event)) => {
             let _member = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -401,7 +458,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
                 None
             }};
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -417,7 +474,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildMembersChunk(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -427,7 +484,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildRoleCreate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -436,7 +493,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildRoleDelete(mut event)) => {
             let _role = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -449,7 +506,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::GuildRoleUpdate(mut event)) => {
             let _before = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -463,7 +520,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildUnavailable(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -473,7 +530,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::GuildUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -492,7 +549,9 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         // Already handled by the framework check macro
         DispatchEvent::Model(Event::MessageCreate(_)) => {},
         DispatchEvent::Model(Event::MessageDeleteBulk(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            update!(event);
+
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -500,7 +559,9 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::MessageDelete(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            update!(event);
+
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -510,7 +571,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::MessageUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -520,7 +581,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::PresencesReplace(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -530,7 +591,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::PresenceUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -538,7 +599,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::ReactionAdd(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -546,7 +607,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::ReactionRemove(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -554,7 +615,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::ReactionRemoveAll(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -562,9 +623,20 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::Ready(mut event)) => {
+            #[cfg(feature = "cache")]
+            let guild_count = CACHE.read().guild_count_for_shard(shard_info[0]);
+
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            #[cfg(feature = "cache")]
+            check_shard_guild_count(
+                guild_count,
+                context(data, runner_tx, shard_info),
+                event_handler,
+                threadpool,
+            );
+
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(&event_handler);
 
             threadpool.execute(move || {
@@ -572,12 +644,12 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::Resumed(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
 
             event_handler.resume(context, event);
         },
         DispatchEvent::Model(Event::TypingStart(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -585,7 +657,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::Unknown(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -594,7 +666,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         },
         DispatchEvent::Model(Event::UserUpdate(mut event)) => {
             let _before = update!(event);
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -606,7 +678,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::VoiceServerUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -616,7 +688,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
         DispatchEvent::Model(Event::VoiceStateUpdate(mut event)) => {
             update!(event);
 
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
@@ -624,7 +696,7 @@ fn handle_event<H: EventHandler + Send + Sync + 'static>(
             });
         },
         DispatchEvent::Model(Event::WebhookUpdate(mut event)) => {
-            let context = context(data, runner_tx, shard_id);
+            let context = context(data, runner_tx, shard_info);
             let event_handler = Arc::clone(event_handler);
 
             threadpool.execute(move || {
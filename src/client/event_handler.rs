@@ -247,6 +247,23 @@ pub trait EventHandler {
     /// Dispatched upon reconnection.
     fn resume(&self, _ctx: Context, _: ResumedEvent) {}
 
+    /// Dispatched when a shard's connection is closed by the gateway.
+    ///
+    /// Provides the context of the shard and the close code and reason, if
+    /// any. This is dispatched on every close, regardless of whether the shard
+    /// will resume or reidentify afterwards.
+    fn shard_disconnect(&self, _ctx: Context, _: ShardDisconnectEvent) {}
+
+    /// Dispatched when the number of guilds a shard holds exceeds the cache's
+    /// [`Settings::shard_guild_threshold`].
+    ///
+    /// Provides the number of guilds the shard, given by the context's shard
+    /// Id, now holds.
+    ///
+    /// [`Settings::shard_guild_threshold`]: ../cache/struct.Settings.html#structfield.shard_guild_threshold
+    #[cfg(feature = "cache")]
+    fn shard_guild_threshold_exceeded(&self, _ctx: Context, _guild_count: usize) {}
+
     /// Dispatched when a shard's connection stage is updated
     ///
     /// Provides the context of the shard and the event information about the update.
@@ -554,7 +554,8 @@ impl Client {
     ///
     /// This will retrieve an automatically determined number of shards to use
     /// from the API - determined by Discord - and then open a number of shards
-    /// equivalent to that amount.
+    /// equivalent to that amount. Shards are identified as concurrently as the
+    /// session start limit's `max_concurrency` allows.
     ///
     /// Refer to the [Gateway documentation][gateway docs] for more information
     /// on effectively using sharding.
@@ -599,6 +600,10 @@ impl Client {
         let (x, y) = {
             let res = http::get_bot_gateway()?;
 
+            let mut manager = self.shard_manager.lock();
+            manager.set_max_concurrency(res.session_start_limit.max_concurrency);
+            manager.set_session_start_limit(res.session_start_limit);
+
             (res.shards as u64 - 1, res.shards as u64)
         };
 
@@ -878,6 +883,12 @@ impl Client {
 
         self.shard_manager_worker.run();
 
+        if let Some(shard_id) = self.shard_manager.lock().failed_shard() {
+            error!("Shut down all shards after shard {} failed", shard_id);
+
+            return Err(Error::Client(ClientError::ShardBootFailure));
+        }
+
         Ok(())
     }
 }
@@ -1,7 +1,29 @@
 //! A set of constants used by the library.
 
+/// The maximum number of embeds allowed within a message.
+pub const EMBED_COUNT_LIMIT: u16 = 10;
+/// The maximum unicode code points allowed within an embed author name.
+pub const EMBED_AUTHOR_NAME_LIMIT: u16 = 256;
+/// The maximum unicode code points allowed within an embed description.
+pub const EMBED_DESCRIPTION_LIMIT: u16 = 4096;
+/// The maximum unicode code points allowed within an embed field name.
+pub const EMBED_FIELD_NAME_LIMIT: u16 = 256;
+/// The maximum unicode code points allowed within an embed field value.
+pub const EMBED_FIELD_VALUE_LIMIT: u16 = 1024;
+/// The maximum number of fields allowed within an embed.
+pub const EMBED_FIELDS_LIMIT: u16 = 25;
+/// The maximum unicode code points allowed within an embed footer text.
+pub const EMBED_FOOTER_LIMIT: u16 = 2048;
 /// The maximum length of the textual size of an embed.
 pub const EMBED_MAX_LENGTH: u16 = 6000;
+/// The maximum unicode code points allowed within an embed title.
+pub const EMBED_TITLE_LIMIT: u16 = 256;
+/// The maximum unicode code points allowed within a webhook username.
+pub const WEBHOOK_USERNAME_LIMIT: u16 = 80;
+/// The maximum number of seconds an invite can be valid for.
+pub const INVITE_MAX_AGE_LIMIT: u64 = 604_800;
+/// The maximum number of uses an invite can be limited to.
+pub const INVITE_MAX_USES_LIMIT: u64 = 100;
 /// The gateway version used by the library. The gateway URI is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 6;
@@ -11,6 +33,10 @@ pub const VOICE_GATEWAY_VERSION: u8 = 3;
 pub const LARGE_THRESHOLD: u8 = 250;
 /// The maximum unicode code points allowed within a message by Discord.
 pub const MESSAGE_CODE_LIMIT: u16 = 2000;
+/// The maximum number of messages that can be pinned in a channel.
+pub const PINS_LIMIT: u8 = 50;
+/// The maximum number of days a member's communication can be disabled for.
+pub const MEMBER_TIMEOUT_MAX_DAYS: i64 = 28;
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ../../hyper/header/struct.UserAgent.html
@@ -1,3 +1,6 @@
+use crate::model::id::{ChannelId, RoleId, UserId};
+use crate::model::misc::{ChannelIdParseError, RoleIdParseError, UserIdParseError};
+use crate::utils;
 use std::{
     str::FromStr,
     error::Error as StdError,
@@ -50,8 +53,79 @@ impl<E: StdError> fmt::Display for Error<E> {
     }
 }
 
+/// Defines how parsing a range via [`Args::single_range`] failed.
+///
+/// [`Args::single_range`]: struct.Args.html#method.single_range
+#[derive(Debug)]
+pub enum RangeError<E: StdError> {
+    /// The argument is not of the form `start-end`.
+    Malformed,
+    /// The start of the range is greater than its end.
+    Reversed,
+    /// Parsing either bound of the range failed.
+    Parse(E),
+}
+
+impl<E: StdError> StdError for RangeError<E> {
+    fn cause(&self) -> Option<&dyn StdError> {
+        match *self {
+            RangeError::Parse(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl<E: StdError> fmt::Display for RangeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RangeError::Malformed => write!(f, "expected a range of the form `start-end`"),
+            RangeError::Reversed => write!(f, "the start of the range is greater than its end"),
+            RangeError::Parse(ref e) => fmt::Display::fmt(&e, f),
+        }
+    }
+}
+
 type Result<T, E> = ::std::result::Result<T, Error<E>>;
 
+/// An Id which can be parsed from either a mention of its own kind or a plain
+/// numeric Id, for use with [`Args::single_mention`].
+///
+/// Unlike parsing an Id via `FromStr`, a mention of a different kind is
+/// rejected, such as a channel mention when parsing a [`UserId`].
+///
+/// [`Args::single_mention`]: struct.Args.html#method.single_mention
+/// [`UserId`]: ../../model/id/struct.UserId.html
+pub trait ParseMention: Sized {
+    /// The error returned when the argument is not a valid mention or Id.
+    type Err: StdError;
+
+    /// Parses a mention or a plain numeric Id.
+    fn parse_mention(s: &str) -> ::std::result::Result<Self, Self::Err>;
+}
+
+macro_rules! impl_parse_mention {
+    ($($id:ident, $err:ident, $parse_fn:ident;)*) => {
+        $(
+            impl ParseMention for $id {
+                type Err = $err;
+
+                fn parse_mention(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                    match utils::$parse_fn(s) {
+                        Some(id) => Ok($id(id)),
+                        None => s.parse::<u64>().map($id).map_err(|_| $err::InvalidFormat),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_mention! {
+    ChannelId, ChannelIdParseError, parse_channel;
+    RoleId, RoleIdParseError, parse_role;
+    UserId, UserIdParseError, parse_username;
+}
+
 fn find_end(s: &str, i: usize) -> Option<usize> {
     if i > s.len() {
         return None;
@@ -420,6 +494,43 @@ impl Args {
         Ok(parsed)
     }
 
+    /// Parses the current argument as a mention of a user (`<@id>` or
+    /// `<@!id>`), channel (`<#id>`), or role (`<@&id>`), or as a plain numeric
+    /// Id, and advances.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::framework::standard::Args;
+    /// use serenity::model::id::{ChannelId, RoleId, UserId};
+    ///
+    /// let mut args = Args::new("<@!7> <#8> <@&9> 10", &[" ".to_string()]);
+    ///
+    /// assert_eq!(args.single_mention::<UserId>().unwrap(), UserId(7));
+    /// assert_eq!(args.single_mention::<ChannelId>().unwrap(), ChannelId(8));
+    /// assert_eq!(args.single_mention::<RoleId>().unwrap(), RoleId(9));
+    /// assert_eq!(args.single_mention::<UserId>().unwrap(), UserId(10));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Parse`] if the argument is neither a mention of the
+    /// requested kind nor a numeric Id, in which case the argument is not
+    /// advanced past.
+    ///
+    /// [`Error::Parse`]: enum.Error.html#variant.Parse
+    pub fn single_mention<T: ParseMention>(&mut self) -> Result<T, T::Err> {
+        if self.is_empty() {
+            return Err(Error::Eos);
+        }
+
+        let cur = &self.args[self.offset];
+
+        let parsed = T::parse_mention(&cur.lit)?;
+        self.offset += 1;
+        Ok(parsed)
+    }
+
     /// Like [`single`], but doesn't advance.
     ///
     /// # Examples
@@ -563,7 +674,8 @@ impl Args {
         self.args.get(self.offset).map(|t| quotes_extract(t))
     }
 
-    /// Like [`single`], but accounts quotes.
+    /// Like [`single`], but accounts quotes. Arguments without quotes are
+    /// parsed as they are.
     ///
     /// # Examples
     ///
@@ -593,6 +705,141 @@ impl Args {
         Ok(parsed)
     }
 
+    /// Parses the current argument as a range of the form `start-end`, such
+    /// as `3-7`, and advances.
+    ///
+    /// Negative bounds are supported, as in `-5--1`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::framework::standard::{Args, RangeError};
+    ///
+    /// let mut args = Args::new("3-7 7-3", &[" ".to_string()]);
+    ///
+    /// assert_eq!(args.single_range::<u32>().unwrap(), (3, 7));
+    /// assert!(args.single_range::<u32>().is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RangeError::Malformed`] if the argument is not a range, and
+    /// [`RangeError::Reversed`] if its start is greater than its end. Use
+    /// [`single_range_rev`] to accept reversed ranges. The argument is not
+    /// advanced past on an error.
+    ///
+    /// [`RangeError::Malformed`]: enum.RangeError.html#variant.Malformed
+    /// [`RangeError::Reversed`]: enum.RangeError.html#variant.Reversed
+    /// [`single_range_rev`]: #method.single_range_rev
+    pub fn single_range<T>(&mut self) -> Result<(T, T), RangeError<T::Err>>
+        where T: FromStr + PartialOrd, T::Err: StdError {
+        self._single_range(false)
+    }
+
+    /// Like [`single_range`], but accepts ranges whose start is greater than
+    /// their end, such as `7-3`.
+    ///
+    /// The bounds are returned in the order they were given.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::framework::standard::Args;
+    ///
+    /// let mut args = Args::new("7-3", &[" ".to_string()]);
+    ///
+    /// assert_eq!(args.single_range_rev::<u32>().unwrap(), (7, 3));
+    /// ```
+    ///
+    /// [`single_range`]: #method.single_range
+    pub fn single_range_rev<T>(&mut self) -> Result<(T, T), RangeError<T::Err>>
+        where T: FromStr + PartialOrd, T::Err: StdError {
+        self._single_range(true)
+    }
+
+    fn _single_range<T>(&mut self, rev: bool) -> Result<(T, T), RangeError<T::Err>>
+        where T: FromStr + PartialOrd, T::Err: StdError {
+        if self.is_empty() {
+            return Err(Error::Eos);
+        }
+
+        let lit = &self.args[self.offset].lit;
+
+        // Skip a leading minus sign, which belongs to a negative start.
+        let separator = lit.char_indices()
+            .skip(1)
+            .find(|&(_, c)| c == '-')
+            .map(|(i, _)| i);
+
+        let (start, end) = match separator {
+            Some(i) => (&lit[..i], &lit[i + 1..]),
+            None => return Err(Error::Parse(RangeError::Malformed)),
+        };
+
+        let start = T::from_str(start).map_err(|e| Error::Parse(RangeError::Parse(e)))?;
+        let end = T::from_str(end).map_err(|e| Error::Parse(RangeError::Parse(e)))?;
+
+        if !rev && start > end {
+            return Err(Error::Parse(RangeError::Reversed));
+        }
+
+        self.offset += 1;
+
+        Ok((start, end))
+    }
+
+    /// Parses an argument optionally prefixed by a quantity argument of the
+    /// form `<amount>x`, such as the `3x` in `3x apple`, and advances past
+    /// both.
+    ///
+    /// If the current argument is not a quantity, the quantity is `1` and the
+    /// current argument is parsed. Quotes around the parsed argument are
+    /// discarded, as with [`single_quoted`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::framework::standard::Args;
+    ///
+    /// let mut args = Args::new(r#"3x apple "green pear""#, &[" ".to_string()]);
+    ///
+    /// assert_eq!(args.single_quantity::<String>().unwrap(), (3, "apple".to_string()));
+    /// assert_eq!(args.single_quantity::<String>().unwrap(), (1, "green pear".to_string()));
+    /// ```
+    ///
+    /// [`single_quoted`]: #method.single_quoted
+    pub fn single_quantity<T: FromStr>(&mut self) -> Result<(u64, T), T::Err>
+        where T::Err: StdError {
+        if self.is_empty() {
+            return Err(Error::Eos);
+        }
+
+        let quantity = {
+            let lit = &self.args[self.offset].lit;
+
+            if lit.ends_with('x') {
+                lit[..lit.len() - 1].parse::<u64>().ok()
+            } else {
+                None
+            }
+        };
+
+        let (quantity, index) = match quantity {
+            Some(quantity) => (quantity, self.offset + 1),
+            None => (1, self.offset),
+        };
+
+        let current = match self.args.get(index) {
+            Some(current) => current,
+            None => return Err(Error::Eos),
+        };
+
+        let parsed = T::from_str(quotes_extract(current))?;
+        self.offset = index + 1;
+
+        Ok((quantity, parsed))
+    }
+
     /// Like [`single_quoted`], but doesn't advance.
     ///
     /// # Examples
@@ -1077,7 +1324,8 @@ fn quotes_extract(token: &Token) -> &str {
 
 #[cfg(test)]
 mod test {
-    use super::{Args, Error as ArgError};
+    use crate::model::id::{ChannelId, UserId};
+    use super::{Args, Error as ArgError, RangeError};
 
     #[test]
     fn single_with_empty_message() {
@@ -1515,4 +1763,48 @@ mod test {
         assert_eq!(args.single::<String>().unwrap(), "abc");
         assert_eq!(args.remaining(), 0);
     }
+
+    #[test]
+    fn multiple_delimiters_with_quotes() {
+        let args = Args::new("a, b, c", &[", ".to_string(), " ".to_string()]);
+        assert_eq!(args.multiple::<String>().unwrap(), ["a", "b", "c"]);
+
+        let args = Args::new("\"a, b\", c", &[", ".to_string(), " ".to_string()]);
+        assert_eq!(args.multiple_quoted::<String>().unwrap(), ["a, b", "c"]);
+
+        // The other accessors also do not split quoted arguments, but keep
+        // the quotation marks.
+        let args = Args::new("\"a, b\", c", &[", ".to_string(), " ".to_string()]);
+        assert_eq!(args.multiple::<String>().unwrap(), ["\"a, b\"", "c"]);
+    }
+
+    #[test]
+    fn single_mention_rejects_other_kinds() {
+        let mut args = Args::new("<#7> <@7", &[" ".to_string()]);
+        assert_matches!(args.single_mention::<UserId>().unwrap_err(), ArgError::Parse(_));
+        assert_eq!(args.single_mention::<ChannelId>().unwrap(), ChannelId(7));
+        assert_matches!(args.single_mention::<UserId>().unwrap_err(), ArgError::Parse(_));
+    }
+
+    #[test]
+    fn single_range() {
+        let mut args = Args::new("3-7 -5--1 7-3 7 a-3", &[" ".to_string()]);
+        assert_eq!(args.single_range::<u32>().unwrap(), (3, 7));
+        assert_eq!(args.single_range::<i32>().unwrap(), (-5, -1));
+        assert_matches!(args.single_range::<u32>().unwrap_err(), ArgError::Parse(RangeError::Reversed));
+        assert_eq!(args.single_range_rev::<u32>().unwrap(), (7, 3));
+        assert_matches!(args.single_range::<u32>().unwrap_err(), ArgError::Parse(RangeError::Malformed));
+        assert_eq!(args.single::<u32>().unwrap(), 7);
+        assert_matches!(args.single_range::<u32>().unwrap_err(), ArgError::Parse(RangeError::Parse(_)));
+        assert_eq!(args.remaining(), 1);
+    }
+
+    #[test]
+    fn single_quantity() {
+        let mut args = Args::new("3x apple pear 2x", &[" ".to_string()]);
+        assert_eq!(args.single_quantity::<String>().unwrap(), (3, "apple".to_string()));
+        assert_eq!(args.single_quantity::<String>().unwrap(), (1, "pear".to_string()));
+        assert_matches!(args.single_quantity::<String>().unwrap_err(), ArgError::Eos);
+        assert_eq!(args.remaining(), 1);
+    }
 }
@@ -13,20 +13,21 @@ use std::{
     sync::Arc
 };
 use crate::utils::Colour;
-use super::{Args, Configuration, HelpBehaviour};
+use super::{Args, Configuration, HelpBehaviour, NotFoundBehaviour};
 
 type CheckFunction = dyn Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
                      + Send
                      + Sync
                      + 'static;
 
-pub struct Check(pub(crate) Box<CheckFunction>);
+#[derive(Clone)]
+pub struct Check(pub(crate) Arc<CheckFunction>);
 
 impl Check {
     pub(crate) fn new<F: Send + Sync + 'static>(f: F) -> Self
         where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
     {
-        Check(Box::new(f))
+        Check(Arc::new(f))
     }
 }
 
@@ -38,6 +39,73 @@ impl Debug for Check {
     }
 }
 
+type ColourFunction = dyn Fn(&Context, &Message) -> Colour + Send + Sync + 'static;
+
+/// The colour of a help embed, either fixed or computed for each help message.
+///
+/// A fixed colour can be created from a [`Colour`] via `From`.
+///
+/// [`Colour`]: ../../utils/struct.Colour.html
+#[derive(Clone)]
+pub enum HelpColour {
+    /// The same colour is used for every help message.
+    Fixed(Colour),
+    /// The colour is computed from the context and the message requesting
+    /// help every time help is rendered.
+    Dynamic(Arc<ColourFunction>),
+}
+
+impl HelpColour {
+    /// Creates a colour computed every time help is rendered.
+    ///
+    /// # Examples
+    ///
+    /// Use the colour of the current user's highest coloured role in the
+    /// guild, if there is one:
+    ///
+    /// ```rust,no_run
+    /// use serenity::CACHE;
+    /// use serenity::framework::standard::HelpColour;
+    /// use serenity::utils::Colour;
+    ///
+    /// let colour = HelpColour::dynamic(|_, msg| {
+    ///     let user_id = CACHE.read().user.id;
+    ///
+    ///     msg.guild_id
+    ///         .and_then(|guild_id| guild_id.member(user_id).ok())
+    ///         .and_then(|member| member.colour())
+    ///         .unwrap_or(Colour::ROSEWATER)
+    /// });
+    /// ```
+    pub fn dynamic<F>(f: F) -> Self
+        where F: Fn(&Context, &Message) -> Colour + Send + Sync + 'static {
+        HelpColour::Dynamic(Arc::new(f))
+    }
+
+    /// Determines the colour to use for a help message.
+    pub fn resolve(&self, context: &Context, message: &Message) -> Colour {
+        match *self {
+            HelpColour::Fixed(colour) => colour,
+            HelpColour::Dynamic(ref f) => f(context, message),
+        }
+    }
+}
+
+impl Debug for HelpColour {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            HelpColour::Fixed(ref colour) => f.debug_tuple("Fixed").field(colour).finish(),
+            HelpColour::Dynamic(_) => f.debug_tuple("Dynamic").field(&"<fn>").finish(),
+        }
+    }
+}
+
+impl From<Colour> for HelpColour {
+    fn from(colour: Colour) -> HelpColour {
+        HelpColour::Fixed(colour)
+    }
+}
+
 pub type HelpFunction = fn(&mut Context, &Message, &HelpOptions, HashMap<String, Arc<CommandGroup>>, &Args)
                    -> Result<(), Error>;
 
@@ -61,6 +129,7 @@ pub type BeforeHook = dyn Fn(&mut Context, &Message, &str) -> bool + Send + Sync
 pub type AfterHook = dyn Fn(&mut Context, &Message, &str, Result<(), Error>) + Send + Sync + 'static;
 pub type UnrecognisedCommandHook = dyn Fn(&mut Context, &Message, &str) + Send + Sync + 'static;
 pub type MessageWithoutCommandHook = dyn Fn(&mut Context, &Message) + Send + Sync + 'static;
+pub type PrefixOnlyHook = dyn Fn(&mut Context, &Message) + Send + Sync + 'static;
 pub(crate) type InternalCommand = Arc<dyn Command>;
 pub type PrefixCheck = dyn Fn(&mut Context, &Message) -> Option<String> + Send + Sync + 'static;
 
@@ -101,11 +170,19 @@ pub struct CommandGroup {
     pub dm_only: bool,
     pub guild_only: bool,
     pub owner_privileges: bool,
+    pub owner_bypass_checks: bool,
     pub owners_only: bool,
     pub help: Option<Arc<Help>>,
     /// A set of checks to be called prior to executing the command-group. The checks
     /// will short-circuit on the first check that returns `false`.
     pub checks: Vec<Check>,
+    /// The names of checks registered on the framework via
+    /// [`StandardFramework::named_check`] to be called after `checks`.
+    ///
+    /// These are resolved into `checks` when added to the framework.
+    ///
+    /// [`StandardFramework::named_check`]: struct.StandardFramework.html#method.named_check
+    pub named_checks: Vec<String>,
     pub default_command: Option<CommandOrAlias>,
     pub description: Option<String>,
 }
@@ -120,22 +197,31 @@ impl Default for CommandGroup {
             dm_only: false,
             guild_only: false,
             owner_privileges: true,
+            owner_bypass_checks: true,
             help_available: true,
             owners_only: false,
             allowed_roles: Vec::new(),
             help: None,
             checks: Vec::new(),
+            named_checks: Vec::new(),
             default_command: None,
             description: None,
         }
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct CommandOptions {
     /// A set of checks to be called prior to executing the command. The checks
     /// will short-circuit on the first check that returns `false`.
     pub checks: Vec<Check>,
+    /// The names of checks registered on the framework via
+    /// [`StandardFramework::named_check`] to be called after `checks`.
+    ///
+    /// These are resolved into `checks` when added to the framework.
+    ///
+    /// [`StandardFramework::named_check`]: struct.StandardFramework.html#method.named_check
+    pub named_checks: Vec<String>,
     /// Ratelimit bucket.
     pub bucket: Option<String>,
     /// Command description, used by other commands.
@@ -158,12 +244,35 @@ pub struct CommandOptions {
     pub dm_only: bool,
     /// Whether command can be used only in guilds or not.
     pub guild_only: bool,
-    /// Whether the command treats owners as normal users.
+    /// Whether owners bypass the command's gates, as opposed to being treated
+    /// as normal users.
+    ///
+    /// Before a command is dispatched, the following is evaluated in order:
+    ///
+    /// 1. the number of arguments, which owners never bypass;
+    /// 2. the bucket, blocked guilds and channels, required permissions,
+    ///    `dm_only` and `guild_only`, `owners_only`, blocked users, disabled
+    ///    commands, and allowed roles, which owners bypass if this is `true`;
+    /// 3. the group's checks followed by the command's checks, which owners
+    ///    bypass if both this and [`owner_bypass_checks`] are `true`.
+    ///
+    /// [`owner_bypass_checks`]: #structfield.owner_bypass_checks
     pub owner_privileges: bool,
+    /// Whether owners with [`owner_privileges`] also bypass the group's and the
+    /// command's checks. If `false`, owners are still subject to them.
+    ///
+    /// [`owner_privileges`]: #structfield.owner_privileges
+    pub owner_bypass_checks: bool,
     /// Whether command can only be used by owners or not.
     pub owners_only: bool,
     /// Other names that can be used to call this command instead.
     pub aliases: Vec<String>,
+    /// Whether to delete the message invoking this command after it executed
+    /// successfully. If `None`, this follows the framework's configuration.
+    pub delete_message: Option<bool>,
+    /// Whether this command is never run when a near-miss of its name is
+    /// auto-corrected.
+    pub no_fuzzy: bool,
 }
 
 #[derive(Debug)]
@@ -196,6 +305,8 @@ pub struct HelpOptions {
     /// Output-example (without whitespace between both substitutions: `{command_not_found_text}{command_name}`
     /// `{command_name}` describes user's input as in: `{prefix}help {command_name}`.
     pub command_not_found_text: String,
+    /// How to respond when a command or group could not be found.
+    pub command_not_found_behaviour: NotFoundBehaviour,
     /// Explains the user on how to use access a single command's details.
     pub individual_command_tip: String,
     /// Explains reasoning behind strikethrough-commands, see fields requiring `HelpBehaviour` for further information.
@@ -220,9 +331,9 @@ pub struct HelpOptions {
     /// this behaviour will be executed.
     pub wrong_channel: HelpBehaviour,
     /// Colour help-embed will use upon encountering an error.
-    pub embed_error_colour: Colour,
+    pub embed_error_colour: HelpColour,
     /// Colour help-embed will use if no error occurred.
-    pub embed_success_colour: Colour,
+    pub embed_success_colour: HelpColour,
     /// If not 0, help will check whether a command is similar to searched named.
     pub max_levenshtein_distance: usize,
 }
@@ -257,6 +368,7 @@ impl Default for HelpOptions {
             dm_and_guild_text: "In DM and guilds".to_string(),
             available_text: "Available".to_string(),
             command_not_found_text: "**Error**: Command `{}` not found.".to_string(),
+            command_not_found_behaviour: NotFoundBehaviour::Text,
             individual_command_tip: "To get help with an individual command, pass its \
                  name as an argument to this command.".to_string(),
             group_prefix: "Prefix".to_string(),
@@ -265,8 +377,8 @@ impl Default for HelpOptions {
             lacking_role: HelpBehaviour::Strike,
             lacking_permissions: HelpBehaviour::Strike,
             wrong_channel: HelpBehaviour::Strike,
-            embed_error_colour: Colour::DARK_RED,
-            embed_success_colour: Colour::ROSEWATER,
+            embed_error_colour: Colour::DARK_RED.into(),
+            embed_success_colour: Colour::ROSEWATER.into(),
             max_levenshtein_distance: 0,
         }
     }
@@ -331,6 +443,7 @@ impl Default for CommandOptions {
         CommandOptions {
             aliases: Vec::new(),
             checks: Vec::default(),
+            named_checks: Vec::new(),
             desc: None,
             usage: None,
             example: None,
@@ -341,9 +454,12 @@ impl Default for CommandOptions {
             dm_only: false,
             guild_only: false,
             owner_privileges: true,
+            owner_bypass_checks: true,
             help_available: true,
             owners_only: false,
             allowed_roles: Vec::new(),
+            delete_message: None,
+            no_fuzzy: false,
         }
     }
 }
@@ -359,14 +475,20 @@ pub fn positions(ctx: &mut Context, msg: &Message, conf: &Configuration) -> Opti
         let mut positions = Vec::new();
 
         // Dynamic prefixes, if present and suitable, always have a higher priority.
-        if let Some(x) = conf.dynamic_prefix.as_ref().and_then(|f| f(ctx, msg)) {
-            if msg.content.starts_with(&x) {
-                positions.push(x.chars().count());
+        // If none is returned, fall back to the configured prefixes.
+        let dynamic_prefix = conf.dynamic_prefix
+            .as_ref()
+            .and_then(|f| f(ctx, msg))
+            .filter(|x| !x.is_empty());
+
+        if let Some(x) = dynamic_prefix {
+            if let Some(pos) = prefix_position(&msg.content, &x, conf.case_insensitive_prefix) {
+                positions.push(pos);
             }
         } else {
             for n in &conf.prefixes {
-                if msg.content.starts_with(n) {
-                    positions.push(n.chars().count());
+                if let Some(pos) = prefix_position(&msg.content, n, conf.case_insensitive_prefix) {
+                    positions.push(pos);
                 }
             }
         }
@@ -406,6 +528,40 @@ pub fn positions(ctx: &mut Context, msg: &Message, conf: &Configuration) -> Opti
     }
 }
 
+// Returns the number of characters of the content that the prefix spans if
+// the content starts with it.
+//
+// When matching case-insensitively, this counts the characters of the original
+// content, as lowercasing may change the number of characters.
+fn prefix_position(content: &str, prefix: &str, case_insensitive: bool) -> Option<usize> {
+    if !case_insensitive {
+        return if content.starts_with(prefix) {
+            Some(prefix.chars().count())
+        } else {
+            None
+        };
+    }
+
+    let prefix = prefix.to_lowercase();
+    let mut lowered = String::with_capacity(prefix.len());
+    let mut count = 0;
+
+    for c in content.chars() {
+        if lowered.len() >= prefix.len() {
+            break;
+        }
+
+        lowered.extend(c.to_lowercase());
+        count += 1;
+    }
+
+    if lowered == prefix {
+        Some(count)
+    } else {
+        None
+    }
+}
+
 fn find_mention_end(content: &str, conf: &Configuration) -> Option<usize> {
     conf.on_mention.as_ref().and_then(|mentions| {
         mentions
@@ -431,3 +587,33 @@ fn find_end_of_prefix_with_whitespace(content: &str, position: usize) -> Option<
     }
     Some(content.len())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::model::id::UserId;
+    use super::{find_mention_end, prefix_position};
+    use super::super::configuration::{mentions_of, Configuration};
+
+    #[test]
+    fn test_prefix_position() {
+        assert_eq!(prefix_position("Bot ping", "Bot ", false), Some(4));
+        assert_eq!(prefix_position("bot ping", "Bot ", false), None);
+
+        assert_eq!(prefix_position("bot ping", "Bot ", true), Some(4));
+        assert_eq!(prefix_position("BOT ping", "bot ", true), Some(4));
+        assert_eq!(prefix_position("bo", "bot ", true), None);
+        assert_eq!(prefix_position("Ünï ping", "ünÏ ", true), Some(4));
+        assert_eq!(prefix_position("robot ping", "bot", true), None);
+    }
+
+    #[test]
+    fn test_find_mention_end() {
+        let mut conf = Configuration::default();
+        assert_eq!(find_mention_end("<@7> ping", &conf), None);
+
+        conf.on_mention = Some(mentions_of(UserId(7)));
+        assert_eq!(find_mention_end("<@7> ping", &conf), Some(4));
+        assert_eq!(find_mention_end("<@!7> ping", &conf), Some(5));
+        assert_eq!(find_mention_end("<@8> ping", &conf), None);
+    }
+}
@@ -4,6 +4,7 @@ use crate::model::{
     channel::Message,
     id::{ChannelId, GuildId, UserId}
 };
+use parking_lot::RwLock;
 use std::{
     collections::HashSet,
     default::Default,
@@ -46,8 +47,11 @@ use super::command::{Command, InternalCommand, PrefixCheck};
 pub struct Configuration {
     #[doc(hidden)] pub allow_dm: bool,
     #[doc(hidden)] pub allow_whitespace: bool,
+    #[doc(hidden)] pub auto_correct: bool,
+    #[doc(hidden)] pub auto_correct_distance: usize,
     #[doc(hidden)] pub blocked_guilds: HashSet<GuildId>,
     #[doc(hidden)] pub blocked_users: HashSet<UserId>,
+    #[doc(hidden)] pub catch_panics: bool,
     #[doc(hidden)] pub allowed_channels: HashSet<ChannelId>,
     #[doc(hidden)] pub depth: usize,
     #[doc(hidden)] pub disabled_commands: HashSet<String>,
@@ -55,12 +59,15 @@ pub struct Configuration {
     #[doc(hidden)] pub ignore_bots: bool,
     #[doc(hidden)] pub ignore_webhooks: bool,
     #[doc(hidden)] pub on_mention: Option<Vec<String>>,
-    #[doc(hidden)] pub owners: HashSet<UserId>,
+    #[doc(hidden)] pub owners: Owners,
     #[doc(hidden)] pub prefixes: Vec<String>,
     #[doc(hidden)] pub no_dm_prefix: bool,
     #[doc(hidden)] pub delimiters: Vec<String>,
+    #[doc(hidden)] pub delete_message_on_command: bool,
     #[doc(hidden)] pub case_insensitive: bool,
+    #[doc(hidden)] pub case_insensitive_prefix: bool,
     #[doc(hidden)] pub prefix_only_cmd: Option<InternalCommand>,
+    #[doc(hidden)] pub unrecognised_command_delay: i64,
 }
 
 impl Configuration {
@@ -103,6 +110,39 @@ impl Configuration {
         self
     }
 
+    /// Whether to run a command when its name is only mistyped slightly, such
+    /// as `ban` when `!bam` is sent.
+    ///
+    /// A command is only run if no command matches exactly and it is the only
+    /// one within the [`auto_correct_distance`] of the typed name. Commands in
+    /// groups with prefixes are not considered, nor are commands that opt out
+    /// via [`CreateCommand::no_fuzzy`].
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// [`CreateCommand::no_fuzzy`]: struct.CreateCommand.html#method.no_fuzzy
+    /// [`auto_correct_distance`]: #method.auto_correct_distance
+    pub fn auto_correct(mut self, auto_correct: bool) -> Self {
+        self.auto_correct = auto_correct;
+
+        self
+    }
+
+    /// The maximum edit distance at which a mistyped command name is
+    /// auto-corrected, if [`auto_correct`] is enabled.
+    ///
+    /// Inserting, removing, or replacing a character, or swapping two adjacent
+    /// characters, each count as one edit.
+    ///
+    /// **Note**: Defaults to `1`.
+    ///
+    /// [`auto_correct`]: #method.auto_correct
+    pub fn auto_correct_distance(mut self, distance: usize) -> Self {
+        self.auto_correct_distance = distance;
+
+        self
+    }
+
     /// HashSet of channels Ids where commands will be working.
     ///
     /// **Note**: Defaults to an empty HashSet.
@@ -157,6 +197,11 @@ impl Configuration {
 
     /// HashSet of user Ids whose commands will be ignored.
     ///
+    /// Messages from these users are ignored before any prefix is resolved,
+    /// so they never trigger commands, the help command, or any of the
+    /// unrecognised-command or prefix-only handlers. No dispatch error is sent
+    /// for them either.
+    ///
     /// Guilds owned by user Ids will also be ignored.
     ///
     /// **Note**: Defaults to an empty HashSet.
@@ -183,6 +228,47 @@ impl Configuration {
         self
     }
 
+    /// Whether to delete the message that invoked a command after the command
+    /// executed successfully, such as to clean up after a `purge` command.
+    ///
+    /// This can be overridden per command via
+    /// [`CreateCommand::delete_message`].
+    ///
+    /// The message is only deleted in guild channels. Failing to delete it -
+    /// for example if it has already been deleted, or the current user lacks
+    /// the [Manage Messages] permission - is ignored.
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .configure(|c| c.prefix("!").delete_message_on_command(true))
+    ///     .command("keep", |c| c
+    ///         .delete_message(false)
+    ///         .exec(|_, msg, _| {
+    ///             msg.channel_id.say("This command's message is kept.")?;
+    ///
+    ///             Ok(())
+    ///         })));
+    /// ```
+    ///
+    /// [`CreateCommand::delete_message`]: struct.CreateCommand.html#method.delete_message
+    /// [Manage Messages]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    pub fn delete_message_on_command(mut self, delete_message_on_command: bool) -> Self {
+        self.delete_message_on_command = delete_message_on_command;
+
+        self
+    }
+
     /// The default depth of the message to check for commands.
     ///
     /// This determines how "far" into a message to check for a valid command.
@@ -239,6 +325,20 @@ impl Configuration {
     ///
     /// **Note**: Defaults to no dynamic prefix check.
     ///
+    /// # Precedence
+    ///
+    /// Prefixes are resolved in the following order:
+    ///
+    /// 1. a mention of the bot, if [`on_mention`] is enabled;
+    /// 2. the prefix returned by this closure, if it returns `Some` non-empty
+    ///    prefix. The [configured prefixes] are then _not_ checked, so that a
+    ///    custom prefix replaces the default one;
+    /// 3. otherwise, the [configured prefixes].
+    ///
+    /// This means that a closure looking up a custom prefix per guild only
+    /// needs to return `None` for guilds without one, and does not need to
+    /// return the default prefix itself.
+    ///
     /// # Examples
     ///
     /// If the Id of the channel is divisible by 5, return a prefix of `"!"`,
@@ -266,6 +366,33 @@ impl Configuration {
     ///         }.to_string())
     ///     })));
     /// ```
+    ///
+    /// Using a custom prefix for some guilds, falling back to `"~"` for all
+    /// other guilds:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// use serenity::framework::StandardFramework;
+    /// use serenity::model::id::GuildId;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut custom_prefixes = HashMap::new();
+    /// custom_prefixes.insert(GuildId(81384788765712384), "!".to_string());
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .configure(|c| c
+    ///         .prefix("~")
+    ///         .dynamic_prefix(move |_, msg| {
+    ///             msg.guild_id.and_then(|id| custom_prefixes.get(&id).cloned())
+    ///         })));
+    /// ```
+    ///
+    /// [`on_mention`]: #method.on_mention
+    /// [configured prefixes]: #method.prefixes
     pub fn dynamic_prefix<F>(mut self, dynamic_prefix: F) -> Self
         where F: Fn(&mut Context, &Message) -> Option<String> + Send + Sync + 'static {
         self.dynamic_prefix = Some(Box::new(dynamic_prefix));
@@ -287,13 +414,36 @@ impl Configuration {
 
     /// If set to true, bot will ignore all commands called by webhooks.
     ///
+    /// A message is considered to be sent by a webhook if its [`webhook_id`]
+    /// is present. These messages are ignored before any prefix is resolved,
+    /// and no dispatch error is sent for them.
+    ///
     /// **Note**: Defaults to `true`.
+    ///
+    /// [`webhook_id`]: ../../model/channel/struct.Message.html#structfield.webhook_id
     pub fn ignore_webhooks(mut self, ignore_webhooks: bool) -> Self {
         self.ignore_webhooks = ignore_webhooks;
 
         self
     }
 
+    /// Whether to catch panics raised while a command is executing.
+    ///
+    /// When enabled, a panicking command is logged and reported to the
+    /// [`on_dispatch_error`] handler as [`DispatchError::CommandPanicked`]
+    /// instead of unwinding through the framework's worker thread. The
+    /// `after` hooks are not called for a command that panicked.
+    ///
+    /// **Note**: Defaults to `true`.
+    ///
+    /// [`DispatchError::CommandPanicked`]: enum.DispatchError.html#variant.CommandPanicked
+    /// [`on_dispatch_error`]: struct.StandardFramework.html#method.on_dispatch_error
+    pub fn catch_panics(mut self, catch_panics: bool) -> Self {
+        self.catch_panics = catch_panics;
+
+        self
+    }
+
     /// Whether or not to respond to commands initiated with a mention. Note
     /// that this can be used in conjunction with [`prefix`].
     ///
@@ -314,18 +464,21 @@ impl Configuration {
     /// nickname. It has no real meaning for your bot, and the library
     /// encourages you to ignore differentiating between the two.
     ///
+    /// Both forms are derived from the bot's user Id, so there is no need to
+    /// register either of them manually.
+    ///
     /// [`prefix`]: #method.prefix
     pub fn on_mention(mut self, on_mention: bool) -> Self {
         if !on_mention {
             return self;
         }
 
-        if let Ok(current_user) = http::get_current_user() {
-            self.on_mention = Some(vec![
-                format!("<@{}>", current_user.id),  // Regular mention
-                format!("<@!{}>", current_user.id), // Nickname mention
-            ]);
-        }
+        // If the current user can't be retrieved yet, the mentions are filled
+        // in once the framework receives it from the `Ready` event.
+        self.on_mention = Some(match http::get_current_user() {
+            Ok(current_user) => mentions_of(current_user.id),
+            Err(_) => Vec::new(),
+        });
 
         self
     }
@@ -369,8 +522,13 @@ impl Configuration {
     ///
     /// client.with_framework(StandardFramework::new().configure(|c| c.owners(set)));
     /// ```
-    pub fn owners(mut self, user_ids: HashSet<UserId>) -> Self {
-        self.owners = user_ids;
+    ///
+    /// The owners can later be inspected and modified at runtime through the
+    /// handle returned by [`StandardFramework::owners`].
+    ///
+    /// [`StandardFramework::owners`]: struct.StandardFramework.html#method.owners
+    pub fn owners(self, user_ids: HashSet<UserId>) -> Self {
+        *self.owners.0.write() = user_ids;
 
         self
     }
@@ -473,6 +631,11 @@ impl Configuration {
     /// Sets multiple delimiters to be used when splitting the content after a command.
     /// Additionally cleans the default delimiter from the vector.
     ///
+    /// Quoted arguments are never split, even if they contain a delimiter.
+    /// The quoted accessors of [`Args`], such as [`Args::single_quoted`],
+    /// return them without their quotation marks, while the other accessors,
+    /// such as [`Args::single`], keep the quotation marks.
+    ///
     /// **Note**: Refer to [`delimiter`] for the default value.
     ///
     /// # Examples
@@ -492,6 +655,9 @@ impl Configuration {
     ///     .delimiters(vec![", ", " "])));
     /// ```
     ///
+    /// [`Args`]: struct.Args.html
+    /// [`Args::single`]: struct.Args.html#method.single
+    /// [`Args::single_quoted`]: struct.Args.html#method.single_quoted
     /// [`delimiter`]: #method.delimiter
     pub fn delimiters<T: ToString, It: IntoIterator<Item=T>>(mut self, delimiters: It) -> Self {
         self.delimiters.clear();
@@ -514,6 +680,40 @@ impl Configuration {
         self
     }
 
+    /// Whether prefixes should be matched regardless of case, so that a
+    /// prefix of `"Bot "` also matches messages starting with `"bot "` or
+    /// `"BOT "`.
+    ///
+    /// This applies to both the configured [`prefixes`] and the
+    /// [`dynamic_prefix`], and is independent of [`case_insensitivity`].
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new().configure(|c| c
+    ///     .prefix("Bot ")
+    ///     .case_insensitive_prefix(true)));
+    /// ```
+    ///
+    /// [`case_insensitivity`]: #method.case_insensitivity
+    /// [`dynamic_prefix`]: #method.dynamic_prefix
+    /// [`prefixes`]: #method.prefixes
+    pub fn case_insensitive_prefix(mut self, case_insensitive_prefix: bool) -> Self {
+        self.case_insensitive_prefix = case_insensitive_prefix;
+
+        self
+    }
+
     /// Sets a command to dispatch if user's input is a prefix only.
     ///
     /// **Note**: Defaults to no command and ignores prefix only.
@@ -522,6 +722,51 @@ impl Configuration {
 
         self
     }
+
+    /// Sets the number of seconds a user must wait between triggering the
+    /// [`unrecognised_command`] hook, so that a user repeatedly sending
+    /// unknown commands only triggers it once per interval.
+    ///
+    /// Unknown commands sent during the interval are treated as messages
+    /// without a command. Recognised commands are not affected; use buckets
+    /// to ratelimit those.
+    ///
+    /// **Note**: Defaults to `0`, meaning that the hook is always triggered.
+    ///
+    /// # Examples
+    ///
+    /// Only reply to a user's unknown commands once every 30 seconds:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .configure(|c| c.prefix("~").unrecognised_command_delay(30))
+    ///     .unrecognised_command(|_, msg, name| {
+    ///         let _ = msg.reply(&format!("There is no command named `{}`", name));
+    ///     }));
+    /// ```
+    ///
+    /// [`unrecognised_command`]: struct.StandardFramework.html#method.unrecognised_command
+    pub fn unrecognised_command_delay(mut self, delay: i64) -> Self {
+        self.unrecognised_command_delay = delay;
+
+        self
+    }
+}
+
+/// Builds both the regular and the nickname mention of a user.
+pub(crate) fn mentions_of(user_id: UserId) -> Vec<String> {
+    vec![
+        format!("<@{}>", user_id),  // Regular mention
+        format!("<@!{}>", user_id), // Nickname mention
+    ]
 }
 
 impl Default for Configuration {
@@ -530,9 +775,14 @@ impl Default for Configuration {
     /// - **allow_dm** to `true`
     /// - **allow_whitespace** to `false`
     /// - **allowed_channels** to an empty HashSet
+    /// - **auto_correct** to `false`
+    /// - **auto_correct_distance** to `1`
     /// - **blocked_guilds** to an empty HashSet
     /// - **blocked_users** to an empty HashSet
     /// - **case_insensitive** to `false`
+    /// - **catch_panics** to `true`
+    /// - **case_insensitive_prefix** to `false`
+    /// - **delete_message_on_command** to `false`
     /// - **delimiters** to `vec![" "]`
     /// - **depth** to `5`
     /// - **disabled_commands** to an empty HashSet
@@ -543,14 +793,20 @@ impl Default for Configuration {
     /// - **on_mention** to `false` (basically)
     /// - **owners** to an empty HashSet
     /// - **prefix** to an empty vector
+    /// - **unrecognised_command_delay** to `0`
     fn default() -> Configuration {
         Configuration {
             allow_dm: true,
             allow_whitespace: false,
             allowed_channels: HashSet::default(),
+            auto_correct: false,
+            auto_correct_distance: 1,
             blocked_guilds: HashSet::default(),
             blocked_users: HashSet::default(),
             case_insensitive: false,
+            case_insensitive_prefix: false,
+            catch_panics: true,
+            delete_message_on_command: false,
             delimiters: vec![" ".to_string()],
             depth: 5,
             disabled_commands: HashSet::default(),
@@ -559,9 +815,72 @@ impl Default for Configuration {
             ignore_webhooks: true,
             no_dm_prefix: false,
             on_mention: None,
-            owners: HashSet::default(),
+            owners: Owners::default(),
             prefixes: vec![],
             prefix_only_cmd: None,
+            unrecognised_command_delay: 0,
         }
     }
 }
+
+/// A shared handle to the set of owners of a [`StandardFramework`].
+///
+/// Cloning the handle is cheap, and all clones refer to the same set. Changes
+/// made through any handle take effect for the next dispatched command, so
+/// this can be stored - for example in [`Client::data`] - and used from within
+/// commands.
+///
+/// # Examples
+///
+/// Obtain the handle before giving the framework to the client, and add a
+/// co-owner at runtime:
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # struct Handler;
+/// #
+/// # impl EventHandler for Handler {}
+/// # let mut client = Client::new("token", Handler).unwrap();
+/// use serenity::model::id::UserId;
+/// use serenity::framework::StandardFramework;
+///
+/// let framework = StandardFramework::new().configure(|c| c
+///     .owners(vec![UserId(7)].into_iter().collect()));
+/// let owners = framework.owners();
+///
+/// client.with_framework(framework);
+///
+/// owners.add(UserId(77));
+/// assert!(owners.contains(UserId(77)));
+/// ```
+///
+/// [`Client::data`]: ../../client/struct.Client.html#structfield.data
+/// [`StandardFramework`]: struct.StandardFramework.html
+#[derive(Clone, Debug, Default)]
+pub struct Owners(Arc<RwLock<HashSet<UserId>>>);
+
+impl Owners {
+    /// Adds a user as an owner.
+    ///
+    /// Returns whether the user was not already an owner.
+    pub fn add<U: Into<UserId>>(&self, user_id: U) -> bool {
+        self.0.write().insert(user_id.into())
+    }
+
+    /// Whether the user is an owner.
+    pub fn contains<U: Into<UserId>>(&self, user_id: U) -> bool {
+        self.0.read().contains(&user_id.into())
+    }
+
+    /// Removes a user from the owners.
+    ///
+    /// Returns whether the user was an owner.
+    pub fn remove<U: Into<UserId>>(&self, user_id: U) -> bool {
+        self.0.write().remove(&user_id.into())
+    }
+
+    /// Returns a copy of the current set of owners.
+    pub fn to_set(&self) -> HashSet<UserId> {
+        self.0.read().clone()
+    }
+}
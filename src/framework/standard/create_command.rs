@@ -114,6 +114,21 @@ impl CreateCommand {
         self
     }
 
+    /// Adds a check registered on the framework under the given name via
+    /// [`StandardFramework::named_check`], allowing one check to be shared
+    /// between many commands.
+    ///
+    /// **Note**: The check must be registered before this is added to the
+    /// framework, which otherwise panics. These checks are bypassed for
+    /// commands sent by the application owner.
+    ///
+    /// [`StandardFramework::named_check`]: struct.StandardFramework.html#method.named_check
+    pub fn check_named(mut self, name: &str) -> Self {
+        self.0.named_checks.push(name.to_string());
+
+        self
+    }
+
     /// Description, used by other commands.
     pub fn desc(mut self, desc: &str) -> Self {
         self.0.desc = Some(desc.to_string());
@@ -161,6 +176,32 @@ impl CreateCommand {
         self
     }
 
+    /// Whether to delete the message invoking this command after it executed
+    /// successfully, overriding [`Configuration::delete_message_on_command`].
+    ///
+    /// [`Configuration::delete_message_on_command`]: struct.Configuration.html#method.delete_message_on_command
+    pub fn delete_message(mut self, delete_message: bool) -> Self {
+        self.0.delete_message = Some(delete_message);
+
+        self
+    }
+
+    /// Whether to never run this command when a near-miss of its name is
+    /// auto-corrected, requiring it to be typed exactly.
+    ///
+    /// This should be set for destructive commands.
+    ///
+    /// **Note**: Defaults to `false`.
+    ///
+    /// Refer to [`Configuration::auto_correct`] for more information.
+    ///
+    /// [`Configuration::auto_correct`]: struct.Configuration.html#method.auto_correct
+    pub fn no_fuzzy(mut self, no_fuzzy: bool) -> Self {
+        self.0.no_fuzzy = no_fuzzy;
+
+        self
+    }
+
     /// Whether command can be used only in guilds or not.
     pub fn guild_only(mut self, guild_only: bool) -> Self {
         self.0.guild_only = guild_only;
@@ -169,13 +210,33 @@ impl CreateCommand {
     }
 
     /// Whether owners shall bypass buckets, missing permissions,
-    /// wrong channels, missing roles, and checks.
+    /// wrong channels, missing roles, and, unless disabled via
+    /// [`owner_bypass_checks`], checks.
+    ///
+    /// Refer to [`CommandOptions::owner_privileges`] for the order in which
+    /// these are evaluated.
+    ///
+    /// [`CommandOptions::owner_privileges`]: struct.CommandOptions.html#structfield.owner_privileges
+    /// [`owner_bypass_checks`]: #method.owner_bypass_checks
     pub fn owner_privileges(mut self, owner_privileges: bool) -> Self {
         self.0.owner_privileges = owner_privileges;
 
         self
     }
 
+    /// Whether owners with [`owner_privileges`] shall also bypass checks.
+    ///
+    /// Set this to `false` to have owners bypass permission and role gates
+    /// while still being subject to checks, such as one disabling commands
+    /// during maintenance.
+    ///
+    /// [`owner_privileges`]: #method.owner_privileges
+    pub fn owner_bypass_checks(mut self, owner_bypass_checks: bool) -> Self {
+        self.0.owner_bypass_checks = owner_bypass_checks;
+
+        self
+    }
+
     /// Whether command should be displayed in help list or not, used by other commands.
     pub fn help_available(mut self, help_available: bool) -> Self {
         self.0.help_available = help_available;
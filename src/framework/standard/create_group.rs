@@ -44,7 +44,8 @@ impl CreateGroup {
             .guild_only(self.0.guild_only)
             .help_available(self.0.help_available)
             .owners_only(self.0.owners_only)
-            .owner_privileges(self.0.owner_privileges);
+            .owner_privileges(self.0.owner_privileges)
+            .owner_bypass_checks(self.0.owner_bypass_checks);
 
         if let Some(ref bucket) = self.0.bucket {
             cmd = cmd.bucket(bucket);
@@ -171,13 +172,33 @@ impl CreateGroup {
     }
 
     /// Whether owners shall bypass buckets, missing permissions,
-    /// wrong channels, missing roles, and checks.
+    /// wrong channels, missing roles, and, unless disabled via
+    /// [`owner_bypass_checks`], checks.
+    ///
+    /// Refer to [`CommandOptions::owner_privileges`] for the order in which
+    /// these are evaluated.
+    ///
+    /// [`CommandOptions::owner_privileges`]: struct.CommandOptions.html#structfield.owner_privileges
+    /// [`owner_bypass_checks`]: #method.owner_bypass_checks
     pub fn owner_privileges(mut self, owner_privileges: bool) -> Self {
         self.0.owner_privileges = owner_privileges;
 
         self
     }
 
+    /// Whether owners with [`owner_privileges`] shall also bypass checks.
+    ///
+    /// Set this to `false` to have owners bypass permission and role gates
+    /// while still being subject to checks, such as one disabling commands
+    /// during maintenance.
+    ///
+    /// [`owner_privileges`]: #method.owner_privileges
+    pub fn owner_bypass_checks(mut self, owner_bypass_checks: bool) -> Self {
+        self.0.owner_bypass_checks = owner_bypass_checks;
+
+        self
+    }
+
     /// Whether command should be displayed in help list or not, used by other commands.
     pub fn help_available(mut self, help_available: bool) -> Self {
         self.0.help_available = help_available;
@@ -222,6 +243,20 @@ impl CreateGroup {
         self
     }
 
+    /// Adds a check registered on the framework under the given name via
+    /// [`StandardFramework::named_check`] to the group.
+    ///
+    /// **Note**: The check must be registered before this is added to the
+    /// framework, which otherwise panics. These checks are bypassed for
+    /// commands sent by the application owner.
+    ///
+    /// [`StandardFramework::named_check`]: struct.StandardFramework.html#method.named_check
+    pub fn check_named(mut self, name: &str) -> Self {
+        self.0.named_checks.push(name.to_string());
+
+        self
+    }
+
     /// Adds a command for a group that will be executed if no command-name
     /// has been passed.
     pub fn default_cmd<C: Command + 'static>(mut self, c: C) -> Self {
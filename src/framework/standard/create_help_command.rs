@@ -1,5 +1,6 @@
 use super::command::{
     Help,
+    HelpColour,
     HelpOptions,
     HelpFunction
 };
@@ -8,10 +9,10 @@ pub use super::{
     CommandGroup,
     CommandOptions,
     CommandError,
-    HelpBehaviour
+    HelpBehaviour,
+    NotFoundBehaviour
 };
 
-use crate::utils::Colour;
 use std::{
     fmt::Write,
     sync::Arc
@@ -118,6 +119,22 @@ impl CreateHelpCommand {
         self
     }
 
+    /// Sets how to respond upon failing to find an individual command.
+    ///
+    /// Similar commands are suggested if there are any. Otherwise, by default
+    /// this is [`NotFoundBehaviour::Text`], responding with the
+    /// [`no_help_available_text`]. Use [`NotFoundBehaviour::Ignore`] to not
+    /// respond at all, such as to avoid spam in large guilds.
+    ///
+    /// [`NotFoundBehaviour::Ignore`]: enum.NotFoundBehaviour.html#variant.Ignore
+    /// [`NotFoundBehaviour::Text`]: enum.NotFoundBehaviour.html#variant.Text
+    /// [`no_help_available_text`]: #method.no_help_available_text
+    pub fn command_not_found_behaviour(mut self, behaviour: NotFoundBehaviour) -> Self {
+        self.0.command_not_found_behaviour = behaviour;
+
+        self
+    }
+
     /// Sets the message on top of the help-menu, informing the
     /// user how to obtain more information about a single command.
     pub fn individual_command_tip(mut self, text: &str) -> Self {
@@ -205,15 +222,27 @@ impl CreateHelpCommand {
     }
 
     /// Sets the colour for the embed if no error occurred.
-    pub fn embed_success_colour(mut self, colour: Colour) -> Self {
-        self.0.embed_success_colour = colour;
+    ///
+    /// This is either a fixed [`Colour`], or a [`HelpColour::dynamic`]
+    /// computed for each help message.
+    ///
+    /// [`Colour`]: ../../utils/struct.Colour.html
+    /// [`HelpColour::dynamic`]: enum.HelpColour.html#method.dynamic
+    pub fn embed_success_colour<C: Into<HelpColour>>(mut self, colour: C) -> Self {
+        self.0.embed_success_colour = colour.into();
 
         self
     }
 
     /// Sets the colour for the embed if an error occurred.
-    pub fn embed_error_colour(mut self, colour: Colour) -> Self {
-        self.0.embed_error_colour = colour;
+    ///
+    /// This is either a fixed [`Colour`], or a [`HelpColour::dynamic`]
+    /// computed for each help message.
+    ///
+    /// [`Colour`]: ../../utils/struct.Colour.html
+    /// [`HelpColour::dynamic`]: enum.HelpColour.html#method.dynamic
+    pub fn embed_error_colour<C: Into<HelpColour>>(mut self, colour: C) -> Self {
+        self.0.embed_error_colour = colour.into();
 
         self
     }
@@ -48,6 +48,7 @@ use super::{
     CommandOptions,
     CommandError,
     HelpBehaviour,
+    NotFoundBehaviour,
 };
 use crate::utils::Colour;
 
@@ -154,6 +155,8 @@ pub enum CustomisedHelpData<'a> {
     SingleCommand { command: Command<'a> },
     /// To display failure in finding a fitting command.
     NoCommandFound { help_error_message: &'a str },
+    /// To not respond at all, as configured for unknown commands.
+    Nothing,
 }
 
 /// Wraps around a `Vec<Vec<T>>` and provides access
@@ -222,6 +225,51 @@ pub(crate) fn levenshtein_distance(word_a: &str, word_b: &str) -> usize {
     matrix[(len_a, len_b)]
 }
 
+/// Calculates and returns the optimal string alignment distance between two
+/// passed words, which is the levenshtein distance with swapping two adjacent
+/// characters counting as a single edit.
+pub(crate) fn optimal_string_alignment_distance(word_a: &str, word_b: &str) -> usize {
+    let chars_a = word_a.chars().collect::<Vec<_>>();
+    let chars_b = word_b.chars().collect::<Vec<_>>();
+    let len_a = chars_a.len();
+    let len_b = chars_b.len();
+
+    if len_a == 0 {
+        return len_b;
+    } else if len_b == 0 {
+        return len_a;
+    }
+
+    let mut matrix = Matrix::new(len_b + 1, len_a + 1);
+
+    for x in 0..len_a {
+        matrix[(x + 1, 0)] = matrix[(x, 0)] + 1;
+    }
+
+    for y in 0..len_b {
+        matrix[(0, y + 1)] = matrix[(0, y)] + 1;
+    }
+
+    for x in 0..len_a {
+
+        for y in 0..len_b {
+            let substitution = if chars_a[x] == chars_b[y] { 0 } else { 1 };
+
+            let mut distance = (matrix[(x, y + 1)] + 1)
+                .min(matrix[(x + 1, y)] + 1)
+                .min(matrix[(x, y)] + substitution);
+
+            if x > 0 && y > 0 && chars_a[x] == chars_b[y - 1] && chars_a[x - 1] == chars_b[y] {
+                distance = distance.min(matrix[(x - 1, y - 1)] + 1);
+            }
+
+            matrix[(x + 1, y + 1)] = distance;
+        }
+    }
+
+    matrix[(len_a, len_b)]
+}
+
 fn remove_aliases(cmds: &HashMap<String, CommandOrAlias>) -> HashMap<&String, &InternalCommand> {
     let mut result = HashMap::new();
 
@@ -532,6 +580,29 @@ let commands = remove_aliases(&group.commands);
     group_with_cmds
 }
 
+/// Forges the `CustomisedHelpData` for a command or group that could not be
+/// found, taking the `NotFoundBehaviour` into consideration when there are no
+/// similar commands to suggest.
+#[cfg(feature = "cache")]
+fn not_found_help_data<'a>(
+    help_options: &'a HelpOptions,
+    suggestions: Vec<SuggestedCommandName>,
+) -> CustomisedHelpData<'a> {
+    if !suggestions.is_empty() {
+        return CustomisedHelpData::SuggestedCommands {
+            help_description: help_options.suggestion_text.clone(),
+            suggestions: Suggestions(suggestions),
+        };
+    }
+
+    match help_options.command_not_found_behaviour {
+        NotFoundBehaviour::Text => CustomisedHelpData::NoCommandFound {
+            help_error_message: &help_options.no_help_available_text,
+        },
+        NotFoundBehaviour::Ignore => CustomisedHelpData::Nothing,
+    }
+}
+
 /// Iterates over all commands and forges them into a `CustomisedHelpData`
 /// taking `HelpOptions` into consideration when deciding on whether a command
 /// shall be picked and in what textual format.
@@ -573,16 +644,7 @@ pub fn create_customised_help_data<'a, H: BuildHasher>(
                     }
                 }
 
-                if suggestions.is_empty() {
-                    CustomisedHelpData::NoCommandFound {
-                        help_error_message: &help_options.no_help_available_text,
-                    }
-                } else {
-                    CustomisedHelpData::SuggestedCommands {
-                        help_description: help_options.suggestion_text.clone(),
-                        suggestions: Suggestions(suggestions),
-                    }
-                }
+                not_found_help_data(help_options, suggestions)
             },
         };
     }
@@ -730,7 +792,7 @@ fn send_error_embed(channel_id: ChannelId, input: &str, colour: Colour) -> Resul
 /// ```
 #[cfg(feature = "cache")]
 pub fn with_embeds<H: BuildHasher>(
-    _: &mut Context,
+    context: &mut Context,
     msg: &Message,
     help_options: &HelpOptions,
     groups: HashMap<String, Arc<CommandGroup>, H>,
@@ -744,13 +806,13 @@ pub fn with_embeds<H: BuildHasher>(
                 msg.channel_id,
                 &help_description,
                 &suggestions,
-                help_options.embed_error_colour,
+                help_options.embed_error_colour.resolve(context, msg),
             ),
-        &CustomisedHelpData::NoCommandFound { ref help_error_message } =>
+        &CustomisedHelpData::NoCommandFound { help_error_message } =>
             send_error_embed(
                 msg.channel_id,
                 help_error_message,
-                help_options.embed_error_colour,
+                help_options.embed_error_colour.resolve(context, msg),
             ),
         &CustomisedHelpData::GroupedCommands { ref help_description, ref groups } =>
             send_grouped_commands_embed(
@@ -758,15 +820,16 @@ pub fn with_embeds<H: BuildHasher>(
                 msg.channel_id,
                 &help_description,
                 &groups,
-                help_options.embed_success_colour,
+                help_options.embed_success_colour.resolve(context, msg),
             ),
         &CustomisedHelpData::SingleCommand { ref command } =>
             send_single_command_embed(
                 &help_options,
                 msg.channel_id,
                 &command,
-                help_options.embed_success_colour,
+                help_options.embed_success_colour.resolve(context, msg),
             ),
+        &CustomisedHelpData::Nothing => return Ok(()),
     } {
         warn_about_failed_send!(&formatted_help, why);
     }
@@ -861,6 +924,7 @@ pub fn plain<H: BuildHasher>(
         &CustomisedHelpData::SingleCommand { ref command } => {
             single_command_to_plain_string(&help_options, &command)
         },
+        &CustomisedHelpData::Nothing => return Ok(()),
     };
 
     if let Err(why) = msg.channel_id.say(result) {
@@ -873,7 +937,7 @@ pub fn plain<H: BuildHasher>(
 
 #[cfg(test)]
 mod levenshtein_tests {
-    use super::levenshtein_distance;
+    use super::{levenshtein_distance, optimal_string_alignment_distance};
 
     #[test]
     fn reflexive() {
@@ -921,6 +985,65 @@ mod levenshtein_tests {
 
         assert!(distance_of_a_c <= (distance_of_a_b + distance_of_b_c));
     }
+
+    #[test]
+    fn adjacent_transposition() {
+        assert_eq!(2, levenshtein_distance("ban", "bna"));
+        assert_eq!(1, optimal_string_alignment_distance("ban", "bna"));
+        assert_eq!(2, optimal_string_alignment_distance("ban", "nba"));
+        assert_eq!(1, optimal_string_alignment_distance("ban", "bam"));
+        assert_eq!(3, optimal_string_alignment_distance("", "ban"));
+        assert_eq!(0, optimal_string_alignment_distance("ban", "ban"));
+    }
+}
+
+#[cfg(all(test, feature = "cache"))]
+mod not_found_tests {
+    use super::{not_found_help_data, CustomisedHelpData, SuggestedCommandName};
+    use super::super::{HelpOptions, NotFoundBehaviour};
+
+    fn suggestion() -> Vec<SuggestedCommandName> {
+        vec![SuggestedCommandName {
+            name: "ping".to_string(),
+            levenshtein_distance: 1,
+        }]
+    }
+
+    #[test]
+    fn text() {
+        let help_options = HelpOptions::default();
+
+        match not_found_help_data(&help_options, vec![]) {
+            CustomisedHelpData::NoCommandFound { help_error_message } => {
+                assert_eq!(help_error_message, help_options.no_help_available_text);
+            },
+            other => panic!("Unexpected help data: {:?}", other),
+        }
+
+        match not_found_help_data(&help_options, suggestion()) {
+            CustomisedHelpData::SuggestedCommands { .. } => {},
+            other => panic!("Unexpected help data: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn ignore() {
+        let help_options = HelpOptions {
+            command_not_found_behaviour: NotFoundBehaviour::Ignore,
+            ..HelpOptions::default()
+        };
+
+        match not_found_help_data(&help_options, vec![]) {
+            CustomisedHelpData::Nothing => {},
+            other => panic!("Unexpected help data: {:?}", other),
+        }
+
+        // Similar commands are still suggested.
+        match not_found_help_data(&help_options, suggestion()) {
+            CustomisedHelpData::SuggestedCommands { .. } => {},
+            other => panic!("Unexpected help data: {:?}", other),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -12,12 +12,15 @@ mod args;
 pub use self::args::{
     Args,
     Iter,
-    Error as ArgError
+    Error as ArgError,
+    ParseMention,
+    RangeError,
 };
 pub(crate) use self::buckets::{Bucket, Ratelimit};
 pub(crate) use self::command::Help;
 pub use self::command::{
     Check,
+    HelpColour,
     HelpFunction,
     HelpOptions,
     Command,
@@ -26,7 +29,7 @@ pub use self::command::{
     Error as CommandError
 };
 pub use self::command::CommandOrAlias;
-pub use self::configuration::Configuration;
+pub use self::configuration::{Configuration, Owners};
 pub use self::create_help_command::CreateHelpCommand;
 pub use self::create_command::{CreateCommand, FnOrCommand};
 pub use self::create_group::CreateGroup;
@@ -39,10 +42,19 @@ use crate::model::{
     id::{ChannelId, GuildId, UserId},
     Permissions
 };
-use self::command::{AfterHook, BeforeHook, MessageWithoutCommandHook, UnrecognisedCommandHook};
+use self::command::{
+    AfterHook,
+    BeforeHook,
+    InternalCommand,
+    MessageWithoutCommandHook,
+    PrefixOnlyHook,
+    UnrecognisedCommandHook,
+};
+use chrono::Utc;
 use std::{
     collections::HashMap,
     default::Default,
+    panic::{self, AssertUnwindSafe},
     sync::Arc
 };
 use super::Framework;
@@ -53,6 +65,12 @@ use crate::client::CACHE;
 #[cfg(feature = "cache")]
 use crate::model::channel::Channel;
 
+/// The number of users whose last unrecognised command is remembered before
+/// those whose [`unrecognised_command_delay`] has passed are forgotten.
+///
+/// [`unrecognised_command_delay`]: struct.Configuration.html#method.unrecognised_command_delay
+const UNRECOGNISED_COMMAND_TIMES_LIMIT: usize = 1000;
+
 /// A convenience macro for generating a struct fulfilling the [`Command`][command trait] trait.
 ///
 /// This is meant for use with the [`StandardFramework`], specifically `Framework`::{[`cmd`]/[`command`]}.
@@ -169,6 +187,12 @@ pub enum DispatchError {
     /// When the requested command is disabled in bot configuration.
     CommandDisabled(String),
     /// When the user is blocked in bot configuration.
+    ///
+    /// **Note**: This is no longer dispatched, as messages from blocked users
+    /// are ignored before any prefix is resolved. Refer to
+    /// [`Configuration::blocked_users`].
+    ///
+    /// [`Configuration::blocked_users`]: struct.Configuration.html#method.blocked_users
     BlockedUser,
     /// When the guild or its owner is blocked in bot configuration.
     BlockedGuild,
@@ -197,7 +221,19 @@ pub enum DispatchError {
     /// ignored.
     IgnoredBot,
     /// When the bot ignores webhooks and a command was issued by one.
+    ///
+    /// **Note**: This is no longer dispatched, as messages from webhooks are
+    /// ignored before any prefix is resolved. Refer to
+    /// [`Configuration::ignore_webhooks`].
+    ///
+    /// [`Configuration::ignore_webhooks`]: struct.Configuration.html#method.ignore_webhooks
     WebhookAuthor,
+    /// When the command panicked while executing.
+    ///
+    /// This is only dispatched if [`Configuration::catch_panics`] is enabled.
+    ///
+    /// [`Configuration::catch_panics`]: struct.Configuration.html#method.catch_panics
+    CommandPanicked,
 }
 
 type DispatchErrorHook = dyn Fn(Context, Message, DispatchError) + Send + Sync + 'static;
@@ -215,9 +251,16 @@ pub struct StandardFramework {
     before: Option<Arc<BeforeHook>>,
     dispatch_error_handler: Option<Arc<DispatchErrorHook>>,
     buckets: HashMap<String, Bucket>,
+    checks: HashMap<String, Check>,
     after: Option<Arc<AfterHook>>,
     unrecognised_command: Option<Arc<UnrecognisedCommandHook>>,
+    /// When each user last triggered the unrecognised command hook, used to
+    /// apply [`Configuration::unrecognised_command_delay`].
+    ///
+    /// [`Configuration::unrecognised_command_delay`]: struct.Configuration.html#method.unrecognised_command_delay
+    unrecognised_command_times: HashMap<u64, i64>,
     message_without_command: Option<Arc<MessageWithoutCommandHook>>,
+    prefix_only: Option<Arc<PrefixOnlyHook>>,
     /// Whether the framework has been "initialized".
     ///
     /// The framework is initialized once one of the following occurs:
@@ -278,6 +321,32 @@ impl StandardFramework {
         self
     }
 
+    /// Returns a handle to the set of owners configured via
+    /// [`Configuration::owners`].
+    ///
+    /// The handle can be used to inspect and modify the owners while the
+    /// framework is running. Refer to [`Owners`] for more information.
+    ///
+    /// [`Configuration::owners`]: struct.Configuration.html#method.owners
+    /// [`Owners`]: struct.Owners.html
+    pub fn owners(&self) -> Owners {
+        self.configuration.owners.clone()
+    }
+
+    /// Adds a user as an owner of the framework.
+    ///
+    /// Returns whether the user was not already an owner.
+    pub fn add_owner<U: Into<UserId>>(&self, user_id: U) -> bool {
+        self.configuration.owners.add(user_id)
+    }
+
+    /// Removes a user from the owners of the framework.
+    ///
+    /// Returns whether the user was an owner.
+    pub fn remove_owner<U: Into<UserId>>(&self, user_id: U) -> bool {
+        self.configuration.owners.remove(user_id)
+    }
+
     /// Defines a bucket with `delay` between each command, and the `limit` of uses
     /// per `time_span`.
     ///
@@ -435,6 +504,50 @@ impl StandardFramework {
         self
     }
 
+    /// Registers a check under a name, so that it can be shared between many
+    /// commands and groups through [`CreateCommand::check_named`] and
+    /// [`CreateGroup::check_named`].
+    ///
+    /// Names are resolved when a command or group is added, so the check
+    /// must be registered before any command or group using it. Registering a
+    /// check under a name that is already taken replaces the previous check
+    /// for commands and groups added afterwards.
+    ///
+    /// # Examples
+    ///
+    /// Sharing an owner check between two commands:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .named_check("owner", |_, msg, _, _| msg.author.id == 7)
+    ///     .command("ping", |c| c
+    ///         .check_named("owner")
+    ///         .exec(|_, msg, _| { msg.channel_id.say("pong!")?; Ok(()) }))
+    ///     .command("pong", |c| c
+    ///         .check_named("owner")
+    ///         .exec(|_, msg, _| { msg.channel_id.say("ping!")?; Ok(()) })));
+    /// ```
+    ///
+    /// [`CreateCommand::check_named`]: struct.CreateCommand.html#method.check_named
+    /// [`CreateGroup::check_named`]: struct.CreateGroup.html#method.check_named
+    pub fn named_check<F>(mut self, name: &str, check: F) -> Self
+        where F: Fn(&mut Context, &Message, &mut Args, &CommandOptions) -> bool
+                     + Send
+                     + Sync
+                     + 'static {
+        self.checks.insert(name.to_string(), Check::new(check));
+
+        self
+    }
+
     /// Defines a bucket with only a `delay` between each command.
     ///
     /// # Examples
@@ -498,12 +611,173 @@ impl StandardFramework {
                 .contains(&message.channel_id)
     }
 
+    /// Whether the author of the message is never allowed to use the
+    /// framework, either due to being a blocked user or a webhook that is to
+    /// be ignored.
+    #[inline]
+    fn is_ignored_author(&self, message: &Message) -> bool {
+        (self.configuration.ignore_webhooks && message.webhook_id.is_some())
+            || self.configuration.blocked_users.contains(&message.author.id)
+    }
+
+    /// Whether the user may trigger the unrecognised command hook at the given
+    /// time, recording the time if so.
+    ///
+    /// Nothing is recorded if there is no hook. Once
+    /// `UNRECOGNISED_COMMAND_TIMES_LIMIT` users are recorded, the users whose
+    /// delay has passed are forgotten.
+    fn take_unrecognised_command(&mut self, user_id: UserId, time: i64) -> bool {
+        if self.unrecognised_command.is_none() {
+            return false;
+        }
+
+        let delay = self.configuration.unrecognised_command_delay;
+
+        if delay <= 0 {
+            return true;
+        }
+
+        if self.unrecognised_command_times.len() >= UNRECOGNISED_COMMAND_TIMES_LIMIT {
+            self.unrecognised_command_times
+                .retain(|_, last_time| time < last_time.saturating_add(delay));
+        }
+
+        let last_time = self.unrecognised_command_times.entry(user_id.0).or_insert(i64::MIN);
+
+        if time < last_time.saturating_add(delay) {
+            return false;
+        }
+
+        *last_time = time;
+
+        true
+    }
+
+    /// Whether the invoking message of the command is deleted once it
+    /// succeeds, either as set for the command or as configured for all
+    /// commands.
+    fn deletes_message(&self, command: &InternalCommand) -> bool {
+        command.options()
+            .delete_message
+            .unwrap_or(self.configuration.delete_message_on_command)
+    }
+
+    /// Creates the job running a command that passed its checks, calling the
+    /// `before` and `after` hooks around it with the command's name.
+    fn command_job(&self,
+                   mut context: Context,
+                   message: Message,
+                   command: InternalCommand,
+                   args: Args,
+                   built: String) -> impl FnOnce() + Send + 'static {
+        let before = self.before.clone();
+        let after = self.after.clone();
+
+        let delete_message = self.deletes_message(&command);
+
+        let dispatch_error_handler = self.dispatch_error_handler.clone();
+        let catch_panics = self.configuration.catch_panics;
+
+        move || {
+            if let Some(before) = before {
+                if !(before)(&mut context, &message, &built) {
+                    return;
+                }
+            }
+
+            if !command.before(&mut context, &message) {
+                return;
+            }
+
+            let result = run_command(catch_panics, || {
+                command.execute(&mut context, &message, args)
+            });
+
+            let result = match result {
+                Some(result) => result,
+                None => {
+                    if let Some(handler) = dispatch_error_handler {
+                        handler(context, message, DispatchError::CommandPanicked);
+                    }
+
+                    return;
+                },
+            };
+            let succeeded = result.is_ok();
+
+            command.after(&mut context, &message, &result);
+
+            if let Some(after) = after {
+                (after)(&mut context, &message, &built, result);
+            }
+
+            if succeeded && delete_message {
+                delete_invoking_message(&message);
+            }
+        }
+    }
+
+    /// Finds the command that `name` is a near-miss of, if there is exactly
+    /// one within the [`auto_correct_distance`].
+    ///
+    /// Aliases resolve to the command they point to. Groups with prefixes are
+    /// not searched, and `None` is returned if the only match opted out via
+    /// [`no_fuzzy`].
+    ///
+    /// [`auto_correct_distance`]: struct.Configuration.html#method.auto_correct_distance
+    /// [`no_fuzzy`]: struct.CreateCommand.html#method.no_fuzzy
+    fn fuzzy_command(&self, name: &str) -> Option<(String, InternalCommand, Arc<CommandGroup>)> {
+        let name = if self.configuration.case_insensitive {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        };
+
+        let mut found: Option<(String, InternalCommand, Arc<CommandGroup>)> = None;
+
+        for group in self.groups.values() {
+            if group.prefixes.is_some() {
+                continue;
+            }
+
+            for (key, command) in &group.commands {
+                let distance = help_commands::optimal_string_alignment_distance(&name, key);
+
+                if distance == 0 || distance > self.configuration.auto_correct_distance {
+                    continue;
+                }
+
+                let (target, command) = match command {
+                    CommandOrAlias::Command(command) => (key, command),
+                    CommandOrAlias::Alias(points_to) => match group.commands.get(points_to) {
+                        Some(CommandOrAlias::Command(command)) => (points_to, command),
+                        _ => continue,
+                    },
+                };
+
+                if let Some((ref found_name, _, _)) = found {
+                    // A command and its alias may both be near-misses.
+                    if found_name == target {
+                        continue;
+                    }
+
+                    return None;
+                }
+
+                found = Some((target.clone(), Arc::clone(command), Arc::clone(group)));
+            }
+        }
+
+        match found {
+            Some((_, ref command, _)) if command.options().no_fuzzy => None,
+            found => found,
+        }
+    }
+
     #[inline]
     fn should_fail_common(&self, message: &Message) -> Option<DispatchError> {
         if self.configuration.ignore_bots && message.author.bot {
             Some(DispatchError::IgnoredBot)
-        } else if self.configuration.ignore_webhooks && message.webhook_id.is_some() {
-            Some(DispatchError::WebhookAuthor)
         } else {
             None
         }
@@ -512,7 +786,7 @@ impl StandardFramework {
     #[allow(too_many_arguments)]
     #[cfg_attr(feature = "cargo-clippy", allow(cyclomatic_complexity))]
     fn should_fail(&mut self,
-                   mut context: &mut Context,
+                   context: &mut Context,
                    message: &Message,
                    command: &Arc<CommandOptions>,
                    group: &Arc<CommandGroup>,
@@ -543,8 +817,14 @@ impl StandardFramework {
                 }
             }
 
-            if command.owner_privileges && self.configuration.owners.contains(&message.author.id) {
-                return None;
+            let is_owner = self.configuration.owners.contains(message.author.id);
+
+            if command.owner_privileges && is_owner {
+                return if command.owner_bypass_checks {
+                    None
+                } else {
+                    self.failed_check(context, message, command, group, args)
+                };
             }
 
             if let Some(ref bucket) = command.bucket {
@@ -594,12 +874,8 @@ impl StandardFramework {
                 }
             }
 
-            if command.owners_only {
+            if command.owners_only && !is_owner {
                 Some(DispatchError::OnlyForOwners)
-            } else if self.configuration
-                   .blocked_users
-                   .contains(&message.author.id) {
-                Some(DispatchError::BlockedUser)
             } else if self.configuration.disabled_commands.contains(to_check) {
                 Some(DispatchError::CommandDisabled(to_check.to_string()))
             } else if self.configuration.disabled_commands.contains(built) {
@@ -624,29 +900,74 @@ impl StandardFramework {
                     }
                 }
 
-                let all_group_checks_passed = group
-                    .checks
-                    .iter()
-                    .all(|check| (check.0)(&mut context, message, args, command));
+                self.failed_check(context, message, command, group, args)
+            }
+        }
+    }
 
-                if !all_group_checks_passed {
-                    return Some(DispatchError::CheckFailed);
-                }
+    /// Runs the group's checks and then the command's checks, returning an
+    /// error on the first one that fails.
+    fn failed_check(&self,
+                    mut context: &mut Context,
+                    message: &Message,
+                    command: &Arc<CommandOptions>,
+                    group: &Arc<CommandGroup>,
+                    args: &mut Args)
+                    -> Option<DispatchError> {
+        let all_group_checks_passed = group
+            .checks
+            .iter()
+            .all(|check| (check.0)(&mut context, message, args, command));
 
-                let all_command_checks_passed = command
-                    .checks
-                    .iter()
-                    .all(|check| (check.0)(&mut context, message, args, command));
+        if !all_group_checks_passed {
+            return Some(DispatchError::CheckFailed);
+        }
 
-                if all_command_checks_passed {
-                    None
-                } else {
-                    Some(DispatchError::CheckFailed)
-                }
-            }
+        let all_command_checks_passed = command
+            .checks
+            .iter()
+            .all(|check| (check.0)(&mut context, message, args, command));
+
+        if all_command_checks_passed {
+            None
+        } else {
+            Some(DispatchError::CheckFailed)
         }
     }
 
+    /// Returns the checks registered under the given names.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no check has been registered under one of the names.
+    fn named_checks(&self, names: &[String]) -> Vec<Check> {
+        names.iter().map(|name| match self.checks.get(name) {
+            Some(check) => check.clone(),
+            None => panic!("No check has been registered under the name `{}`", name),
+        }).collect()
+    }
+
+    /// Resolves the command's named checks to the checks registered under
+    /// those names, adding them after its other checks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no check has been registered under one of the names.
+    fn resolve_named_checks(&self, cmd: Arc<dyn Command>) -> Arc<dyn Command> {
+        let options = cmd.options();
+
+        if options.named_checks.is_empty() {
+            return cmd;
+        }
+
+        let mut options = (*options).clone();
+        let checks = self.named_checks(&options.named_checks);
+        options.checks.extend(checks);
+        options.named_checks.clear();
+
+        Arc::new(NamedChecks(cmd, Arc::new(options)))
+    }
+
     /// Adds a function to be associated with a command, which will be called
     /// when a command is used in a message.
     ///
@@ -698,14 +1019,14 @@ impl StandardFramework {
     /// [`on`]: #method.on
     /// [`Command`]: trait.Command.html
     pub fn cmd<C: Command + 'static>(mut self, name: &str, c: C) -> Self {
+        let cmd = self.resolve_named_checks(Arc::new(c));
+
         {
             let ungrouped = self.groups
                 .entry("Ungrouped".to_string())
                 .or_insert_with(|| Arc::new(CommandGroup::default()));
 
             if let Some(ref mut group) = Arc::get_mut(ungrouped) {
-                let cmd: Arc<dyn Command> = Arc::new(c);
-
                 for alias in &cmd.options().aliases {
                      group.commands.insert(
                          alias.to_string(),
@@ -739,13 +1060,14 @@ impl StandardFramework {
     /// ```
     pub fn command<F>(mut self, command_name: &str, f: F) -> Self
         where F: FnOnce(CreateCommand) -> CreateCommand {
+        let cmd = self.resolve_named_checks(f(CreateCommand::default()).finish());
+
         {
             let ungrouped = self.groups
                 .entry("Ungrouped".to_string())
                 .or_insert_with(|| Arc::new(CommandGroup::default()));
 
             if let Some(ref mut group) = Arc::get_mut(ungrouped) {
-                let cmd = f(CreateCommand::default()).finish();
                 let name = command_name.to_string();
 
                 if let Some(ref prefixes) = group.prefixes {
@@ -804,7 +1126,17 @@ impl StandardFramework {
     /// ```
     pub fn group<F>(mut self, group_name: &str, f: F) -> Self
         where F: FnOnce(CreateGroup) -> CreateGroup {
-        let group = f(CreateGroup(CommandGroup::default())).0;
+        let mut group = f(CreateGroup(CommandGroup::default())).0;
+
+        let checks = self.named_checks(&group.named_checks);
+        group.checks.extend(checks);
+        group.named_checks.clear();
+
+        for command in group.commands.values_mut().chain(group.default_command.as_mut()) {
+            if let CommandOrAlias::Command(ref mut cmd) = *command {
+                *cmd = self.resolve_named_checks(Arc::clone(cmd));
+            }
+        }
 
         self.groups.insert(group_name.into(), Arc::new(group));
         self.initialized = true;
@@ -992,6 +1324,40 @@ impl StandardFramework {
         self
     }
 
+    /// Specify the function to be called if a message consists of only a
+    /// prefix or a mention of the bot, with no command after it.
+    ///
+    /// This is distinct from [`message_without_command`], which is called when
+    /// no prefix was used at all.
+    ///
+    /// # Examples
+    ///
+    /// Pointing users to the help command when only the prefix is sent:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// #
+    /// # impl EventHandler for Handler {}
+    /// # let mut client = Client::new("token", Handler).unwrap();
+    /// #
+    /// use serenity::framework::StandardFramework;
+    ///
+    /// client.with_framework(StandardFramework::new()
+    ///     .configure(|c| c.prefix("!"))
+    ///     .prefix_only(|_, msg| {
+    ///         let _ = msg.channel_id.say("Try `!help` for a list of commands.");
+    ///     }));
+    /// ```
+    ///
+    /// [`message_without_command`]: #method.message_without_command
+    pub fn prefix_only<F>(mut self, f: F) -> Self
+        where F: Fn(&mut Context, &Message) + Send + Sync + 'static {
+        self.prefix_only = Some(Arc::new(f));
+
+        self
+    }
+
     /// Sets what code should be executed when a user sends `(prefix)help`.
     ///
     /// If a command named `help` was set with [`command`], then this takes precedence first.
@@ -1035,46 +1401,44 @@ impl Framework for StandardFramework {
         message: Message,
         threadpool: &ThreadPool,
     ) {
+        if self.is_ignored_author(&message) {
+            return;
+        }
+
         let res = command::positions(&mut context, &message, &self.configuration);
         let mut unrecognised_command_name = String::from("");
 
         let positions = match res {
             Some(mut positions) => {
-                // First, take out the prefixes that are as long as _or_ longer
-                // than the message, to avoid character boundary violations.
-                positions.retain(|p| *p < message.content.len());
+                // First, take out the prefixes that are not followed by any
+                // content, i.e. where the message is only the prefix or
+                // mention.
+                positions.retain(|p| {
+                    !skip_chars_and_trim_to_new_string(&message.content, *p).is_empty()
+                });
 
                 // Ensure that there is _at least one_ position remaining. There
-                // is no point in continuing if there is not.
+                // is no point in continuing if there is not, as the message
+                // consists of only a prefix.
                 if positions.is_empty() {
+                    if let Some(ref prefix_only) = self.prefix_only {
+                        if !(self.configuration.ignore_bots && message.author.bot) {
+                            let prefix_only = Arc::clone(prefix_only);
+                            let mut context = context.clone();
+                            let message = message.clone();
+
+                            threadpool.execute(move || {
+                                (prefix_only)(&mut context, &message);
+                            });
+                        }
+                    }
 
                     if let Some(ref prefix_only_cmd) =
                         self.configuration.prefix_only_cmd {
-                        let prefix_only_cmd = Arc::clone(prefix_only_cmd);
-                        let before = self.before.clone();
-                        let after = self.after.clone();
-
-                        threadpool.execute(move || {
-                            if let Some(before) = before {
-                                if !(before)(&mut context, &message, "") {
-                                    return;
-                                }
-                            }
+                        let command = Arc::clone(prefix_only_cmd);
+                        let args = Args::new("", &Vec::new());
 
-                            if !prefix_only_cmd.before(&mut context, &message) {
-                                return;
-                            }
-
-                            let result = prefix_only_cmd.execute(&mut context,
-                                &message, Args::new("", &Vec::new()));
-
-                            prefix_only_cmd.after(&mut context, &message,
-                                &result);
-
-                            if let Some(after) = after {
-                                (after)(&mut context, &message, "", result);
-                            }
-                        });
+                        threadpool.execute(self.command_job(context, message, command, args, String::new()));
                     }
 
                     return;
@@ -1097,6 +1461,10 @@ impl Framework for StandardFramework {
             },
         };
 
+        // The first word after a prefix, which is auto-corrected if no command
+        // matches.
+        let mut fuzzy_candidate: Option<(usize, String)> = None;
+
         'outer: for position in positions {
             let mut built = String::new();
 
@@ -1113,6 +1481,10 @@ impl Framework for StandardFramework {
                     None => continue 'outer,
                 });
 
+                if i == 0 && fuzzy_candidate.is_none() {
+                    fuzzy_candidate = Some((position, built.clone()));
+                }
+
                 let groups = self.groups.clone();
 
                 for group in groups.values() {
@@ -1220,25 +1592,7 @@ impl Framework for StandardFramework {
                                 return;
                             }
 
-                            threadpool.execute(move || {
-                                if let Some(before) = before {
-                                    if !(before)(&mut context, &message, &built) {
-                                        return;
-                                    }
-                                }
-
-                                if !command.before(&mut context, &message) {
-                                    return;
-                                }
-
-                                let result = command.execute(&mut context, &message, args);
-
-                                command.after(&mut context, &message, &result);
-
-                                if let Some(after) = after {
-                                    (after)(&mut context, &message, &built, result);
-                                }
-                            });
+                            threadpool.execute(self.command_job(context, message, command, args, built));
 
                             return;
 
@@ -1268,25 +1622,7 @@ impl Framework for StandardFramework {
                                 return;
                             }
 
-                            threadpool.execute(move || {
-                                if let Some(before) = before {
-                                    if !(before)(&mut context, &message, &args.full()) {
-                                        return;
-                                    }
-                                }
-
-                                if !command.before(&mut context, &message) {
-                                    return;
-                                }
-
-                                let result = command.execute(&mut context, &message, args);
-
-                                command.after(&mut context, &message, &result);
-
-                                if let Some(after) = after {
-                                    (after)(&mut context, &message, &built, result);
-                                }
-                            });
+                            threadpool.execute(self.command_job(context, message, command, args, built));
 
                             return;
                         }
@@ -1295,9 +1631,44 @@ impl Framework for StandardFramework {
             }
         }
 
+        if self.configuration.auto_correct {
+            if let Some((position, name)) = fuzzy_candidate {
+                if let Some((corrected, command, group)) = self.fuzzy_command(&name) {
+                    let mut args = command_and_help_args!(&message.content, position, name.len(), &self.configuration.delimiters);
+
+                    if let Some(error) = self.should_fail(
+                        &mut context,
+                        &message,
+                        &command.options(),
+                        &group,
+                        &mut args,
+                        &corrected,
+                        &corrected,
+                    ) {
+                        if let Some(ref handler) = self.dispatch_error_handler {
+                            handler(context, message, error);
+                        }
+                        return;
+                    }
+
+                    threadpool.execute(self.command_job(context, message, command, args, corrected));
+
+                    return;
+                }
+            }
+        }
+
         if !(self.configuration.ignore_bots && message.author.bot) {
 
-            if let &Some(ref unrecognised_command) = &self.unrecognised_command {
+            let now = Utc::now().timestamp();
+
+            let unrecognised_command = if self.take_unrecognised_command(message.author.id, now) {
+                self.unrecognised_command.clone()
+            } else {
+                None
+            };
+
+            if let Some(unrecognised_command) = unrecognised_command {
 
                 // If both functions are set, we need to clone `Context` and
                 // `Message`, else we can avoid it.
@@ -1305,7 +1676,6 @@ impl Framework for StandardFramework {
                     let mut context_unrecognised = context.clone();
                     let message_unrecognised = message.clone();
 
-                    let unrecognised_command = unrecognised_command.clone();
                     threadpool.execute(move || {
                         (unrecognised_command)(&mut context_unrecognised, &message_unrecognised,
                         &unrecognised_command_name);
@@ -1316,7 +1686,6 @@ impl Framework for StandardFramework {
                             (message_without_command)(&mut context, &message);
                     });
                 } else {
-                    let unrecognised_command = unrecognised_command.clone();
                     threadpool.execute(move || {
                         (unrecognised_command)(&mut context, &message, &unrecognised_command_name);
                     });
@@ -1332,6 +1701,52 @@ impl Framework for StandardFramework {
 
     fn update_current_user(&mut self, user_id: UserId) {
         self.user_id = user_id.0;
+
+        if let Some(ref mut mentions) = self.configuration.on_mention {
+            *mentions = configuration::mentions_of(user_id);
+        }
+    }
+}
+
+/// Runs a command, optionally catching a panic raised while it executes.
+///
+/// Returns `None` if the command panicked and `catch_panics` is enabled. The
+/// panic is logged with its message, if it carries one.
+fn run_command<F>(catch_panics: bool, f: F) -> Option<Result<(), CommandError>>
+    where F: FnOnce() -> Result<(), CommandError> {
+    if !catch_panics {
+        return Some(f());
+    }
+
+    // Commands are arbitrary boxed closures without an `UnwindSafe` bound. No
+    // state touched by a panicking command is observed afterwards, as its
+    // context and message are only handed on to the dispatch error handler.
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => Some(result),
+        Err(payload) => {
+            let reason = payload.downcast_ref::<&str>()
+                .map(|reason| (*reason).to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "<unknown>".to_string());
+
+            error!("Command panicked: {}", reason);
+
+            None
+        },
+    }
+}
+
+/// Deletes the message that invoked a command, if it was sent in a guild.
+///
+/// Failures, such as the message having already been deleted or the current
+/// user lacking the Manage Messages permission, are ignored.
+fn delete_invoking_message(message: &Message) {
+    if message.guild_id.is_none() {
+        return;
+    }
+
+    if let Err(why) = message.delete() {
+        debug!("Failed to delete command message {}: {:?}", message.id, why);
     }
 }
 
@@ -1384,3 +1799,340 @@ impl fmt::Display for HelpBehaviour {
        fmt::Debug::fmt(self, f)
     }
 }
+
+/// Describes how the help-command shall respond once it is asked for a command
+/// or group that does not exist, as in `{prefix}help {command_name}`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NotFoundBehaviour {
+    /// Responds with suggestions for similar commands if there are any,
+    /// otherwise with the `no_help_available_text`.
+    Text,
+    /// Responds with suggestions for similar commands if there are any,
+    /// otherwise does not respond at all.
+    Ignore,
+}
+
+impl fmt::Display for NotFoundBehaviour {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+       fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A command whose named checks have been resolved, with the options holding
+/// them in place of the command's own.
+struct NamedChecks(Arc<dyn Command>, Arc<CommandOptions>);
+
+impl Command for NamedChecks {
+    fn execute(&self, c: &mut Context, m: &Message, a: Args) -> Result<(), CommandError> {
+        self.0.execute(c, m, a)
+    }
+
+    fn options(&self) -> Arc<CommandOptions> { Arc::clone(&self.1) }
+
+    fn init(&self) { self.0.init() }
+
+    fn before(&self, c: &mut Context, m: &Message) -> bool { self.0.before(c, m) }
+
+    fn after(&self, c: &mut Context, m: &Message, res: &Result<(), CommandError>) {
+        self.0.after(c, m, res)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::DateTime;
+    use crate::client::Context;
+    use crate::model::channel::{Message, MessageType};
+    use crate::model::id::{ChannelId, MessageId, UserId, WebhookId};
+    use crate::model::user::User;
+    use parking_lot::Mutex;
+    use serde_json::Value;
+    use std::{
+        collections::HashSet,
+        sync::{atomic::{AtomicUsize, Ordering}, mpsc, Arc},
+    };
+    use threadpool::ThreadPool;
+    use typemap::ShareMap;
+    use super::super::Framework;
+    use super::{
+        run_command,
+        Args,
+        Check,
+        CommandError,
+        CommandOrAlias,
+        DispatchError,
+        StandardFramework,
+        UNRECOGNISED_COMMAND_TIMES_LIMIT,
+    };
+
+    fn noop(_: &mut Context, _: &Message, _: Args) -> Result<(), CommandError> { Ok(()) }
+
+    fn context() -> Context {
+        Context::new(Arc::new(Mutex::new(ShareMap::custom())), mpsc::channel().0, [0, 1])
+    }
+
+    /// Creates a direct message from the user.
+    fn message(author_id: u64, content: &str) -> Message {
+        Message {
+            id: MessageId(1),
+            attachments: vec![],
+            author: User {
+                id: UserId(author_id),
+                avatar: None,
+                bot: false,
+                discriminator: 1,
+                name: "user".to_string(),
+            },
+            channel_id: ChannelId(2),
+            guild_id: None,
+            content: content.to_string(),
+            edited_timestamp: None,
+            embeds: vec![],
+            kind: MessageType::Regular,
+            member: None,
+            mention_everyone: false,
+            mention_roles: vec![],
+            mentions: vec![],
+            nonce: Value::Null,
+            pinned: false,
+            reactions: vec![],
+            timestamp: DateTime::parse_from_rfc3339("2016-11-22T12:34:56+00:00").unwrap(),
+            tts: false,
+            webhook_id: None,
+        }
+    }
+
+    fn command_checks(framework: &StandardFramework, group: &str, name: &str) -> Vec<Check> {
+        match framework.groups[group].commands[name] {
+            CommandOrAlias::Command(ref cmd) => {
+                let options = cmd.options();
+                assert!(options.named_checks.is_empty());
+
+                options.checks.clone()
+            },
+            CommandOrAlias::Alias(_) => panic!("`{}` is an alias", name),
+        }
+    }
+
+    #[test]
+    fn test_named_check_shared() {
+        let framework = StandardFramework::new()
+            .named_check("admin", |_, _, _, _| true)
+            .command("ban", |c| c.exec(noop).check_named("admin"))
+            .group("moderation", |g| g
+                .check_named("admin")
+                .command("kick", |c| c.exec(noop).check_named("admin")));
+
+        let ban = command_checks(&framework, "Ungrouped", "ban");
+        let kick = command_checks(&framework, "moderation", "kick");
+        let group = &framework.groups["moderation"];
+
+        assert_eq!(ban.len(), 1);
+        assert_eq!(kick.len(), 1);
+        assert_eq!(group.checks.len(), 1);
+        assert!(group.named_checks.is_empty());
+        assert!(Arc::ptr_eq(&ban[0].0, &kick[0].0));
+        assert!(Arc::ptr_eq(&ban[0].0, &group.checks[0].0));
+    }
+
+    #[test]
+    #[should_panic(expected = "No check has been registered under the name `admin`")]
+    fn test_named_check_unknown() {
+        let _ = StandardFramework::new()
+            .named_check("owner", |_, _, _, _| true)
+            .command("ban", |c| c.exec(noop).check_named("admin"));
+    }
+
+    #[test]
+    #[should_panic(expected = "No check has been registered under the name `admin`")]
+    fn test_named_check_registered_after_command() {
+        let _ = StandardFramework::new()
+            .group("moderation", |g| g
+                .command("kick", |c| c.exec(noop).check_named("admin")))
+            .named_check("admin", |_, _, _, _| true);
+    }
+
+    #[test]
+    fn test_deletes_message() {
+        let command = |framework: &StandardFramework, name: &str| {
+            match framework.groups["Ungrouped"].commands[name] {
+                CommandOrAlias::Command(ref cmd) => Arc::clone(cmd),
+                CommandOrAlias::Alias(_) => panic!("`{}` is an alias", name),
+            }
+        };
+
+        let framework = StandardFramework::new()
+            .command("ping", |c| c.exec(noop))
+            .command("keep", |c| c.exec(noop).delete_message(false))
+            .command("clear", |c| c.exec(noop).delete_message(true));
+        assert!(!framework.deletes_message(&command(&framework, "ping")));
+        assert!(!framework.deletes_message(&command(&framework, "keep")));
+        assert!(framework.deletes_message(&command(&framework, "clear")));
+
+        let framework = StandardFramework::new()
+            .configure(|c| c.delete_message_on_command(true))
+            .command("ping", |c| c.exec(noop))
+            .command("keep", |c| c.exec(noop).delete_message(false));
+        assert!(framework.deletes_message(&command(&framework, "ping")));
+        assert!(!framework.deletes_message(&command(&framework, "keep")));
+    }
+
+    #[test]
+    fn test_owner_bypass_checks() {
+        let should_fail = |bypass_checks: bool, author_id: u64| {
+            let mut framework = StandardFramework::new()
+                .configure(|c| c.owners(vec![UserId(1)].into_iter().collect::<HashSet<_>>()))
+                .command("ping", |c| c
+                    .exec(noop)
+                    .check(|_, _, _, _| false)
+                    .owner_bypass_checks(bypass_checks));
+            let group = Arc::clone(&framework.groups["Ungrouped"]);
+            let options = match group.commands["ping"] {
+                CommandOrAlias::Command(ref cmd) => cmd.options(),
+                CommandOrAlias::Alias(_) => unreachable!(),
+            };
+
+            framework.should_fail(
+                &mut context(),
+                &message(author_id, "~ping"),
+                &options,
+                &group,
+                &mut Args::new("", &[" ".to_string()]),
+                "ping",
+                "ping",
+            )
+        };
+
+        // Owners bypass the failing check.
+        assert!(should_fail(true, 1).is_none());
+        // Anyone else is subject to it.
+        assert!(match should_fail(true, 2) {
+            Some(DispatchError::CheckFailed) => true,
+            _ => false,
+        });
+        // Owners are subject to it when not bypassing checks.
+        assert!(match should_fail(false, 1) {
+            Some(DispatchError::CheckFailed) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_ignored_authors() {
+        let handled = Arc::new(AtomicUsize::new(0));
+        let (before, prefix_only, unrecognised, without_command, dispatch_error) = (
+            handled.clone(),
+            handled.clone(),
+            handled.clone(),
+            handled.clone(),
+            handled.clone(),
+        );
+        let mut framework = StandardFramework::new()
+            .configure(|c| c
+                .prefix("~")
+                .blocked_users(vec![UserId(2)].into_iter().collect::<HashSet<_>>()))
+            .before(move |_, _, _| {
+                before.fetch_add(1, Ordering::SeqCst);
+
+                true
+            })
+            .prefix_only(move |_, _| { prefix_only.fetch_add(1, Ordering::SeqCst); })
+            .unrecognised_command(move |_, _, _| { unrecognised.fetch_add(1, Ordering::SeqCst); })
+            .message_without_command(move |_, _| { without_command.fetch_add(1, Ordering::SeqCst); })
+            .on_dispatch_error(move |_, _, _| { dispatch_error.fetch_add(1, Ordering::SeqCst); })
+            .command("ping", |c| c.exec(noop));
+        let threadpool = ThreadPool::new(1);
+
+        let mut webhook = message(3, "");
+        webhook.webhook_id = Some(WebhookId(4));
+
+        for content in &["~ping", "~", "~unknown", "hello"] {
+            framework.dispatch(context(), message(2, content), &threadpool);
+
+            let mut webhook = webhook.clone();
+            webhook.content = content.to_string();
+            framework.dispatch(context(), webhook, &threadpool);
+        }
+
+        threadpool.join();
+        assert_eq!(handled.load(Ordering::SeqCst), 0);
+
+        // Anyone else is handled.
+        framework.dispatch(context(), message(1, "~ping"), &threadpool);
+        threadpool.join();
+        assert_eq!(handled.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_fuzzy_command() {
+        let framework = StandardFramework::new()
+            .configure(|c| c.auto_correct(true))
+            .command("ban", |c| c.exec(noop).known_as("bam"))
+            .command("kick", |c| c.exec(noop))
+            .command("purge", |c| c.exec(noop).no_fuzzy(true));
+
+        // A near-miss of both a command and its alias.
+        assert_eq!(framework.fuzzy_command("bah").map(|(name, _, _)| name), Some("ban".to_string()));
+        assert_eq!(framework.fuzzy_command("kik").map(|(name, _, _)| name), Some("kick".to_string()));
+        // Swapping two adjacent characters is a single edit.
+        assert_eq!(framework.fuzzy_command("bna").map(|(name, _, _)| name), Some("ban".to_string()));
+        // Too far from any command.
+        assert!(framework.fuzzy_command("bnaa").is_none());
+        // Opted out of being auto-corrected.
+        assert!(framework.fuzzy_command("purg").is_none());
+
+        let framework = StandardFramework::new()
+            .command("ban", |c| c.exec(noop))
+            .command("bin", |c| c.exec(noop));
+
+        // Ambiguous between two commands.
+        assert!(framework.fuzzy_command("bon").is_none());
+    }
+
+    #[test]
+    fn test_run_command_catches_panics() {
+        assert!(run_command(true, || Ok(())).unwrap().is_ok());
+        assert!(run_command(true, || panic!("command failed")).is_none());
+    }
+
+    #[test]
+    fn test_unrecognised_command_delay() {
+        let mut framework = StandardFramework::new()
+            .unrecognised_command(|_, _, _| {});
+        assert!(framework.take_unrecognised_command(UserId(1), 100));
+        assert!(framework.take_unrecognised_command(UserId(1), 100));
+
+        let mut framework = StandardFramework::new()
+            .configure(|c| c.unrecognised_command_delay(10))
+            .unrecognised_command(|_, _, _| {});
+        assert!(framework.take_unrecognised_command(UserId(1), 100));
+        assert!(!framework.take_unrecognised_command(UserId(1), 105));
+        assert!(framework.take_unrecognised_command(UserId(2), 105));
+        assert!(framework.take_unrecognised_command(UserId(1), 110));
+    }
+
+    #[test]
+    fn test_unrecognised_command_delay_without_hook() {
+        let mut framework = StandardFramework::new()
+            .configure(|c| c.unrecognised_command_delay(10));
+        assert!(!framework.take_unrecognised_command(UserId(1), 100));
+        assert!(framework.unrecognised_command_times.is_empty());
+    }
+
+    #[test]
+    fn test_unrecognised_command_times_evicted() {
+        let mut framework = StandardFramework::new()
+            .configure(|c| c.unrecognised_command_delay(10))
+            .unrecognised_command(|_, _, _| {});
+
+        for id in 0..UNRECOGNISED_COMMAND_TIMES_LIMIT as u64 {
+            assert!(framework.take_unrecognised_command(UserId(id), 100));
+        }
+
+        // Only the users still within their delay are kept.
+        assert!(framework.take_unrecognised_command(UserId(0), 110));
+        assert!(framework.take_unrecognised_command(UserId(1), 115));
+        assert_eq!(framework.unrecognised_command_times.len(), 2);
+        assert!(!framework.take_unrecognised_command(UserId(0), 115));
+    }
+}
@@ -18,6 +18,9 @@ pub enum Error {
     BuildingUrl,
     /// The connection closed, potentially uncleanly.
     Closed(Option<CloseData>),
+    /// The connection to the gateway could not be established within the
+    /// connect timeout.
+    ConnectTimeout,
     /// Expected a Hello during a handshake
     ExpectedHello,
     /// When there was an error sending a heartbeat.
@@ -50,6 +53,16 @@ pub enum Error {
     OverloadedShard,
     /// Failed to reconnect after a number of attempts.
     ReconnectFailure,
+    /// When the gateway closed the connection because the session start
+    /// limit, as given by the [`SessionStartLimit`], was reached.
+    ///
+    /// Identifying past this limit results in the token being reset, so the
+    /// shard is not reconnected. The client's shard runners instead have the
+    /// [`ShardQueuer`] start the shard again once the limit has reset.
+    ///
+    /// [`SessionStartLimit`]: ../model/gateway/struct.SessionStartLimit.html
+    /// [`ShardQueuer`]: ../client/bridge/gateway/struct.ShardQueuer.html
+    SessionStartLimitReached,
 }
 
 impl Display for Error {
@@ -63,6 +76,7 @@ impl StdError for Error {
         match *self {
             BuildingUrl => "Error building url",
             Closed(_) => "Connection closed",
+            ConnectTimeout => "Timed out connecting to the gateway",
             ExpectedHello => "Expected a Hello",
             HeartbeatFailed => "Failed sending a heartbeat",
             InvalidAuthentication => "Sent invalid authentication",
@@ -73,6 +87,7 @@ impl StdError for Error {
             NoSessionId => "No Session Id present when required",
             OverloadedShard => "Shard has too many guilds",
             ReconnectFailure => "Failed to Reconnect",
+            SessionStartLimitReached => "Session start limit reached",
         }
     }
 }
@@ -52,19 +52,23 @@ mod ws_client_ext;
 
 pub use self::{
     error::Error as GatewayError,
-    shard::Shard,
+    shard::{set_connect_timeout, Shard},
     ws_client_ext::WebSocketGatewayClientExt
 };
 
 use crate::model::{
+    event::GatewayEvent,
     gateway::Game,
     user::OnlineStatus
 };
 use serde_json::Value;
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use websocket::sync::{
-    client::Client,
-    stream::{TcpStream, TlsStream}
+use websocket::{
+    message::CloseData,
+    sync::{
+        client::Client,
+        stream::{TcpStream, TlsStream}
+    }
 };
 
 #[cfg(feature = "client")]
@@ -189,9 +193,30 @@ pub enum ShardAction {
 }
 
 /// The type of reconnection that should be performed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ReconnectType {
     /// Indicator that a new connection should be made by sending an IDENTIFY.
     Reidentify,
     /// Indicator that a new connection should be made by sending a RESUME.
     Resume,
 }
+
+/// The outcome of a single [`Shard::receive`] call.
+///
+/// [`Shard::receive`]: struct.Shard.html#method.receive
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum ShardReceive {
+    /// A payload was received from the gateway, and has already been handled
+    /// by the shard.
+    Event(GatewayEvent),
+    /// No payload was received before the read timeout elapsed.
+    ///
+    /// The shard is still connected, and `receive` can be called again.
+    Timeout,
+    /// The connection to the gateway was closed, along with the close data if
+    /// the gateway sent any.
+    ///
+    /// The shard needs to be reconnected before receiving again.
+    Closed(Option<CloseData>),
+}
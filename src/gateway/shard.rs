@@ -1,14 +1,24 @@
 use crate::constants::{self, close_codes};
 use crate::internal::prelude::*;
+use crate::internal::ws_impl::ReceiverExt;
 use crate::model::{
     event::{Event, GatewayEvent},
     gateway::Game,
     id::GuildId,
     user::OnlineStatus
 };
+use hyper::Error as HyperError;
+use native_tls::{HandshakeError, TlsConnector};
 use parking_lot::Mutex;
+use serde::Deserialize;
 use std::{
-    sync::Arc,
+    io::{Error as IoError, ErrorKind as IoErrorKind},
+    net::{TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
     time::{Duration as StdDuration, Instant}
 };
 use super::{
@@ -17,16 +27,52 @@ use super::{
     ShardAction,
     GatewayError,
     ReconnectType,
+    ShardReceive,
     WsClient,
     WebSocketGatewayClientExt,
 };
 use websocket::{
     client::Url,
+    message::CloseData,
     stream::sync::AsTcpStream,
     sync::client::ClientBuilder,
     WebSocketError
 };
 
+#[cfg(feature = "cache")]
+use crate::CACHE;
+
+/// The number of milliseconds to wait for a connection to the gateway to be
+/// established. Refer to [`set_connect_timeout`].
+///
+/// [`set_connect_timeout`]: fn.set_connect_timeout.html
+static CONNECT_TIMEOUT: AtomicUsize = AtomicUsize::new(30_000);
+
+/// The maximum number of seconds to wait before a RESUME attempt after
+/// consecutive failed attempts.
+const MAX_RESUME_BACKOFF_IN_SECONDS: u64 = 32;
+
+/// Sets the maximum amount of time to wait for a connection to the gateway to
+/// be established, including the TLS and WebSocket handshakes.
+///
+/// This applies to both starting and reconnecting a [`Shard`]. If the timeout
+/// is reached, then a [`GatewayError::ConnectTimeout`] is returned so that the
+/// connection can be retried.
+///
+/// The timeout covers opening the TCP connection followed by the TLS and
+/// WebSocket handshakes, each of which is given the time remaining. Resolving
+/// the gateway's hostname is left to the system's resolver.
+///
+/// **Note**: Defaults to 30 seconds.
+///
+/// [`GatewayError::ConnectTimeout`]: enum.GatewayError.html#variant.ConnectTimeout
+/// [`Shard`]: struct.Shard.html
+pub fn set_connect_timeout(timeout: StdDuration) {
+    let millis = timeout.as_secs() as usize * 1000 + timeout.subsec_millis() as usize;
+
+    CONNECT_TIMEOUT.store(millis, Ordering::Relaxed);
+}
+
 /// A Shard is a higher-level handler for a websocket connection to Discord's
 /// gateway. The shard allows for sending and receiving messages over the
 /// websocket, such as setting the active game, reconnecting, syncing guilds,
@@ -47,7 +93,7 @@ use websocket::{
 ///
 /// This can be done by passing in the required parameters to [`new`]. You can
 /// then manually handle the shard yourself and receive events via
-/// [`receive`].
+/// [`receive`], which returns after a short timeout if nothing was received.
 ///
 /// **Note**: You _really_ do not need to do this. Just call one of the
 /// appropriate methods on the [`Client`].
@@ -63,6 +109,9 @@ use websocket::{
 /// [module docs]: index.html#sharding
 pub struct Shard {
     pub client: WsClient,
+    /// Whether the presence is marked as AFK, and the time in milliseconds
+    /// since the Unix epoch since which the user has been idle, if known.
+    afk: (bool, Option<u64>),
     current_presence: CurrentPresence,
     /// A tuple of:
     ///
@@ -79,8 +128,7 @@ pub struct Shard {
     // This _must_ be set to `true` in `Shard::handle_event`'s
     // `Ok(GatewayEvent::HeartbeatAck)` arm.
     last_heartbeat_acknowledged: bool,
-    seq: u64,
-    session_id: Option<String>,
+    session: Session,
     shard_info: [u64; 2],
     /// Whether the shard has permanently shutdown.
     shutdown: bool,
@@ -122,8 +170,8 @@ impl Shard {
     /// let gateway = Arc::new(Mutex::new(http::get_gateway()?.url));
     /// let shard = Shard::new(gateway, token, [0, 1])?;
     ///
-    /// // at this point, you can create a `loop`, and receive events and match
-    /// // their variants
+    /// // at this point, you can create a `loop`, and receive events via
+    /// // `shard.receive()` and match their variants
     /// #     Ok(())
     /// # }
     /// #
@@ -144,27 +192,89 @@ impl Shard {
         let heartbeat_instants = (None, None);
         let heartbeat_interval = None;
         let last_heartbeat_acknowledged = true;
-        let seq = 0;
         let stage = ConnectionStage::Handshake;
-        let session_id = None;
 
         Ok(Shard {
             shutdown: false,
+            afk: (false, None),
             client,
             current_presence,
             heartbeat_instants,
             heartbeat_interval,
             last_heartbeat_acknowledged,
-            seq,
+            session: Session::default(),
             stage,
             started: Instant::now(),
             token,
-            session_id,
             shard_info,
             ws_url,
         })
     }
 
+    /// Instantiates a new Shard which resumes a session from a previous
+    /// process, rather than identifying anew.
+    ///
+    /// The `session_id` and `seq` are those retrieved from [`session_info`]
+    /// before the previous process shut down. As resuming does not count
+    /// towards the identify ratelimit, this allows restarting a bot without
+    /// each of its shards re-identifying.
+    ///
+    /// If Discord no longer accepts the session, it invalidates it, and the
+    /// shard falls back to reconnecting with an IDENTIFY.
+    ///
+    /// # Examples
+    ///
+    /// Restore a shard from the session info of a previous process:
+    ///
+    /// ```rust,no_run
+    /// extern crate parking_lot;
+    /// extern crate serenity;
+    /// #
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// #
+    /// use parking_lot::Mutex;
+    /// use serenity::gateway::Shard;
+    /// use serenity::http;
+    /// use std::env;
+    /// use std::sync::Arc;
+    ///
+    /// let token = Arc::new(Mutex::new(env::var("DISCORD_BOT_TOKEN")?));
+    /// let gateway = Arc::new(Mutex::new(http::get_gateway()?.url));
+    ///
+    /// // the session id and sequence saved before the previous shutdown
+    /// let session_id = env::var("SESSION_ID")?;
+    /// let seq = env::var("SEQ")?.parse()?;
+    ///
+    /// let shard = Shard::restore(gateway, token, [0, 1], session_id, seq)?;
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`session_info`]: #method.session_info
+    pub fn restore(
+        ws_url: Arc<Mutex<String>>,
+        token: Arc<Mutex<String>>,
+        shard_info: [u64; 2],
+        session_id: String,
+        seq: u64,
+    ) -> Result<Shard> {
+        let mut shard = Shard::new(ws_url, token, shard_info)?;
+
+        shard.client.send_resume(&shard_info, &session_id, &seq, &shard.token.lock())?;
+
+        shard.session.id = Some(session_id);
+        shard.session.seq = seq;
+        shard.stage = ConnectionStage::Resuming;
+
+        Ok(shard)
+    }
+
     /// Retrieves the current presence of the shard.
     #[inline]
     pub fn current_presence(&self) -> &CurrentPresence {
@@ -215,7 +325,7 @@ impl Shard {
     ///
     /// [`GatewayError::HeartbeatFailed`]: enum.GatewayError.html#variant.HeartbeatFailed
     pub fn heartbeat(&mut self) -> Result<()> {
-        match self.client.send_heartbeat(&self.shard_info, Some(self.seq)) {
+        match self.client.send_heartbeat(&self.shard_info, Some(self.session.seq)) {
             Ok(()) => {
                 self.heartbeat_instants.0 = Some(Instant::now());
                 self.last_heartbeat_acknowledged = false;
@@ -253,12 +363,29 @@ impl Shard {
 
     #[inline]
     pub fn seq(&self) -> u64 {
-        self.seq
+        self.session.seq
     }
 
     #[inline]
     pub fn session_id(&self) -> Option<&String> {
-        self.session_id.as_ref()
+        self.session.id.as_ref()
+    }
+
+    /// Retrieves the session Id and the last sequence number received, if the
+    /// shard has a session.
+    ///
+    /// These can be saved before shutting down, to resume the session in a new
+    /// process via [`restore`].
+    ///
+    /// [`restore`]: #method.restore
+    pub fn session_info(&self) -> Option<(String, u64)> {
+        self.session.id.as_ref().map(|id| (id.clone(), self.session.seq))
+    }
+
+    /// Sets whether the presence is marked as AFK.
+    #[inline]
+    pub fn set_afk(&mut self, afk: bool) {
+        self.afk.0 = afk;
     }
 
     /// ```rust,no_run
@@ -285,10 +412,44 @@ impl Shard {
         self.current_presence.0 = game;
     }
 
+    /// Sets the game and online status of the presence.
+    ///
+    /// This resets the AFK state set via [`set_presence_full`].
+    ///
+    /// [`set_presence_full`]: #method.set_presence_full
     #[inline]
     pub fn set_presence(&mut self, status: OnlineStatus, game: Option<Game>) {
+        self.set_presence_full(status, game, false, None);
+    }
+
+    /// Sets the full presence, including whether the user is AFK and the time
+    /// in milliseconds since the Unix epoch since which the user has been idle.
+    ///
+    /// If `since` is `None` and the status is [`Idle`], the time the presence
+    /// is sent is used.
+    ///
+    /// **Note**: As with the other setters, this does not send the presence;
+    /// call [`update_presence`] to do so.
+    ///
+    /// [`Idle`]: ../model/user/enum.OnlineStatus.html#variant.Idle
+    /// [`update_presence`]: #method.update_presence
+    pub fn set_presence_full(
+        &mut self,
+        status: OnlineStatus,
+        game: Option<Game>,
+        afk: bool,
+        since: Option<u64>,
+    ) {
         self.set_game(game);
         self.set_status(status);
+        self.afk = (afk, since);
+    }
+
+    /// Sets the time in milliseconds since the Unix epoch since which the user
+    /// has been idle.
+    #[inline]
+    pub fn set_since(&mut self, since: Option<u64>) {
+        self.afk.1 = since;
     }
 
     #[inline]
@@ -332,11 +493,129 @@ impl Shard {
     /// ```
     pub fn shard_info(&self) -> [u64; 2] { self.shard_info }
 
+    /// Returns the number of guilds in the cache that belong to the shard.
+    ///
+    /// Discord closes the connection with [`GatewayError::OverloadedShard`]
+    /// once a shard holds too many guilds, so this can be used to reshard
+    /// ahead of time.
+    ///
+    /// [`GatewayError::OverloadedShard`]: enum.GatewayError.html#variant.OverloadedShard
+    #[cfg(feature = "cache")]
+    pub fn guild_count(&self) -> usize {
+        CACHE.read().guild_count_for_shard(self.shard_info[0])
+    }
+
     /// Returns the current connection stage of the shard.
     pub fn stage(&self) -> ConnectionStage {
         self.stage
     }
 
+    /// Receives the next payload from the gateway, waiting at most for the
+    /// shard's read timeout of 100 milliseconds.
+    ///
+    /// Received payloads are handled by the shard before being returned: the
+    /// connection state and sequence are updated, and any needed heartbeat,
+    /// IDENTIFY, or RESUME is sent. This allows a custom event loop to perform
+    /// other work between reads, without blocking until the next payload.
+    ///
+    /// When [`ShardReceive::Closed`] is returned, the shard should be
+    /// reconnected via [`resume_or_identify`] before receiving again.
+    ///
+    /// **Note**: Heartbeats are only sent in response to the gateway, so
+    /// [`check_heartbeat`] should be called periodically from the loop.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `GatewayError::InvalidAuthentication`,
+    /// `GatewayError::InvalidShardData`, `GatewayError::NoAuthentication`, or
+    /// `GatewayError::OverloadedShard` under the same conditions as the
+    /// client's shard runners would fail the shard.
+    ///
+    /// Returns a `GatewayError::SessionStartLimitReached` if the gateway
+    /// closed the connection because the session start limit was reached, in
+    /// which case the shard should not be reconnected until the limit resets.
+    ///
+    /// Returns an [`Error::Json`] if a payload could not be deserialized.
+    ///
+    /// # Examples
+    ///
+    /// Receive events, performing maintenance between reads:
+    ///
+    /// ```rust,no_run
+    /// # extern crate parking_lot;
+    /// # extern crate serenity;
+    /// #
+    /// # use parking_lot::Mutex;
+    /// # use serenity::gateway::Shard;
+    /// # use std::error::Error;
+    /// # use std::sync::Arc;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// #     let mutex = Arc::new(Mutex::new("".to_string()));
+    /// #
+    /// #     let mut shard = Shard::new(mutex.clone(), mutex, [0, 1])?;
+    /// #
+    /// use serenity::gateway::ShardReceive;
+    ///
+    /// loop {
+    ///     match shard.receive()? {
+    ///         ShardReceive::Event(event) => println!("Received {:?}", event),
+    ///         ShardReceive::Timeout => {},
+    ///         ShardReceive::Closed(_) => {
+    ///             shard.resume_or_identify()?;
+    ///         },
+    ///     }
+    ///
+    ///     if !shard.check_heartbeat() {
+    ///         shard.resume_or_identify()?;
+    ///     }
+    /// }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`Error::Json`]: ../enum.Error.html#variant.Json
+    /// [`ShardReceive::Closed`]: enum.ShardReceive.html#variant.Closed
+    /// [`check_heartbeat`]: #method.check_heartbeat
+    /// [`resume_or_identify`]: #method.resume_or_identify
+    pub fn receive(&mut self) -> Result<ShardReceive> {
+        let event = match self.client.recv_json() {
+            Ok(Some(value)) => GatewayEvent::deserialize(value).map_err(Error::from)?,
+            Ok(None) => return Ok(ShardReceive::Timeout),
+            Err(Error::WebSocket(WebSocketError::IoError(ref why))) if is_read_timeout(why) => {
+                return Ok(ShardReceive::Timeout);
+            },
+            // Any other I/O error, such as a reset connection, means that the
+            // connection is gone.
+            Err(Error::WebSocket(WebSocketError::IoError(_))) |
+            Err(Error::WebSocket(WebSocketError::NoDataAvailable)) => {
+                return Ok(ShardReceive::Closed(None));
+            },
+            Err(Error::Gateway(GatewayError::Closed(data))) => {
+                // Let the shard update its session from the close code; the
+                // reconnect is left to the caller.
+                let _ = self.handle_event(&Err(Error::Gateway(GatewayError::Closed(data.clone()))))?;
+
+                return Ok(ShardReceive::Closed(data));
+            },
+            Err(why) => return Err(why),
+        };
+
+        let event = Ok(event);
+
+        match self.handle_event(&event)? {
+            Some(ShardAction::Heartbeat) => self.heartbeat()?,
+            Some(ShardAction::Identify) => self.identify()?,
+            Some(ShardAction::Reconnect(reconnect_type)) => self.reconnect_with(reconnect_type)?,
+            None => {},
+        }
+
+        event.map(ShardReceive::Event)
+    }
+
     /// Handles an event from the gateway over the receiver, requiring the
     /// receiver to be passed if a reconnect needs to occur.
     ///
@@ -366,16 +645,22 @@ impl Shard {
         -> Result<Option<ShardAction>> {
         match *event {
             Ok(GatewayEvent::Dispatch(seq, ref event)) => {
-                if seq > self.seq + 1 {
-                    warn!("[Shard {:?}] Sequence off; them: {}, us: {}", self.shard_info, seq, self.seq);
+                if seq > self.session.seq + 1 {
+                    warn!(
+                        "[Shard {:?}] Sequence off; them: {}, us: {}",
+                        self.shard_info,
+                        seq,
+                        self.session.seq,
+                    );
                 }
 
                 match *event {
                     Event::Ready(ref ready) => {
                         debug!("[Shard {:?}] Received Ready", self.shard_info);
 
-                        self.session_id = Some(ready.ready.session_id.clone());
+                        self.session.id = Some(ready.ready.session_id.clone());
                         self.stage = ConnectionStage::Connected;
+                        self.session.resume_attempts = 0;
                     },
                     Event::Resumed(_) => {
                         info!("[Shard {:?}] Resumed", self.shard_info);
@@ -383,11 +668,12 @@ impl Shard {
                         self.stage = ConnectionStage::Connected;
                         self.last_heartbeat_acknowledged = true;
                         self.heartbeat_instants = (Some(Instant::now()), None);
+                        self.session.resume_attempts = 0;
                     },
                     _ => {},
                 }
 
-                self.seq = seq;
+                self.session.seq = seq;
 
                 Ok(None)
             },
@@ -395,12 +681,12 @@ impl Shard {
                 info!("[Shard {:?}] Received shard heartbeat", self.shard_info);
 
                 // Received seq is off -- attempt to resume.
-                if s > self.seq + 1 {
+                if s > self.session.seq + 1 {
                     info!(
                         "[Shard {:?}] Received off sequence (them: {}; us: {}); resuming",
                         self.shard_info,
                         s,
-                        self.seq
+                        self.session.seq
                     );
 
                     if self.stage == ConnectionStage::Handshake {
@@ -432,14 +718,14 @@ impl Shard {
                        self.shard_info,
                        interval);
 
-                if self.stage == ConnectionStage::Resuming {
-                    return Ok(None);
-                }
-
                 if interval > 0 {
                     self.heartbeat_interval = Some(interval);
                 }
 
+                if self.stage == ConnectionStage::Resuming {
+                    return Ok(None);
+                }
+
                 Ok(Some(if self.stage == ConnectionStage::Handshake {
                     ShardAction::Identify
                 } else {
@@ -468,6 +754,15 @@ impl Shard {
                 let num = data.as_ref().map(|d| d.status_code);
                 let clean = num == Some(1000);
 
+                if data.as_ref().map(is_session_start_limit_close) == Some(true) {
+                    error!("[Shard {:?}] Session start limit reached",
+                           self.shard_info);
+
+                    // Identifying again before the limit resets would get the
+                    // token reset, so don't reconnect.
+                    return Err(Error::Gateway(GatewayError::SessionStartLimitReached));
+                }
+
                 match num {
                     Some(close_codes::UNKNOWN_OPCODE) => {
                         warn!("[Shard {:?}] Sent invalid opcode",
@@ -495,9 +790,9 @@ impl Shard {
                     Some(close_codes::INVALID_SEQUENCE) => {
                         warn!("[Shard {:?}] Sent invalid seq: {}",
                               self.shard_info,
-                              self.seq);
+                              self.session.seq);
 
-                        self.seq = 0;
+                        self.session.seq = 0;
                     },
                     Some(close_codes::RATE_LIMITED) => {
                         warn!("[Shard {:?}] Gateway ratelimited",
@@ -518,7 +813,7 @@ impl Shard {
                     Some(4006) | Some(close_codes::SESSION_TIMEOUT) => {
                         info!("[Shard {:?}] Invalid session", self.shard_info);
 
-                        self.session_id = None;
+                        self.session.clear();
                     },
                     Some(other) if !clean => {
                         warn!(
@@ -533,7 +828,7 @@ impl Shard {
 
                 let resume = num.map(|x| {
                     x != close_codes::AUTHENTICATION_FAILED &&
-                    self.session_id.is_some()
+                    self.can_resume()
                 }).unwrap_or(true);
 
                 Ok(Some(if resume {
@@ -650,14 +945,103 @@ impl Shard {
         Some(self.reconnection_type())
     }
 
+    /// Returns whether the shard has a session which a RESUME can be attempted
+    /// with.
+    ///
+    /// If this is `false`, then calling [`resume`] returns a
+    /// [`GatewayError::NoSessionId`], and an IDENTIFY is needed instead.
+    ///
+    /// [`GatewayError::NoSessionId`]: enum.GatewayError.html#variant.NoSessionId
+    /// [`resume`]: #method.resume
+    pub fn can_resume(&self) -> bool {
+        self.session.id.is_some()
+    }
+
     pub fn reconnection_type(&self) -> ReconnectType {
-        if self.session_id().is_some() {
-            ReconnectType::Resume
-        } else {
-            ReconnectType::Reidentify
+        self.session.reconnection_type()
+    }
+
+    /// Performs a reconnect of the given type, bypassing the automatic
+    /// decision made by [`reconnection_type`].
+    ///
+    /// This can be used to force a fresh IDENTIFY when the session is known to
+    /// be stale, or to force a RESUME attempt.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GatewayError::NoSessionId`] when resuming if the shard does
+    /// not have a session.
+    ///
+    /// [`GatewayError::NoSessionId`]: enum.GatewayError.html#variant.NoSessionId
+    /// [`reconnection_type`]: #method.reconnection_type
+    pub fn reconnect_with(&mut self, reconnect_type: ReconnectType) -> Result<()> {
+        match reconnect_type {
+            ReconnectType::Reidentify => self.reconnect(),
+            ReconnectType::Resume => self.resume(),
         }
     }
 
+    /// Reconnects the shard, sending a RESUME if the shard [can resume] and
+    /// otherwise opening a new connection to IDENTIFY with.
+    ///
+    /// This makes the same decision as the client's shard runners, for use
+    /// when driving a shard manually. When an IDENTIFY is needed, it should be
+    /// sent via [`identify`] once the gateway's Hello is received.
+    ///
+    /// If the shard is already connecting, then no action is performed and
+    /// `None` is returned. Otherwise, the type of reconnect performed is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # extern crate parking_lot;
+    /// # extern crate serenity;
+    /// #
+    /// # use parking_lot::Mutex;
+    /// # use serenity::gateway::Shard;
+    /// # use std::error::Error;
+    /// # use std::sync::Arc;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// #     let mutex = Arc::new(Mutex::new("".to_string()));
+    /// #
+    /// #     let mut shard = Shard::new(mutex.clone(), mutex, [0, 1])?;
+    /// #
+    /// // after the connection has been lost:
+    /// match shard.resume_or_identify()? {
+    ///     Some(kind) => println!("Reconnecting via {:?}", kind),
+    ///     None => println!("Already connecting"),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [can resume]: #method.can_resume
+    /// [`identify`]: #method.identify
+    pub fn resume_or_identify(&mut self) -> Result<Option<ReconnectType>> {
+        match self.should_reconnect() {
+            Some(reconnect_type) => {
+                self.reconnect_with(reconnect_type)?;
+
+                Ok(Some(reconnect_type))
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Drops the [`session_id`] of the shard, so that the next reconnect sends
+    /// an IDENTIFY rather than a RESUME.
+    ///
+    /// [`session_id`]: #method.session_id
+    pub fn clear_session(&mut self) {
+        self.session.clear();
+    }
+
     /// Requests that one or multiple [`Guild`]s be chunked.
     ///
     /// This will ask the gateway to start sending member chunks for large
@@ -757,7 +1141,13 @@ impl Shard {
     // - the time that the last heartbeat sent as being now
     // - the `stage` to `Identifying`
     pub fn identify(&mut self) -> Result<()> {
-        self.client.send_identify(&self.shard_info, &self.token.lock())?;
+        self.client.send_identify(
+            &self.shard_info,
+            &self.token.lock(),
+            &self.current_presence,
+            self.afk.0,
+            self.afk.1,
+        )?;
 
         self.heartbeat_instants.0 = Some(Instant::now());
         self.stage = ConnectionStage::Identifying;
@@ -794,23 +1184,43 @@ impl Shard {
         self.heartbeat_instants = (Some(Instant::now()), None);
         self.heartbeat_interval = None;
         self.last_heartbeat_acknowledged = true;
-        self.session_id = None;
+        self.session.reset();
         self.stage = ConnectionStage::Disconnected;
-        self.seq = 0;
     }
 
+    /// Opens a new connection and sends a RESUME for the current session.
+    ///
+    /// After consecutive attempts without the shard having connected in
+    /// between, this waits before reconnecting, doubling the wait with each
+    /// attempt up to 32 seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GatewayError::NoSessionId`] if the shard does not have a
+    /// session to resume. Refer to [`can_resume`].
+    ///
+    /// [`GatewayError::NoSessionId`]: enum.GatewayError.html#variant.NoSessionId
+    /// [`can_resume`]: #method.can_resume
     pub fn resume(&mut self) -> Result<()> {
         debug!("Shard {:?}] Attempting to resume", self.shard_info);
 
+        let backoff = self.session.attempt_resume()?;
+
+        if backoff > 0 {
+            debug!("[Shard {:?}] Waiting {}s before resuming", self.shard_info, backoff);
+
+            thread::sleep(StdDuration::from_secs(backoff));
+        }
+
         self.client = self.initialize()?;
         self.stage = ConnectionStage::Resuming;
 
-        match self.session_id.as_ref() {
+        match self.session.id.as_ref() {
             Some(session_id) => {
                 self.client.send_resume(
                     &self.shard_info,
                     session_id,
-                    &self.seq,
+                    &self.session.seq,
                     &self.token.lock(),
                 )
             },
@@ -827,19 +1237,168 @@ impl Shard {
         Ok(())
     }
 
+    /// Resets the presence to being online with no game, and not AFK, and
+    /// sends it.
+    ///
+    /// Unlike the presence setters, this sends the presence immediately, as
+    /// via [`update_presence`].
+    ///
+    /// [`update_presence`]: #method.update_presence
+    pub fn reset_presence(&mut self) -> Result<()> {
+        self.set_presence_full(OnlineStatus::Online, None, false, None);
+
+        self.update_presence()
+    }
+
     pub fn update_presence(&mut self) -> Result<()> {
-        self.client.send_presence_update(
+        self.client.send_presence_update_full(
             &self.shard_info,
             &self.current_presence,
+            self.afk.0,
+            self.afk.1,
         )
     }
 }
 
+/// The gateway session of a shard, which a RESUME continues.
+#[derive(Debug, Default)]
+struct Session {
+    id: Option<String>,
+    /// The number of RESUMEs attempted since the shard was last connected,
+    /// used to back off between attempts.
+    resume_attempts: u32,
+    seq: u64,
+}
+
+impl Session {
+    /// Drops the session Id, so that the next reconnect sends an IDENTIFY.
+    ///
+    /// The sequence is kept, as it is still needed for heartbeating.
+    fn clear(&mut self) {
+        self.id = None;
+    }
+
+    /// Drops the session Id along with the sequence, for a new connection.
+    fn reset(&mut self) {
+        self.id = None;
+        self.seq = 0;
+    }
+
+    fn reconnection_type(&self) -> ReconnectType {
+        if self.id.is_some() {
+            ReconnectType::Resume
+        } else {
+            ReconnectType::Reidentify
+        }
+    }
+
+    /// Records a RESUME attempt, returning the number of seconds to wait
+    /// before making it.
+    ///
+    /// The first attempt is made immediately, and the wait then doubles with
+    /// each attempt up to 32 seconds.
+    fn attempt_resume(&mut self) -> Result<u64> {
+        if self.id.is_none() {
+            return Err(Error::Gateway(GatewayError::NoSessionId));
+        }
+
+        let backoff = if self.resume_attempts == 0 {
+            0
+        } else {
+            1u64
+                .checked_shl(self.resume_attempts - 1)
+                .unwrap_or(MAX_RESUME_BACKOFF_IN_SECONDS)
+                .min(MAX_RESUME_BACKOFF_IN_SECONDS)
+        };
+
+        self.resume_attempts = self.resume_attempts.saturating_add(1);
+
+        Ok(backoff)
+    }
+}
+
 fn connect(base_url: &str) -> Result<WsClient> {
     let url = build_gateway_url(base_url)?;
-    let client = ClientBuilder::from_url(&url).connect_secure(None)?;
+    let timeout = StdDuration::from_millis(CONNECT_TIMEOUT.load(Ordering::Relaxed) as u64);
+
+    // Each step of connecting can block indefinitely on a network stall, so
+    // each step is bounded by the time remaining until the deadline.
+    let deadline = Instant::now() + timeout;
+
+    let host = match url.host_str() {
+        Some(host) => host,
+        None => return Err(Error::Gateway(GatewayError::BuildingUrl)),
+    };
+    let port = url.port_or_known_default().unwrap_or(443);
+
+    let stream = connect_tcp((host, port), deadline)?;
+    set_handshake_timeout(&stream, deadline)?;
+
+    let stream = match TlsConnector::new()?.connect(host, stream) {
+        Ok(stream) => stream,
+        Err(HandshakeError::WouldBlock(_)) => return Err(connect_timeout()),
+        Err(HandshakeError::Failure(why)) => return Err(Error::Tls(why)),
+    };
+    set_handshake_timeout(stream.get_ref(), deadline)?;
+
+    match ClientBuilder::from_url(&url).connect_on(stream) {
+        Ok(client) => Ok(client),
+        Err(WebSocketError::IoError(ref why)) if is_read_timeout(why) => {
+            Err(connect_timeout())
+        },
+        Err(WebSocketError::HttpError(HyperError::Io(ref why))) if is_read_timeout(why) => {
+            Err(connect_timeout())
+        },
+        Err(why) => Err(Error::WebSocket(why)),
+    }
+}
+
+/// Opens a TCP connection to the first of the address's resolved socket
+/// addresses that accepts one before the deadline.
+fn connect_tcp<A: ToSocketAddrs>(address: A, deadline: Instant) -> Result<TcpStream> {
+    let mut last_error = IoError::new(IoErrorKind::NotFound, "The gateway's host has no addresses");
+
+    for addr in address.to_socket_addrs()? {
+        let remaining = remaining_until(deadline).ok_or_else(connect_timeout)?;
+
+        match TcpStream::connect_timeout(&addr, remaining) {
+            Ok(stream) => return Ok(stream),
+            Err(why) => last_error = why,
+        }
+    }
+
+    if is_read_timeout(&last_error) {
+        Err(connect_timeout())
+    } else {
+        Err(Error::Io(last_error))
+    }
+}
+
+/// Bounds the reads and writes of a handshake on the stream by the time
+/// remaining until the deadline.
+fn set_handshake_timeout(stream: &TcpStream, deadline: Instant) -> Result<()> {
+    let remaining = remaining_until(deadline).ok_or_else(connect_timeout)?;
+
+    stream.set_read_timeout(Some(remaining))?;
+    stream.set_write_timeout(Some(remaining))?;
+
+    Ok(())
+}
+
+fn remaining_until(deadline: Instant) -> Option<StdDuration> {
+    let now = Instant::now();
+
+    if now < deadline {
+        Some(deadline - now)
+    } else {
+        None
+    }
+}
 
-    Ok(client)
+fn connect_timeout() -> Error {
+    warn!("Timed out connecting to the gateway");
+
+    Error::Gateway(GatewayError::ConnectTimeout)
 }
 
 fn set_client_timeout(client: &mut WsClient) -> Result<()> {
@@ -858,3 +1417,105 @@ fn build_gateway_url(base: &str) -> Result<Url> {
             Error::Gateway(GatewayError::BuildingUrl)
         })
 }
+
+/// Whether an I/O error while reading from the gateway only means that no
+/// payload was received before the read timeout elapsed.
+fn is_read_timeout(why: &IoError) -> bool {
+    let kind = why.kind();
+
+    kind == IoErrorKind::WouldBlock || kind == IoErrorKind::TimedOut
+}
+
+/// Whether a close was sent because the session start limit was reached.
+///
+/// Discord has no close code for this, so the reason is checked instead.
+fn is_session_start_limit_close(data: &CloseData) -> bool {
+    data.reason.to_lowercase().contains("session start limit")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::internal::prelude::*;
+    use std::{
+        io::{Error as IoError, ErrorKind as IoErrorKind},
+        net::TcpListener,
+        time::{Duration, Instant},
+    };
+    use super::super::{GatewayError, ReconnectType};
+    use super::{connect, is_read_timeout, set_connect_timeout, Session};
+
+    fn session(id: Option<&str>, seq: u64) -> Session {
+        Session {
+            id: id.map(ToString::to_string),
+            resume_attempts: 0,
+            seq,
+        }
+    }
+
+    #[test]
+    fn test_session_clear() {
+        let mut session = session(Some("a"), 5);
+        assert_eq!(session.reconnection_type(), ReconnectType::Resume);
+
+        // Clearing forces an IDENTIFY, but keeps the sequence to heartbeat
+        // with.
+        session.clear();
+        assert_eq!(session.reconnection_type(), ReconnectType::Reidentify);
+        assert_eq!(session.seq, 5);
+
+        match session.attempt_resume() {
+            Err(Error::Gateway(GatewayError::NoSessionId)) => {},
+            other => panic!("Unexpected result: {:?}", other),
+        }
+        assert_eq!(session.resume_attempts, 0);
+    }
+
+    #[test]
+    fn test_session_reset() {
+        let mut session = session(Some("a"), 5);
+        session.reset();
+
+        assert_eq!(session.reconnection_type(), ReconnectType::Reidentify);
+        assert_eq!(session.seq, 0);
+    }
+
+    #[test]
+    fn test_session_resume_backoff() {
+        let mut session = session(Some("a"), 5);
+        let backoffs = (0..8)
+            .map(|_| session.attempt_resume().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(backoffs, [0, 1, 2, 4, 8, 16, 32, 32]);
+        assert_eq!(session.reconnection_type(), ReconnectType::Resume);
+
+        session.resume_attempts = u32::max_value();
+        assert_eq!(session.attempt_resume().unwrap(), 32);
+    }
+
+    #[test]
+    fn test_connect_timeout() {
+        // The listener never accepts, so the TLS handshake stalls.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("wss://{}", listener.local_addr().unwrap());
+
+        set_connect_timeout(Duration::from_millis(200));
+        let start = Instant::now();
+        let result = connect(&url);
+        set_connect_timeout(Duration::from_secs(30));
+
+        match result {
+            Err(Error::Gateway(GatewayError::ConnectTimeout)) => {},
+            other => panic!("Unexpected result: {:?}", other.map(|_| ())),
+        }
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_is_read_timeout() {
+        assert!(is_read_timeout(&IoError::from(IoErrorKind::WouldBlock)));
+        assert!(is_read_timeout(&IoError::from(IoErrorKind::TimedOut)));
+        assert!(!is_read_timeout(&IoError::from(IoErrorKind::ConnectionReset)));
+        assert!(!is_read_timeout(&IoError::from(IoErrorKind::BrokenPipe)));
+    }
+}
@@ -3,7 +3,9 @@ use crate::constants::{self, OpCode};
 use crate::gateway::{CurrentPresence, WsClient};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::SenderExt;
+use crate::model::gateway::GameType;
 use crate::model::id::GuildId;
+use crate::model::user::OnlineStatus;
 use std::env::consts;
 
 pub trait WebSocketGatewayClientExt {
@@ -18,8 +20,14 @@ pub trait WebSocketGatewayClientExt {
     fn send_heartbeat(&mut self, shard_info: &[u64; 2], seq: Option<u64>)
         -> Result<()>;
 
-    fn send_identify(&mut self, shard_info: &[u64; 2], token: &str)
-        -> Result<()>;
+    fn send_identify(
+        &mut self,
+        shard_info: &[u64; 2],
+        token: &str,
+        current_presence: &CurrentPresence,
+        afk: bool,
+        since: Option<u64>,
+    ) -> Result<()>;
 
     fn send_presence_update(
         &mut self,
@@ -27,6 +35,14 @@ pub trait WebSocketGatewayClientExt {
         current_presence: &CurrentPresence,
     ) -> Result<()>;
 
+    fn send_presence_update_full(
+        &mut self,
+        shard_info: &[u64; 2],
+        current_presence: &CurrentPresence,
+        afk: bool,
+        since: Option<u64>,
+    ) -> Result<()>;
+
     fn send_resume(
         &mut self,
         shard_info: &[u64; 2],
@@ -66,8 +82,14 @@ impl WebSocketGatewayClientExt for WsClient {
         })).map_err(From::from)
     }
 
-    fn send_identify(&mut self, shard_info: &[u64; 2], token: &str)
-        -> Result<()> {
+    fn send_identify(
+        &mut self,
+        shard_info: &[u64; 2],
+        token: &str,
+        current_presence: &CurrentPresence,
+        afk: bool,
+        since: Option<u64>,
+    ) -> Result<()> {
         debug!("[Shard {:?}] Identifying", shard_info);
 
         self.send_json(&json!({
@@ -75,6 +97,9 @@ impl WebSocketGatewayClientExt for WsClient {
             "d": {
                 "compression": true,
                 "large_threshold": constants::LARGE_THRESHOLD,
+                // Sending the presence up front avoids the shard briefly
+                // appearing without it until a presence update is sent.
+                "presence": presence_json(current_presence, afk, since),
                 "shard": shard_info,
                 "token": token,
                 "v": constants::GATEWAY_VERSION,
@@ -92,23 +117,21 @@ impl WebSocketGatewayClientExt for WsClient {
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
     ) -> Result<()> {
-        let &(ref game, ref status) = current_presence;
-        let now = Utc::now().timestamp() as u64;
+        self.send_presence_update_full(shard_info, current_presence, false, None)
+    }
 
+    fn send_presence_update_full(
+        &mut self,
+        shard_info: &[u64; 2],
+        current_presence: &CurrentPresence,
+        afk: bool,
+        since: Option<u64>,
+    ) -> Result<()> {
         debug!("[Shard {:?}] Sending presence update", shard_info);
 
         self.send_json(&json!({
             "op": OpCode::StatusUpdate.num(),
-            "d": {
-                "afk": false,
-                "since": now,
-                "status": status.name(),
-                "game": game.as_ref().map(|x| json!({
-                    "name": x.name,
-                    "type": x.kind,
-                    "url": x.url,
-                })),
-            },
+            "d": presence_json(current_presence, afk, since),
         }))
     }
 
@@ -131,3 +154,27 @@ impl WebSocketGatewayClientExt for WsClient {
         })).map_err(From::from)
     }
 }
+
+/// Creates the presence payload sent in identifies and presence updates.
+fn presence_json(current_presence: &CurrentPresence, afk: bool, since: Option<u64>) -> Value {
+    let &(ref game, ref status) = current_presence;
+
+    // Discord expects the time since which the user has been idle.
+    let since = since.or_else(|| if *status == OnlineStatus::Idle {
+        Some(Utc::now().timestamp_millis() as u64)
+    } else {
+        None
+    });
+
+    json!({
+        "afk": afk,
+        "since": since,
+        "status": status.name(),
+        "game": game.as_ref().map(|x| json!({
+            "name": x.name,
+            "type": x.kind,
+            // Discord ignores the URL of anything but streams.
+            "url": if x.kind == GameType::Streaming { x.url.as_ref() } else { None },
+        })),
+    })
+}
@@ -1,6 +1,9 @@
 use hyper::client::Response;
+use hyper::status::StatusCode;
+use serde_json::Value;
 use std::{
     error::Error as StdError,
+    time::Duration,
     fmt::{
         Display,
         Formatter,
@@ -12,12 +15,35 @@ use std::{
 pub enum Error {
     /// When a non-successful status code was received for a request.
     UnsuccessfulRequest(Response),
+    /// When a non-successful status code was received for a request, along
+    /// with the response's JSON body.
+    ///
+    /// This is given instead of an `UnsuccessfulRequest` when the body was
+    /// already read to check for a specific Discord error code. A body that
+    /// is not JSON is given as a string.
+    UnsuccessfulRequestBody { status: StatusCode, body: Value },
     /// When the decoding of a ratelimit header could not be properly decoded
     /// into an `i64`.
     RateLimitI64,
     /// When the decoding of a ratelimit header could not be properly decoded
     /// from UTF-8.
     RateLimitUtf8,
+    /// When an empty User-Agent was given to [`set_user_agent`].
+    ///
+    /// [`set_user_agent`]: fn.set_user_agent.html
+    InvalidUserAgent,
+    /// When an invite code does not exist or has expired, containing the code.
+    UnknownInvite(String),
+    /// When the client's IP was temporarily banned by Cloudflare, indicated by
+    /// a 429 response that did not come from Discord's API.
+    ///
+    /// The `retry_after` is the length of the ban, if Cloudflare provided one.
+    /// The library will have already waited out this duration, or
+    /// [`CLOUDFLARE_BAN_DEFAULT_SECS`] if there was none, before returning the
+    /// error.
+    ///
+    /// [`CLOUDFLARE_BAN_DEFAULT_SECS`]: ratelimiting/constant.CLOUDFLARE_BAN_DEFAULT_SECS.html
+    CloudflareBan { retry_after: Option<Duration> },
 }
 
 impl Display for Error {
@@ -27,9 +53,15 @@ impl Display for Error {
 impl StdError for Error {
     fn description(&self) -> &str {
         match *self {
-            Error::UnsuccessfulRequest(_) => "A non-successful response status code was received",
+            Error::UnsuccessfulRequest(_) |
+            Error::UnsuccessfulRequestBody { .. } => {
+                "A non-successful response status code was received"
+            },
             Error::RateLimitI64 => "Error decoding a header into an i64",
             Error::RateLimitUtf8 => "Error decoding a header from UTF-8",
+            Error::InvalidUserAgent => "The User-Agent must not be empty",
+            Error::UnknownInvite(_) => "The invite is invalid or has expired",
+            Error::CloudflareBan { .. } => "The client was temporarily banned by Cloudflare",
         }
     }
 }
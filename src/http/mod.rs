@@ -27,13 +27,22 @@ pub mod ratelimiting;
 pub mod raw;
 pub mod request;
 pub mod routing;
+pub mod typing;
 
 mod error;
 
 pub use hyper::status::{StatusClass, StatusCode};
 pub use self::error::Error as HttpError;
+pub use self::ratelimiting::{
+    request_metrics,
+    reset_request_metrics,
+    set_ratelimiting_enabled,
+    set_request_metrics_enabled,
+};
 pub use self::raw::*;
+pub use self::typing::Typing;
 
+use crate::constants;
 use hyper::{
     client::Client as HyperClient,
     method::Method,
@@ -90,9 +99,11 @@ impl LightMethod {
 
 lazy_static! {
     static ref TOKEN: Arc<Mutex<String>> = Arc::new(Mutex::new(String::default()));
+    static ref USER_AGENT: Mutex<String> = Mutex::new(constants::USER_AGENT.to_string());
 }
 
 /// Enum that allows a user to pass a `Path` or a `File` type to `send_files`
+#[derive(Clone, Copy, Debug)]
 pub enum AttachmentType<'a> {
     /// Indicates that the `AttachmentType` is a byte slice with a filename.
     Bytes((&'a [u8], &'a str)),
@@ -100,6 +111,27 @@ pub enum AttachmentType<'a> {
     File((&'a File, &'a str)),
     /// Indicates that the `AttachmentType` is a `Path`
     Path(&'a Path),
+    /// Indicates that the `AttachmentType` is a `Path` to read from, uploaded
+    /// with the given filename instead of the path's own.
+    ///
+    /// If the filename has no extension, the `Content-Type` is guessed from
+    /// the path instead.
+    PathWithName((&'a Path, &'a str)),
+}
+
+impl<'a> AttachmentType<'a> {
+    /// The filename that the attachment is uploaded with, if it has one.
+    ///
+    /// This is the name to reference the attachment by in an embed, as in
+    /// `attachment://{filename}`.
+    pub fn filename(&self) -> Option<&str> {
+        match *self {
+            AttachmentType::Bytes((_, filename)) |
+            AttachmentType::File((_, filename)) |
+            AttachmentType::PathWithName((_, filename)) => Some(filename),
+            AttachmentType::Path(path) => path.file_name().and_then(|name| name.to_str()),
+        }
+    }
 }
 
 impl<'a> From<(&'a [u8], &'a str)> for AttachmentType<'a> {
@@ -120,10 +152,64 @@ impl<'a> From<&'a PathBuf> for AttachmentType<'a> {
     fn from(pathbuf: &'a PathBuf) -> AttachmentType { AttachmentType::Path(pathbuf.as_path()) }
 }
 
+impl<'a> From<(&'a Path, &'a str)> for AttachmentType<'a> {
+    fn from(params: (&'a Path, &'a str)) -> AttachmentType<'a> {
+        AttachmentType::PathWithName(params)
+    }
+}
+
 impl<'a> From<(&'a File, &'a str)> for AttachmentType<'a> {
     fn from(f: (&'a File, &'a str)) -> AttachmentType<'a> { AttachmentType::File((f.0, f.1)) }
 }
 
+/// Guesses the MIME type of a file from the extension of its filename, for use
+/// as the `Content-Type` of an uploaded file.
+///
+/// This is used by [`send_files`] for attachments given as bytes or a `File`,
+/// so that Discord can preview uploads such as images and videos.
+///
+/// Returns `application/octet-stream` if the extension is not known.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::http;
+///
+/// assert_eq!(http::guess_content_type("kona.PNG"), "image/png");
+/// assert_eq!(http::guess_content_type("clip.mov"), "video/quicktime");
+/// assert_eq!(http::guess_content_type("data"), "application/octet-stream");
+/// ```
+///
+/// [`send_files`]: fn.send_files.html
+pub fn guess_content_type(filename: &str) -> &'static str {
+    let extension = match filename.rfind('.') {
+        Some(pos) => filename[pos + 1..].to_lowercase(),
+        None => return "application/octet-stream",
+    };
+
+    match &extension[..] {
+        "apng" => "image/apng",
+        "bmp" => "image/bmp",
+        "gif" => "image/gif",
+        "jpeg" | "jpg" => "image/jpeg",
+        "png" => "image/png",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "mov" => "video/quicktime",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "flac" => "audio/flac",
+        "mp3" => "audio/mpeg",
+        "ogg" => "audio/ogg",
+        "wav" => "audio/wav",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "txt" => "text/plain",
+        _ => "application/octet-stream",
+    }
+}
+
 /// Representation of the method of a query to send for the [`get_guilds`]
 /// function.
 ///
@@ -137,8 +223,62 @@ pub enum GuildPagination {
 
 #[cfg(test)]
 mod test {
-    use super::AttachmentType;
-    use std::path::Path;
+    use crate::internal::prelude::*;
+    use crate::model::prelude::*;
+    use hyper::{
+        client::Response as HyperResponse,
+        header::Headers,
+        http::{RawStatus, message::{HttpMessage, RequestHead, ResponseHead}},
+        version::HttpVersion,
+        Url,
+    };
+    use super::{AttachmentType, HttpError, StatusCode, raw};
+    use std::{
+        io::{Read, Result as IoResult, Write},
+        path::Path,
+        time::Duration,
+    };
+
+    /// A response with only a status code, received without a connection.
+    #[derive(Debug)]
+    struct MockMessage(u16);
+
+    impl Read for MockMessage {
+        fn read(&mut self, _: &mut [u8]) -> IoResult<usize> { Ok(0) }
+    }
+
+    impl Write for MockMessage {
+        fn write(&mut self, buf: &[u8]) -> IoResult<usize> { Ok(buf.len()) }
+
+        fn flush(&mut self) -> IoResult<()> { Ok(()) }
+    }
+
+    impl HttpMessage for MockMessage {
+        fn set_outgoing(&mut self, head: RequestHead) -> hyper::Result<RequestHead> { Ok(head) }
+
+        fn get_incoming(&mut self) -> hyper::Result<ResponseHead> {
+            Ok(ResponseHead {
+                headers: Headers::new(),
+                raw_status: RawStatus(self.0, "".into()),
+                version: HttpVersion::Http11,
+            })
+        }
+
+        fn set_read_timeout(&self, _: Option<Duration>) -> IoResult<()> { Ok(()) }
+
+        fn set_write_timeout(&self, _: Option<Duration>) -> IoResult<()> { Ok(()) }
+
+        fn close_connection(&mut self) -> hyper::Result<()> { Ok(()) }
+
+        fn has_body(&self) -> bool { false }
+    }
+
+    fn unsuccessful(status: u16) -> Error {
+        let url = Url::parse("https://discordapp.com/api/v6/invites/abc").unwrap();
+        let response = HyperResponse::with_message(url, Box::new(MockMessage(status))).unwrap();
+
+        Error::Http(HttpError::UnsuccessfulRequest(response))
+    }
 
     #[test]
     fn test_attachment_type() {
@@ -150,5 +290,111 @@ mod test {
             AttachmentType::Path(_) => true,
             _ => false,
         });
+        assert!(match AttachmentType::from((Path::new("/tmp/abc123"), "report.pdf")) {
+            AttachmentType::PathWithName((_, "report.pdf")) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_check_message_length() {
+        let content = "é".repeat(2000);
+        let map = json!({ "content": content }).as_object().unwrap().clone();
+        assert!(raw::check_message_length(&map).is_ok());
+
+        let content = "é".repeat(2003);
+        let map = json!({ "content": content }).as_object().unwrap().clone();
+        assert!(match raw::check_message_length(&map) {
+            Err(Error::Model(ModelError::MessageTooLong(3))) => true,
+            _ => false,
+        });
+
+        let description = "a".repeat(3000);
+        let map = json!({
+            "embeds": [
+                { "description": description, "title": "abc" },
+                { "description": description },
+            ],
+        }).as_object().unwrap().clone();
+        assert!(match raw::check_message_length(&map) {
+            Err(Error::Model(ModelError::EmbedTooLarge(3))) => true,
+            _ => false,
+        });
+
+        let map = json!({ "embed": { "timestamp": "2004-06-08T16:04:23" } });
+        assert!(raw::check_message_length(map.as_object().unwrap()).is_ok());
+
+        for map in &[
+            json!({ "embed": { "timestamp": "yesterday" } }),
+            json!({ "embeds": [{}, { "timestamp": "yesterday" }] }),
+        ] {
+            assert!(match raw::check_message_length(map.as_object().unwrap()) {
+                Err(Error::Model(ModelError::InvalidEmbedTimestamp(ref ts))) => ts == "yesterday",
+                _ => false,
+            });
+        }
+    }
+
+    #[test]
+    fn test_map_unknown_invite() {
+        assert!(match raw::map_unknown_invite::<()>(Err(unsuccessful(404)), "abc") {
+            Err(Error::Http(HttpError::UnknownInvite(ref code))) => code == "abc",
+            _ => false,
+        });
+        assert!(match raw::map_unknown_invite::<()>(Err(unsuccessful(403)), "abc") {
+            Err(Error::Http(HttpError::UnsuccessfulRequest(ref response))) => {
+                response.status == StatusCode::Forbidden
+            },
+            _ => false,
+        });
+        assert!(raw::map_unknown_invite(Ok(()), "abc").is_ok());
+    }
+
+    #[test]
+    fn test_error_for_code() {
+        let closed = || Error::Model(ModelError::DirectMessagesClosed);
+
+        let body = br#"{"code": 50007, "message": "Cannot send messages to this user"}"#;
+        assert!(match raw::error_for_code(StatusCode::Forbidden, body, 50007, closed()) {
+            Error::Model(ModelError::DirectMessagesClosed) => true,
+            _ => false,
+        });
+
+        let body = br#"{"code": 50013, "message": "Missing Permissions"}"#;
+        assert!(match raw::error_for_code(StatusCode::Forbidden, body, 50007, closed()) {
+            Error::Http(HttpError::UnsuccessfulRequestBody { status, ref body }) => {
+                status == StatusCode::Forbidden && body["message"] == "Missing Permissions"
+            },
+            _ => false,
+        });
+
+        let body = b"error code: 1020";
+        assert!(match raw::error_for_code(StatusCode::Forbidden, body, 50007, closed()) {
+            Error::Http(HttpError::UnsuccessfulRequestBody { ref body, .. }) => {
+                body == "error code: 1020"
+            },
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_encode_reaction() {
+        let keycap = ReactionType::from("#\u{fe0f}\u{20e3}");
+        assert_eq!(raw::encode_reaction(&keycap), "%23%EF%B8%8F%E2%83%A3");
+
+        let custom = ReactionType::Custom {
+            animated: false,
+            id: EmojiId(302516740095606785),
+            name: Some("smug_face".to_string()),
+        };
+        assert_eq!(raw::encode_reaction(&custom), "smug_face:302516740095606785");
+    }
+
+    #[test]
+    fn test_set_user_agent_empty() {
+        assert!(match raw::set_user_agent(" ") {
+            Err(Error::Http(HttpError::InvalidUserAgent)) => true,
+            _ => false,
+        });
     }
 }
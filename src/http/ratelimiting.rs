@@ -44,14 +44,18 @@ pub use super::routing::Route;
 
 use chrono::{DateTime, Utc};
 use hyper::client::Response;
-use hyper::header::Headers;
+use hyper::header::{ContentType, Headers};
+use hyper::mime::{Mime, SubLevel};
 use hyper::status::StatusCode;
 use crate::internal::prelude::*;
 use parking_lot::Mutex;
 use std::{
     collections::HashMap,
-    sync::Arc,
-    time::Duration,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
     str,
     thread,
     i64
@@ -63,6 +67,24 @@ use super::{HttpError, Request};
 /// [`offset`]: fn.offset.html
 static mut OFFSET: Option<i64> = None;
 
+/// Refer to [`set_ratelimiting_enabled`].
+///
+/// [`set_ratelimiting_enabled`]: fn.set_ratelimiting_enabled.html
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Refer to [`set_request_metrics_enabled`].
+///
+/// [`set_request_metrics_enabled`]: fn.set_request_metrics_enabled.html
+static METRICS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The number of requests that may be made across the entirety of the API per
+/// second, as per Discord's global ratelimit.
+pub const GLOBAL_LIMIT: u32 = 50;
+
+/// The number of seconds that all requests are blocked for after a Cloudflare
+/// ban which did not include a `retry-after`.
+pub const CLOUDFLARE_BAN_DEFAULT_SECS: u64 = 600;
+
 lazy_static! {
     /// The global mutex is a mutex unlocked and then immediately re-locked
     /// prior to every request, to abide by Discord's global ratelimit.
@@ -102,6 +124,16 @@ lazy_static! {
     pub static ref ROUTES: Arc<Mutex<HashMap<Route, Arc<Mutex<RateLimit>>>>> = {
         Arc::new(Mutex::new(HashMap::default()))
     };
+    /// The number of requests remaining in the current global ratelimit
+    /// window, and when the window resets. Refer to [`global_remaining`].
+    ///
+    /// [`global_remaining`]: fn.global_remaining.html
+    static ref GLOBAL_WINDOW: Mutex<Option<(u32, Instant)>> = Mutex::new(None);
+    /// The number of requests made to each route while request metrics are
+    /// enabled. Refer to [`request_metrics`].
+    ///
+    /// [`request_metrics`]: fn.request_metrics.html
+    static ref METRICS: Mutex<HashMap<Route, u64>> = Mutex::new(HashMap::new());
 }
 
 pub(super) fn perform(req: Request) -> Result<Response> {
@@ -120,6 +152,10 @@ pub(super) fn perform(req: Request) -> Result<Response> {
         // This isn't normally important, but might be for ratelimiting.
         let (_, route, _) = req.route.deconstruct();
 
+        if METRICS_ENABLED.load(Ordering::Relaxed) {
+            track_route_request(route);
+        }
+
         // Perform pre-checking here:
         //
         // - get the route's relevant rate
@@ -142,6 +178,10 @@ pub(super) fn perform(req: Request) -> Result<Response> {
         let mut lock = bucket.lock();
         lock.pre_hook(&route);
 
+        if route != Route::None {
+            track_global_request();
+        }
+
         let response = super::raw::retry(&req)?;
 
         // Check if an offset has been calculated yet to determine the time
@@ -155,6 +195,31 @@ pub(super) fn perform(req: Request) -> Result<Response> {
             calculate_offset(response.headers.get_raw("date"));
         }
 
+        // A 429 without a JSON body did not come from Discord's ratelimiter,
+        // but from Cloudflare banning the IP for a while. Its 'retry-after' is
+        // in seconds rather than milliseconds, and retrying before it passes
+        // only extends the ban, so block all requests for the full duration
+        // and then report the ban instead of retrying.
+        if is_cloudflare_ban(response.status, &response.headers) {
+            let retry_after = parse_header(&response.headers, "retry-after")?
+                .map(|secs| Duration::from_secs(secs as u64));
+
+            if let Some(retry_after) = retry_after {
+                warn!("Banned by Cloudflare for {:?}", retry_after);
+            } else {
+                warn!("Banned by Cloudflare for an unknown duration");
+            }
+
+            let wait = cloudflare_ban_wait(retry_after);
+
+            let _global = GLOBAL.lock();
+            *GLOBAL_WINDOW.lock() = Some((0, Instant::now() + wait));
+
+            thread::sleep(wait);
+
+            return Err(Error::Http(HttpError::CloudflareBan { retry_after }));
+        }
+
         // Check if the request got ratelimited by checking for status 429,
         // and if so, sleep for the value of the header 'retry-after' -
         // which is in milliseconds - and then `continue` to try again
@@ -177,6 +242,10 @@ pub(super) fn perform(req: Request) -> Result<Response> {
                 Ok(
                     if let Some(retry_after) = parse_header(&response.headers, "retry-after")? {
                         debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
+
+                        let reset = Instant::now() + Duration::from_millis(retry_after as u64);
+                        *GLOBAL_WINDOW.lock() = Some((0, reset));
+
                         thread::sleep(Duration::from_millis(retry_after as u64));
 
                         true
@@ -240,6 +309,10 @@ impl RateLimit {
         let diff = (self.reset - current_time) as u64;
 
         if self.remaining == 0 {
+            if !ENABLED.load(Ordering::Relaxed) {
+                return;
+            }
+
             let delay = (diff * 1000) + 500;
 
             debug!(
@@ -302,6 +375,124 @@ pub fn offset() -> Option<i64> {
     unsafe { OFFSET }
 }
 
+/// Sets whether requests should be pre-emptively ratelimited.
+///
+/// When disabled, requests are issued without waiting for a route's ratelimit
+/// to reset, although ratelimited responses - with a status of `429` - are
+/// still honored by waiting and retrying.
+///
+/// This is meant for test harnesses pointing the library at a mock server, and
+/// should not be disabled in production.
+///
+/// **Note**: Defaults to `true`.
+pub fn set_ratelimiting_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Sets whether the number of requests made to each [`Route`] is counted,
+/// which can then be retrieved via [`request_metrics`].
+///
+/// Requests that are retried, such as after being ratelimited, count once for
+/// every attempt.
+///
+/// **Note**: Defaults to `false`, in which case no counting is done.
+///
+/// [`Route`]: ../routing/enum.Route.html
+/// [`request_metrics`]: fn.request_metrics.html
+pub fn set_request_metrics_enabled(enabled: bool) {
+    METRICS_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Retrieves a snapshot of the number of requests made to each [`Route`] since
+/// request metrics were enabled via [`set_request_metrics_enabled`].
+///
+/// Routes are per major parameter, so for example requests to two different
+/// channels are counted separately.
+///
+/// # Examples
+///
+/// Print the five most requested routes:
+///
+/// ```rust,no_run
+/// use serenity::http;
+/// use std::cmp::Reverse;
+///
+/// http::set_request_metrics_enabled(true);
+///
+/// // after having made some requests:
+/// let mut metrics = http::request_metrics().into_iter().collect::<Vec<_>>();
+/// metrics.sort_by_key(|&(_, count)| Reverse(count));
+///
+/// for (route, count) in metrics.iter().take(5) {
+///     println!("{:?}: {}", route, count);
+/// }
+/// ```
+///
+/// [`Route`]: ../routing/enum.Route.html
+/// [`set_request_metrics_enabled`]: fn.set_request_metrics_enabled.html
+pub fn request_metrics() -> HashMap<Route, u64> {
+    METRICS.lock().clone()
+}
+
+/// Clears the request counts retrieved via [`request_metrics`], such as after
+/// having exported them.
+///
+/// [`request_metrics`]: fn.request_metrics.html
+pub fn reset_request_metrics() {
+    METRICS.lock().clear();
+}
+
+/// Retrieves the number of requests remaining under Discord's global ratelimit
+/// and when it resets, to allow pacing a large batch of requests.
+///
+/// This is tracked by the library as requests are made, in windows of one
+/// second of up to [`GLOBAL_LIMIT`] requests. If the global ratelimit is hit,
+/// then the remaining count is `0` until the `Retry-After` given by Discord has
+/// passed.
+///
+/// This will return `None` if no window is currently active, meaning that the
+/// full [`GLOBAL_LIMIT`] is available.
+///
+/// # Examples
+///
+/// Wait for the global ratelimit to reset if fewer than 10 requests remain:
+///
+/// ```rust,no_run
+/// use serenity::http::ratelimiting;
+/// use std::{thread, time::Instant};
+///
+/// if let Some((remaining, reset)) = ratelimiting::global_remaining() {
+///     let now = Instant::now();
+///
+///     if remaining < 10 && reset > now {
+///         thread::sleep(reset - now);
+///     }
+/// }
+/// ```
+///
+/// [`GLOBAL_LIMIT`]: constant.GLOBAL_LIMIT.html
+pub fn global_remaining() -> Option<(u32, Instant)> {
+    let window = *GLOBAL_WINDOW.lock();
+
+    window.filter(|&(_, reset)| reset > Instant::now())
+}
+
+fn track_global_request() {
+    let now = Instant::now();
+    let mut window = GLOBAL_WINDOW.lock();
+
+    match *window {
+        Some((ref mut remaining, reset)) if reset > now => {
+            *remaining = remaining.saturating_sub(1);
+        },
+        _ => *window = Some((GLOBAL_LIMIT - 1, now + Duration::from_secs(1))),
+    }
+}
+
+fn track_route_request(route: Route) {
+    *METRICS.lock().entry(route).or_insert(0) += 1;
+}
+
 fn calculate_offset(header: Option<&[Vec<u8>]>) {
     // Get the current time as soon as possible.
     let now = Utc::now().timestamp();
@@ -332,6 +523,31 @@ fn calculate_offset(header: Option<&[Vec<u8>]>) {
     }
 }
 
+/// Whether a response is a Cloudflare ban page rather than one of Discord's
+/// ratelimit responses, which are always JSON.
+fn is_cloudflare_ban(status: StatusCode, headers: &Headers) -> bool {
+    if status != StatusCode::TooManyRequests {
+        return false;
+    }
+
+    if let Some(&ContentType(Mime(_, SubLevel::Json, _))) = headers.get::<ContentType>() {
+        return false;
+    }
+
+    true
+}
+
+/// How long to block all requests for after a Cloudflare ban.
+///
+/// When Cloudflare does not say how long the ban lasts, a conservative default
+/// of [`CLOUDFLARE_BAN_DEFAULT_SECS`] is used, as retrying early extends the
+/// ban.
+///
+/// [`CLOUDFLARE_BAN_DEFAULT_SECS`]: constant.CLOUDFLARE_BAN_DEFAULT_SECS.html
+fn cloudflare_ban_wait(retry_after: Option<Duration>) -> Duration {
+    retry_after.unwrap_or_else(|| Duration::from_secs(CLOUDFLARE_BAN_DEFAULT_SECS))
+}
+
 fn parse_header(headers: &Headers, header: &str) -> Result<Option<i64>> {
     headers.get_raw(header).map_or(Ok(None), |header| {
         str::from_utf8(&header[0])
@@ -343,3 +559,87 @@ fn parse_header(headers: &Headers, header: &str) -> Result<Option<i64>> {
             })
     })
 }
+
+#[cfg(test)]
+mod test {
+    use super::{
+        cloudflare_ban_wait,
+        global_remaining,
+        is_cloudflare_ban,
+        request_metrics,
+        set_ratelimiting_enabled,
+        track_global_request,
+        track_route_request,
+        RateLimit,
+        Route,
+        CLOUDFLARE_BAN_DEFAULT_SECS,
+        GLOBAL_LIMIT,
+    };
+    use chrono::Utc;
+    use hyper::header::{ContentType, Headers};
+    use hyper::status::StatusCode;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_global_remaining() {
+        track_global_request();
+        track_global_request();
+
+        let (remaining, _) = global_remaining().unwrap();
+        assert!(remaining <= GLOBAL_LIMIT - 2);
+    }
+
+    #[test]
+    fn test_request_metrics() {
+        track_route_request(Route::ChannelsId(1));
+        track_route_request(Route::ChannelsId(1));
+        track_route_request(Route::ChannelsId(2));
+
+        let metrics = request_metrics();
+        assert_eq!(metrics[&Route::ChannelsId(1)], 2);
+        assert_eq!(metrics[&Route::ChannelsId(2)], 1);
+    }
+
+    #[test]
+    fn test_is_cloudflare_ban() {
+        let mut json = Headers::new();
+        json.set(ContentType::json());
+        assert!(!is_cloudflare_ban(StatusCode::TooManyRequests, &json));
+        assert!(!is_cloudflare_ban(StatusCode::Ok, &json));
+
+        let mut html = Headers::new();
+        html.set(ContentType::html());
+        assert!(is_cloudflare_ban(StatusCode::TooManyRequests, &html));
+        assert!(!is_cloudflare_ban(StatusCode::Forbidden, &html));
+
+        assert!(is_cloudflare_ban(StatusCode::TooManyRequests, &Headers::new()));
+    }
+
+    #[test]
+    fn test_ratelimiting_disabled() {
+        let mut ratelimit = RateLimit {
+            limit: 5,
+            remaining: 0,
+            reset: Utc::now().timestamp() + 60,
+        };
+
+        // With no requests remaining until the reset, the pre-emptive wait of
+        // about a minute is skipped.
+        set_ratelimiting_enabled(false);
+        let start = Instant::now();
+        ratelimit.pre_hook(&Route::ChannelsId(1));
+        set_ratelimiting_enabled(true);
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(ratelimit.remaining, 0);
+    }
+
+    #[test]
+    fn test_cloudflare_ban_wait() {
+        let retry_after = Duration::from_secs(30);
+        assert_eq!(cloudflare_ban_wait(Some(retry_after)), retry_after);
+
+        let default = Duration::from_secs(CLOUDFLARE_BAN_DEFAULT_SECS);
+        assert_eq!(cloudflare_ban_wait(None), default);
+    }
+}
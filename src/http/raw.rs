@@ -1,3 +1,4 @@
+use crate::builder;
 use crate::constants;
 use hyper::{
     client::{
@@ -19,6 +20,8 @@ use crate::model::prelude::*;
 use multipart::client::Multipart;
 use super::{
     TOKEN,
+    USER_AGENT,
+    guess_content_type,
     ratelimiting,
     request::Request,
     routing::RouteInfo,
@@ -32,7 +35,10 @@ use serde::de::DeserializeOwned;
 use serde_json;
 use std::{
     collections::BTreeMap,
-    io::ErrorKind as IoErrorKind,
+    fmt::Write as FmtWrite,
+    fs::File,
+    io::{ErrorKind as IoErrorKind, Read},
+    time::{Duration, Instant},
 };
 
 /// Sets the token to be used across all requests which require authentication.
@@ -61,6 +67,38 @@ use std::{
 /// # }
 pub fn set_token(token: &str) { TOKEN.lock().clone_from(&token.to_string()); }
 
+/// Sets the User-Agent sent with every request, overriding the default of
+/// [`constants::USER_AGENT`].
+///
+/// Discord asks that the User-Agent identifies the library, its version, and
+/// a URL for the bot.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::http;
+///
+/// http::set_user_agent("DiscordBot (https://example.com/my-bot, 1.0.0)")
+///     .expect("non-empty user agent");
+/// ```
+///
+/// # Errors
+///
+/// Returns an [`HttpError::InvalidUserAgent`] if the given User-Agent is
+/// empty or only whitespace.
+///
+/// [`HttpError::InvalidUserAgent`]: enum.HttpError.html#variant.InvalidUserAgent
+/// [`constants::USER_AGENT`]: ../constants/constant.USER_AGENT.html
+pub fn set_user_agent(user_agent: &str) -> Result<()> {
+    if user_agent.trim().is_empty() {
+        return Err(Error::Http(HttpError::InvalidUserAgent));
+    }
+
+    USER_AGENT.lock().clone_from(&user_agent.to_string());
+
+    Ok(())
+}
+
 /// Adds a [`User`] as a recipient to a [`Group`].
 ///
 /// **Note**: Groups have a limit of 10 recipients, including the current user.
@@ -328,7 +366,7 @@ pub fn create_reaction(channel_id: u64,
         body: None,
         headers: None,
         route: RouteInfo::CreateReaction {
-            reaction: &reaction_type.as_data(),
+            reaction: &encode_reaction(reaction_type),
             channel_id,
             message_id,
         },
@@ -346,6 +384,38 @@ pub fn create_role(guild_id: u64, map: &JsonMap) -> Result<Role> {
     })
 }
 
+/// Starts a [`StageInstance`] in a stage channel, with the given topic.
+///
+/// Discord responds with an error if the channel is not a
+/// [stage channel][`ChannelType::Stage`], or if a stage instance is already
+/// being held in it.
+///
+/// Requires the [Manage Channels], [Mute Members], and [Move Members]
+/// permissions in the channel.
+///
+/// [`ChannelType::Stage`]: ../../model/channel/enum.ChannelType.html#variant.Stage
+/// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+/// [Manage Channels]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+/// [Move Members]: ../../model/permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+/// [Mute Members]: ../../model/permissions/struct.Permissions.html#associatedconstant.MUTE_MEMBERS
+pub fn create_stage_instance(
+    channel_id: u64,
+    topic: &str,
+    privacy_level: StagePrivacyLevel,
+) -> Result<StageInstance> {
+    let body = serde_json::to_vec(&json!({
+        "channel_id": channel_id.to_string(),
+        "privacy_level": privacy_level.num(),
+        "topic": topic,
+    }))?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::CreateStageInstance,
+    })
+}
+
 /// Creates a webhook for the given [channel][`GuildChannel`]'s Id, passing in
 /// the given data.
 ///
@@ -423,12 +493,34 @@ pub fn delete_guild_integration(guild_id: u64, integration_id: u64) -> Result<()
 }
 
 /// Deletes an invite by code.
-pub fn delete_invite(code: &str) -> Result<Invite> {
-    fire(Request {
+///
+/// The code may also be given as an invite URL, such as
+/// `https://discord.gg/WxZumR`.
+///
+/// **Note**: Requires the [Manage Guild] permission, or the [Manage Channels]
+/// permission in the invite's channel.
+///
+/// # Errors
+///
+/// Returns an [`HttpError::UnknownInvite`] if the invite code does not exist,
+/// such as when the invite has expired, reached its maximum uses, or was
+/// already deleted.
+///
+/// [`HttpError::UnknownInvite`]: enum.HttpError.html#variant.UnknownInvite
+/// [Manage Channels]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+/// [Manage Guild]: ../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
+#[allow(unused_mut)]
+pub fn delete_invite(mut code: &str) -> Result<Invite> {
+    #[cfg(feature = "utils")]
+        {
+            code = crate::utils::parse_invite(code);
+        }
+
+    map_unknown_invite(fire(Request {
         body: None,
         headers: None,
         route: RouteInfo::DeleteInvite { code },
-    })
+    }), code)
 }
 
 /// Deletes a message if created by us or we have
@@ -475,6 +567,44 @@ pub fn delete_message_reactions(channel_id: u64, message_id: u64) -> Result<()>
     })
 }
 
+/// Deletes all of the [`Reaction`]s of a single emoji from a [`Message`],
+/// regardless of which users reacted.
+///
+/// **Note**: Requires the [Manage Messages] permission.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::http;
+/// use serenity::model::channel::ReactionType;
+/// use serenity::model::id::{ChannelId, MessageId};
+///
+/// let channel_id = ChannelId(7);
+/// let message_id = MessageId(8);
+/// let reaction = ReactionType::from("👍");
+///
+/// let _ = http::delete_reaction_emoji(channel_id.0, message_id.0, &reaction)
+///     .expect("Error deleting reactions");
+/// ```
+///
+/// [`Message`]: ../../model/channel/struct.Message.html
+/// [`Reaction`]: ../../model/channel/struct.Reaction.html
+/// [Manage Messages]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+pub fn delete_reaction_emoji(channel_id: u64,
+                             message_id: u64,
+                             reaction_type: &ReactionType)
+                             -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteMessageReactionEmoji {
+            reaction: &encode_reaction(reaction_type),
+            channel_id,
+            message_id,
+        },
+    })
+}
+
 /// Deletes a permission override from a role or a member in a channel.
 pub fn delete_permission(channel_id: u64, target_id: u64) -> Result<()> {
     wind(204, Request {
@@ -484,6 +614,50 @@ pub fn delete_permission(channel_id: u64, target_id: u64) -> Result<()> {
     })
 }
 
+/// Formats a [`ReactionType`] for use in the path of the reaction endpoints.
+///
+/// Unicode emojis are percent-encoded in full, including any combining
+/// characters or variation selectors, while custom emojis are formatted as
+/// `name:id`.
+///
+/// This is used by [`create_reaction`], [`delete_reaction`],
+/// [`delete_reaction_emoji`] and [`get_reaction_users`], and should be used
+/// when building a [`RouteInfo`] for a reaction endpoint manually.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::http;
+/// use serenity::model::channel::ReactionType;
+///
+/// let reaction = ReactionType::from("👍");
+///
+/// assert_eq!(http::encode_reaction(&reaction), "%F0%9F%91%8D");
+///
+/// // Emojis made of multiple code points, such as those with a skin tone
+/// // modifier, are encoded as a whole.
+/// let reaction = ReactionType::from("👍🏽");
+///
+/// assert_eq!(http::encode_reaction(&reaction), "%F0%9F%91%8D%F0%9F%8F%BD");
+/// ```
+///
+/// [`ReactionType`]: ../../model/channel/enum.ReactionType.html
+/// [`RouteInfo`]: ../routing/enum.RouteInfo.html
+/// [`create_reaction`]: fn.create_reaction.html
+/// [`delete_reaction`]: fn.delete_reaction.html
+/// [`delete_reaction_emoji`]: fn.delete_reaction_emoji.html
+/// [`get_reaction_users`]: fn.get_reaction_users.html
+pub fn encode_reaction(reaction_type: &ReactionType) -> String {
+    match *reaction_type {
+        ReactionType::Custom { id, ref name, .. } => {
+            let name = name.as_ref().map_or("", |s| s.as_str());
+
+            format!("{}:{}", percent_encode(name), id)
+        },
+        ReactionType::Unicode(ref unicode) => percent_encode(unicode),
+    }
+}
+
 /// Deletes a reaction from a message if owned by us or
 /// we have specific permissions.
 pub fn delete_reaction(channel_id: u64,
@@ -499,7 +673,7 @@ pub fn delete_reaction(channel_id: u64,
         body: None,
         headers: None,
         route: RouteInfo::DeleteReaction {
-            reaction: &reaction_type.as_data(),
+            reaction: &encode_reaction(reaction_type),
             user: &user,
             channel_id,
             message_id,
@@ -516,6 +690,20 @@ pub fn delete_role(guild_id: u64, role_id: u64) -> Result<()> {
     })
 }
 
+/// Ends the [`StageInstance`] being held in a stage channel.
+///
+/// Requires the same permissions as [`create_stage_instance`].
+///
+/// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+/// [`create_stage_instance`]: fn.create_stage_instance.html
+pub fn delete_stage_instance(channel_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteStageInstance { channel_id },
+    })
+}
+
 /// Deletes a [`Webhook`] given its Id.
 ///
 /// This method requires authentication, whereas [`delete_webhook_with_token`]
@@ -546,6 +734,36 @@ pub fn delete_webhook(webhook_id: u64) -> Result<()> {
     })
 }
 
+/// Deletes a [`Message`] previously sent by a [`Webhook`], given the webhook's
+/// Id and unique token.
+///
+/// This method does _not_ require authentication.
+///
+/// # Examples
+///
+/// Deletes a message sent by a webhook:
+///
+/// ```rust,no_run
+/// use serenity::http;
+///
+/// let id = 245037420704169985;
+/// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+/// let message_id = 381880193700069377;
+///
+/// http::delete_webhook_message(id, token, message_id)
+///     .expect("Error deleting webhook message");
+/// ```
+///
+/// [`Message`]: ../../model/channel/struct.Message.html
+/// [`Webhook`]: ../../model/webhook/struct.Webhook.html
+pub fn delete_webhook_message(webhook_id: u64, token: &str, message_id: u64) -> Result<()> {
+    wind(204, Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::DeleteWebhookMessage { message_id, token, webhook_id },
+    })
+}
+
 /// Deletes a [`Webhook`] given its Id and unique token.
 ///
 /// This method does _not_ require authentication.
@@ -606,6 +824,15 @@ pub fn edit_guild(guild_id: u64, map: &JsonMap) -> Result<PartialGuild> {
 }
 
 /// Edits the positions of a guild's channels.
+///
+/// The value should be an array of objects containing the `id` and new
+/// `position` of each channel, and optionally a `parent_id` and
+/// `lock_permissions` to move a channel into a category. All channels are
+/// updated in a single request.
+///
+/// Refer to [`EditChannelPositions`] for a builder for this value.
+///
+/// [`EditChannelPositions`]: ../../builder/struct.EditChannelPositions.html
 pub fn edit_guild_channel_positions(guild_id: u64, value: &Value)
                                     -> Result<()> {
     let body = serde_json::to_vec(value)?;
@@ -645,6 +872,10 @@ pub fn edit_member(guild_id: u64, user_id: u64, map: &JsonMap) -> Result<()> {
 ///
 /// **Note**: Only the author of a message can modify it.
 pub fn edit_message(channel_id: u64, message_id: u64, map: &Value) -> Result<Message> {
+    if let Some(map) = map.as_object() {
+        check_message_length(map)?;
+    }
+
     let body = serde_json::to_vec(map)?;
 
     fire(Request {
@@ -729,6 +960,23 @@ pub fn edit_role_position(guild_id: u64, role_id: u64, position: u64) -> Result<
     })
 }
 
+/// Changes the topic or privacy level of the [`StageInstance`] being held in a
+/// stage channel.
+///
+/// Requires the same permissions as [`create_stage_instance`].
+///
+/// [`StageInstance`]: ../../model/channel/struct.StageInstance.html
+/// [`create_stage_instance`]: fn.create_stage_instance.html
+pub fn edit_stage_instance(channel_id: u64, map: &JsonMap) -> Result<StageInstance> {
+    let body = serde_json::to_vec(map)?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::EditStageInstance { channel_id },
+    })
+}
+
 /// Edits a the webhook with the given data.
 ///
 /// The Value is a map with optional values of:
@@ -812,6 +1060,37 @@ pub fn edit_webhook_with_token(webhook_id: u64, token: &str, map: &JsonMap) -> R
     })
 }
 
+/// Edits a [`Message`] previously sent by a [`Webhook`], given the webhook's
+/// Id and unique token.
+///
+/// The map can contain the `content` and `embeds` of the message. Only the
+/// fields present are modified.
+///
+/// This method does _not_ require authentication.
+///
+/// [`Message`]: ../../model/channel/struct.Message.html
+/// [`Webhook`]: ../../model/webhook/struct.Webhook.html
+pub fn edit_webhook_message(webhook_id: u64,
+                            token: &str,
+                            message_id: u64,
+                            map: &JsonMap)
+                            -> Result<Message> {
+    check_message_length(map)?;
+
+    let body = serde_json::to_vec(map)?;
+
+    let mut headers = Headers::new();
+    headers.set(ContentType(
+        Mime(TopLevel::Application, SubLevel::Json, vec![]),
+    ));
+
+    fire(Request {
+        body: Some(&body),
+        headers: Some(headers),
+        route: RouteInfo::EditWebhookMessage { message_id, token, webhook_id },
+    })
+}
+
 /// Executes a webhook, posting a [`Message`] in the webhook's associated
 /// [`Channel`].
 ///
@@ -877,6 +1156,8 @@ pub fn execute_webhook(webhook_id: u64,
                        wait: bool,
                        map: &JsonMap)
                        -> Result<Option<Message>> {
+    check_message_length(map)?;
+
     let body = serde_json::to_vec(map)?;
 
     let mut headers = Headers::new();
@@ -899,6 +1180,28 @@ pub fn execute_webhook(webhook_id: u64,
         .map_err(From::from)
 }
 
+/// Follows a news channel, creating a webhook in the target channel that
+/// announcements published in the news channel are crossposted to.
+///
+/// Discord responds with an error if the channel to follow is not a news
+/// channel.
+///
+/// Requires the [Manage Webhooks] permission in the target channel.
+///
+/// [Manage Webhooks]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+pub fn follow_news_channel(news_channel_id: u64, target_channel_id: u64)
+    -> Result<FollowedChannel> {
+    let body = serde_json::to_vec(&json!({
+        "webhook_channel_id": target_channel_id.to_string(),
+    }))?;
+
+    fire(Request {
+        body: Some(&body),
+        headers: None,
+        route: RouteInfo::FollowNewsChannel { channel_id: news_channel_id },
+    })
+}
+
 /// Gets the active maintenances from Discord's Status API.
 ///
 /// Does not require authentication.
@@ -918,6 +1221,61 @@ pub fn get_active_maintenances() -> Result<Vec<Maintenance>> {
     }
 }
 
+/// Gets the active threads of a guild, including private threads.
+///
+/// The current user's membership of each thread, if they have joined it, is
+/// set as the thread's [`member`].
+///
+/// [`member`]: ../model/channel/struct.GuildThread.html#structfield.member
+pub fn get_active_threads(guild_id: u64) -> Result<Vec<GuildThread>> {
+    #[derive(Deserialize)]
+    struct ActiveThreads {
+        threads: Vec<GuildThread>,
+        #[serde(default)]
+        members: Vec<ThreadMember>,
+    }
+
+    let response = request(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetActiveThreads { guild_id },
+    })?;
+
+    let ActiveThreads { mut threads, members } =
+        serde_json::from_reader::<HyperResponse, ActiveThreads>(response)?;
+
+    // The memberships are sent separately, and are paired with their thread
+    // by its Id.
+    for member in members {
+        let thread = threads.iter_mut().find(|thread| Some(thread.id) == member.id);
+
+        if let Some(thread) = thread {
+            thread.member = Some(member);
+        }
+    }
+
+    Ok(threads)
+}
+
+/// Gets the ban of a user in a guild, if they are banned.
+///
+/// Returns `Ok(None)` if the user is not banned.
+pub fn get_ban(guild_id: u64, user_id: u64) -> Result<Option<Ban>> {
+    let result = request(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetBan { guild_id, user_id },
+    });
+
+    match result {
+        Ok(response) => serde_json::from_reader(response).map(Some).map_err(From::from),
+        // Discord responds with a 404 if the user is not banned.
+        Err(Error::Http(HttpError::UnsuccessfulRequest(ref response)))
+            if response.status == StatusCode::NotFound => Ok(None),
+        Err(why) => Err(why),
+    }
+}
+
 /// Gets all the users that are banned in specific guild.
 pub fn get_bans(guild_id: u64) -> Result<Vec<Ban>> {
     fire(Request {
@@ -946,7 +1304,31 @@ pub fn get_audit_logs(guild_id: u64,
     })
 }
 
-/// Gets current bot gateway.
+/// Gets the gateway for the current bot user, including the number of shards
+/// Discord recommends and the current [`SessionStartLimit`].
+///
+/// Unlike [`get_gateway`], this requires a bot token to be set.
+///
+/// # Examples
+///
+/// Determine how many shards to start, and whether there are enough session
+/// starts remaining to identify all of them:
+///
+/// ```rust,no_run
+/// use serenity::http;
+///
+/// let gateway = http::get_bot_gateway().expect("Error getting gateway");
+///
+/// if gateway.session_start_limit.remaining < gateway.shards {
+///     println!(
+///         "Too few session starts left; resets in {}ms",
+///         gateway.session_start_limit.reset_after,
+///     );
+/// }
+/// ```
+///
+/// [`SessionStartLimit`]: ../../model/gateway/struct.SessionStartLimit.html
+/// [`get_gateway`]: fn.get_gateway.html
 pub fn get_bot_gateway() -> Result<BotGateway> {
     fire(Request {
         body: None,
@@ -1028,6 +1410,34 @@ pub fn get_current_user() -> Result<CurrentUser> {
     })
 }
 
+/// Gets an emoji of a guild by Id.
+///
+/// The user who created the emoji is only provided if the current user has
+/// the [Manage Emojis] permission.
+///
+/// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+pub fn get_emoji(guild_id: u64, emoji_id: u64) -> Result<Emoji> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetEmoji { emoji_id, guild_id },
+    })
+}
+
+/// Gets all emojis of a guild.
+///
+/// The user who created each emoji is only provided if the current user has
+/// the [Manage Emojis] permission.
+///
+/// [Manage Emojis]: ../../model/permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+pub fn get_emojis(guild_id: u64) -> Result<Vec<Emoji>> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetEmojis { guild_id },
+    })
+}
+
 /// Gets current gateway.
 pub fn get_gateway() -> Result<Gateway> {
     fire(Request {
@@ -1073,30 +1483,58 @@ pub fn get_guild_invites(guild_id: u64) -> Result<Vec<RichInvite>> {
     })
 }
 
-/// Gets a guild's vanity URL if it has one.
-pub fn get_guild_vanity_url(guild_id: u64) -> Result<String> {
+/// Gets a guild's vanity invite code and its number of uses, if it has one.
+///
+/// Returns `None` if the guild does not have the vanity URL feature or has not
+/// set a vanity invite.
+pub fn get_guild_vanity_url(guild_id: u64) -> Result<Option<(String, u32)>> {
     #[derive(Deserialize)]
     struct GuildVanityUrl {
-        code: String,
+        code: Option<String>,
+        #[serde(default)]
+        uses: u32,
     }
 
-    let response = request(Request {
+    let response = match request(Request {
         body: None,
         headers: None,
         route: RouteInfo::GetGuildVanityUrl { guild_id },
-    })?;
+    }) {
+        Err(Error::Http(HttpError::UnsuccessfulRequest(ref response)))
+            if response.status == StatusCode::NotFound => {
+            return Ok(None);
+        },
+        other => other?,
+    };
 
     serde_json::from_reader::<HyperResponse, GuildVanityUrl>(response)
-        .map(|x| x.code)
+        .map(|GuildVanityUrl { code, uses }| code.map(|code| (code, uses)))
         .map_err(From::from)
 }
 
+/// Gets a guild's public widget.
+///
+/// This does not require authentication, but the guild must have its widget
+/// enabled.
+pub fn get_guild_widget(guild_id: u64) -> Result<GuildWidget> {
+    fire(Request {
+        body: None,
+        headers: None,
+        route: RouteInfo::GetGuildWidget { guild_id },
+    })
+}
+
 /// Gets the members of a guild. Optionally pass a `limit` and the Id of the
 /// user to offset the result by.
+///
+/// The `limit` is clamped to between 1 and 1000. To retrieve the next page of
+/// members, pass the highest user Id of the previous page as `after`.
 pub fn get_guild_members(guild_id: u64,
                          limit: Option<u64>,
                          after: Option<u64>)
                          -> Result<Vec<Member>> {
+    let limit = limit.map(|limit| limit.clamp(1, 1000));
+
     let response = request(Request {
         body: None,
         headers: None,
@@ -1118,23 +1556,13 @@ pub fn get_guild_members(guild_id: u64,
     serde_json::from_value::<Vec<Member>>(v).map_err(From::from)
 }
 
-/// Gets the amount of users that can be pruned.
-pub fn get_guild_prune_count(guild_id: u64, map: &Value) -> Result<GuildPrune> {
-    // Note for 0.6.x: turn this into a function parameter.
-    #[derive(Deserialize)]
-    struct GetGuildPruneCountRequest {
-        days: u64,
-    }
-
-    let req = serde_json::from_value::<GetGuildPruneCountRequest>(map.clone())?;
-
+/// Gets the amount of users that would be pruned for not having been seen in
+/// the given number of days, without removing them.
+pub fn get_guild_prune_count(guild_id: u64, days: u16) -> Result<GuildPrune> {
     fire(Request {
         body: None,
         headers: None,
-        route: RouteInfo::GetGuildPruneCount {
-            days: req.days,
-            guild_id,
-        },
+        route: RouteInfo::GetGuildPruneCount { days, guild_id },
     })
 }
 
@@ -1218,7 +1646,18 @@ pub fn get_guilds(target: &GuildPagination, limit: u64) -> Result<Vec<GuildInfo>
     })
 }
 
-/// Gets information about a specific invite.
+/// Gets information about a specific invite, optionally including the
+/// approximate member counts of its guild if `stats` is `true`.
+///
+/// This does not require authentication, so it can be used before a token is
+/// set.
+///
+/// # Errors
+///
+/// Returns an [`HttpError::UnknownInvite`] if the invite code does not exist
+/// or has expired.
+///
+/// [`HttpError::UnknownInvite`]: enum.HttpError.html#variant.UnknownInvite
 #[allow(unused_mut)]
 pub fn get_invite(mut code: &str, stats: bool) -> Result<Invite> {
     #[cfg(feature = "utils")]
@@ -1226,11 +1665,13 @@ pub fn get_invite(mut code: &str, stats: bool) -> Result<Invite> {
             code = crate::utils::parse_invite(code);
         }
 
-    fire(Request {
+    let response = map_unknown_invite(request(Request {
         body: None,
         headers: None,
         route: RouteInfo::GetInvite { code, stats },
-    })
+    }), code)?;
+
+    serde_json::from_reader::<HyperResponse, Invite>(response).map_err(From::from)
 }
 
 /// Gets member of a guild.
@@ -1287,7 +1728,7 @@ pub fn get_reaction_users(channel_id: u64,
                           limit: u8,
                           after: Option<u64>)
                           -> Result<Vec<User>> {
-    let reaction = reaction_type.as_data();
+    let reaction = encode_reaction(reaction_type);
 
     fire(Request {
         body: None,
@@ -1444,6 +1885,44 @@ pub fn leave_guild(guild_id: u64) -> Result<()> {
     })
 }
 
+/// Measures the latency of the REST API.
+///
+/// This times a request for the current user - as via [`get_current_user`] -
+/// from before it is sent until its response has been read, so it includes
+/// the round-trip to Discord and the time taken to process the request. As
+/// the request goes through the ratelimiter, the time spent waiting on a
+/// ratelimit is also included, although fetching the current user is rarely
+/// limited.
+///
+/// This is separate from the gateway latency provided by [`Shard::latency`],
+/// which times heartbeats over the WebSocket connection.
+///
+/// # Examples
+///
+/// Reply to a ping with the REST latency:
+///
+/// ```rust,no_run
+/// # use serenity::model::id::ChannelId;
+/// #
+/// # let channel_id = ChannelId(7);
+/// use serenity::http;
+///
+/// let latency = http::measure_latency().expect("Error measuring latency");
+/// let millis = latency.as_secs() * 1000 + u64::from(latency.subsec_millis());
+///
+/// let _ = channel_id.say(format!("Pong! REST API latency: {}ms", millis));
+/// ```
+///
+/// [`Shard::latency`]: ../gateway/struct.Shard.html#method.latency
+/// [`get_current_user`]: fn.get_current_user.html
+pub fn measure_latency() -> Result<Duration> {
+    let start = Instant::now();
+
+    get_current_user()?;
+
+    Ok(start.elapsed())
+}
+
 /// Deletes a user from group DM.
 pub fn remove_group_recipient(group_id: u64, user_id: u64) -> Result<()> {
     wind(204, Request {
@@ -1464,6 +1943,8 @@ pub fn remove_group_recipient(group_id: u64, user_id: u64) -> Result<()> {
 /// [`HttpError::InvalidRequest`]: enum.HttpError.html#variant.InvalidRequest
 pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, map: JsonMap) -> Result<Message>
     where T: Into<AttachmentType<'a>> {
+    check_message_length(&map)?;
+
     let uri = api!("/channels/{}/messages", channel_id);
     let url = match Url::parse(&uri) {
         Ok(url) => url,
@@ -1478,7 +1959,7 @@ pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, m
         .set(header::Authorization(TOKEN.lock().clone()));
     request
         .headers_mut()
-        .set(header::UserAgent(constants::USER_AGENT.to_string()));
+        .set(header::UserAgent(USER_AGENT.lock().clone()));
 
     let mut request = Multipart::from_request(request)?;
     let mut file_num = "0".to_string();
@@ -1486,16 +1967,32 @@ pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, m
     for file in files {
         match file.into() {
             AttachmentType::Bytes((mut bytes, filename)) => {
+                let content_type = guess_content_type(filename).parse::<Mime>().ok();
+
                 request
-                    .write_stream(&file_num, &mut bytes, Some(filename), None)?;
+                    .write_stream(&file_num, &mut bytes, Some(filename), content_type)?;
             },
             AttachmentType::File((mut f, filename)) => {
+                let content_type = guess_content_type(filename).parse::<Mime>().ok();
+
                 request
-                    .write_stream(&file_num, &mut f, Some(filename), None)?;
+                    .write_stream(&file_num, &mut f, Some(filename), content_type)?;
             },
             AttachmentType::Path(p) => {
                 request.write_file(&file_num, &p)?;
             },
+            AttachmentType::PathWithName((path, filename)) => {
+                let mut f = File::open(path)?;
+                let guess_from = if filename.contains('.') {
+                    filename
+                } else {
+                    path.file_name().and_then(|name| name.to_str()).unwrap_or(filename)
+                };
+                let content_type = guess_content_type(guess_from).parse::<Mime>().ok();
+
+                request
+                    .write_stream(&file_num, &mut f, Some(filename), content_type)?;
+            },
         }
 
         unsafe {
@@ -1525,7 +2022,20 @@ pub fn send_files<'a, T, It: IntoIterator<Item=T>>(channel_id: u64, files: It, m
 }
 
 /// Sends a message to a channel.
+///
+/// # Errors
+///
+/// Returns a [`ModelError::MessageTooLong`] if the content of the message is
+/// over 2000 unicode code points, or a [`ModelError::EmbedTooLarge`] if the
+/// embed is over 6000, containing the number of code points over the limit.
+///
+/// [`ModelError::EmbedTooLarge`]: ../../model/error/enum.Error.html#variant.EmbedTooLarge
+/// [`ModelError::MessageTooLong`]: ../../model/error/enum.Error.html#variant.MessageTooLong
 pub fn send_message(channel_id: u64, map: &Value) -> Result<Message> {
+    if let Some(map) = map.as_object() {
+        check_message_length(map)?;
+    }
+
     let body = serde_json::to_vec(map)?;
 
     fire(Request {
@@ -1536,12 +2046,33 @@ pub fn send_message(channel_id: u64, map: &Value) -> Result<Message> {
 }
 
 /// Pins a message in a channel.
+///
+/// # Errors
+///
+/// Returns a [`ModelError::PinLimitReached`] if the channel already holds the
+/// maximum number of pinned messages.
+///
+/// **Note**: To check for this, the body of a `400 Bad Request` response is
+/// read, so any other error with that status code is returned as an
+/// [`HttpError::UnsuccessfulRequestBody`] holding the body.
+///
+/// [`HttpError::UnsuccessfulRequestBody`]: enum.HttpError.html#variant.UnsuccessfulRequestBody
+/// [`ModelError::PinLimitReached`]: ../../model/error/enum.Error.html#variant.PinLimitReached
 pub fn pin_message(channel_id: u64, message_id: u64) -> Result<()> {
-    wind(204, Request {
+    let result = wind(204, Request {
         body: None,
         headers: None,
         route: RouteInfo::PinMessage { channel_id, message_id },
-    })
+    });
+
+    // Discord responds with the JSON error code 30003 when the maximum number
+    // of pins has been reached.
+    map_error_code(
+        result,
+        StatusCode::BadRequest,
+        30003,
+        Error::Model(ModelError::PinLimitReached),
+    )
 }
 
 /// Unbans a user from a guild.
@@ -1570,24 +2101,24 @@ pub fn remove_member_role(guild_id: u64, user_id: u64, role_id: u64) -> Result<(
     })
 }
 
-/// Starts removing some members from a guild based on the last time they've been online.
-pub fn start_guild_prune(guild_id: u64, map: &Value) -> Result<GuildPrune> {
-    // Note for 0.6.x: turn this into a function parameter.
+/// Starts removing the members of a guild that have not been seen in the given
+/// number of days.
+///
+/// If `compute_count` is `false`, Discord does not count the removed members,
+/// which is recommended for large guilds, and `None` is returned.
+pub fn start_guild_prune(guild_id: u64, days: u16, compute_count: bool) -> Result<Option<GuildPrune>> {
     #[derive(Deserialize)]
-    struct StartGuildPruneRequest {
-        days: u64,
+    struct StartGuildPruneResponse {
+        pruned: Option<u64>,
     }
 
-    let req = serde_json::from_value::<StartGuildPruneRequest>(map.clone())?;
-
-    fire(Request {
+    let response = fire::<StartGuildPruneResponse>(Request {
         body: None,
         headers: None,
-        route: RouteInfo::StartGuildPrune {
-            days: req.days,
-            guild_id,
-        },
-    })
+        route: RouteInfo::StartGuildPrune { compute_count, days, guild_id },
+    })?;
+
+    Ok(response.pruned.map(|pruned| GuildPrune { pruned }))
 }
 
 /// Starts syncing an integration with a guild.
@@ -1749,3 +2280,151 @@ pub(super) fn wind(expected: u16, req: Request) -> Result<()> {
 
     Err(Error::Http(HttpError::UnsuccessfulRequest(resp)))
 }
+
+/// Maps a `404 Not Found` response to a request for an invite to an
+/// [`HttpError::UnknownInvite`] for the code.
+///
+/// [`HttpError::UnknownInvite`]: enum.HttpError.html#variant.UnknownInvite
+pub(super) fn map_unknown_invite<T>(result: Result<T>, code: &str) -> Result<T> {
+    match result {
+        Err(Error::Http(HttpError::UnsuccessfulRequest(ref response)))
+            if response.status == StatusCode::NotFound => {
+            Err(Error::Http(HttpError::UnknownInvite(code.to_string())))
+        },
+        other => other,
+    }
+}
+
+/// Maps an unsuccessful response with the given status and Discord JSON error
+/// code to the given error.
+///
+/// The body of a response with the status is read to check its code, so when
+/// the code differs, an [`HttpError::UnsuccessfulRequestBody`] holding the
+/// body is returned instead.
+///
+/// [`HttpError::UnsuccessfulRequestBody`]: enum.HttpError.html#variant.UnsuccessfulRequestBody
+pub(crate) fn map_error_code<T>(result: Result<T>, status: StatusCode, code: u64, error: Error)
+    -> Result<T> {
+    match result {
+        Err(Error::Http(HttpError::UnsuccessfulRequest(mut response)))
+            if response.status == status => {
+            let mut body = Vec::new();
+            let _ = response.read_to_end(&mut body);
+
+            Err(error_for_code(status, &body, code, error))
+        },
+        other => other,
+    }
+}
+
+/// Returns the error if the JSON error code of an unsuccessful response's
+/// body is the given code.
+pub(super) fn error_for_code(status: StatusCode, body: &[u8], code: u64, error: Error) -> Error {
+    let body = serde_json::from_slice::<Value>(body)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(body).into_owned()));
+
+    if body.get("code").and_then(Value::as_u64) == Some(code) {
+        error
+    } else {
+        Error::Http(HttpError::UnsuccessfulRequestBody { status, body })
+    }
+}
+
+/// Checks that the content and embeds of a message payload are within
+/// Discord's limits, so that an oversized message errors locally rather than
+/// being rejected by the API.
+///
+/// Both the `embed` object of a channel message and the `embeds` array of a
+/// webhook execution are checked, including that their timestamps are in
+/// ISO-8601 format.
+pub(crate) fn check_message_length(map: &JsonMap) -> Result<()> {
+    if let Some(&Value::String(ref content)) = map.get("content") {
+        let count = content.chars().count() as u64;
+        let limit = u64::from(constants::MESSAGE_CODE_LIMIT);
+
+        if count > limit {
+            return Err(Error::Model(ModelError::MessageTooLong(count - limit)));
+        }
+    }
+
+    let mut total = 0;
+
+    if let Some(&Value::Object(ref embed)) = map.get("embed") {
+        check_embed_timestamp(embed)?;
+        total += embed_length(embed);
+    }
+
+    if let Some(&Value::Array(ref embeds)) = map.get("embeds") {
+        for embed in embeds {
+            if let Value::Object(ref embed) = *embed {
+                check_embed_timestamp(embed)?;
+                total += embed_length(embed);
+            }
+        }
+    }
+
+    let limit = u64::from(constants::EMBED_MAX_LENGTH);
+
+    if total > limit {
+        return Err(Error::Model(ModelError::EmbedTooLarge(total - limit)));
+    }
+
+    Ok(())
+}
+
+fn check_embed_timestamp(embed: &JsonMap) -> Result<()> {
+    match builder::check_timestamp(embed.get("timestamp")) {
+        Some(why) => Err(Error::Model(why)),
+        None => Ok(()),
+    }
+}
+
+/// Counts the unicode code points of the textual parts of an embed, as Discord
+/// does for its total embed length limit.
+fn embed_length(embed: &JsonMap) -> u64 {
+    fn count(value: Option<&Value>) -> u64 {
+        match value {
+            Some(&Value::String(ref text)) => text.chars().count() as u64,
+            _ => 0,
+        }
+    }
+
+    let mut total = count(embed.get("title")) + count(embed.get("description"));
+
+    if let Some(&Value::Object(ref author)) = embed.get("author") {
+        total += count(author.get("name"));
+    }
+
+    if let Some(&Value::Object(ref footer)) = embed.get("footer") {
+        total += count(footer.get("text"));
+    }
+
+    if let Some(&Value::Array(ref fields)) = embed.get("fields") {
+        for field in fields {
+            if let Value::Object(ref field) = *field {
+                total += count(field.get("name")) + count(field.get("value"));
+            }
+        }
+    }
+
+    total
+}
+
+/// Percent-encodes every byte of the value outside of the unreserved set of
+/// URI characters.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char);
+            },
+            _ => {
+                let _ = write!(encoded, "%{:02X}", byte);
+            },
+        }
+    }
+
+    encoded
+}
@@ -1,4 +1,3 @@
-use crate::constants;
 use hyper::{
     client::{Body, RequestBuilder as HyperRequestBuilder},
     header::{Authorization, ContentType, Headers, UserAgent},
@@ -6,6 +5,7 @@ use hyper::{
 use super::{
     CLIENT,
     TOKEN,
+    USER_AGENT,
     routing::RouteInfo,
 };
 
@@ -78,14 +78,11 @@ impl<'a> Request<'a> {
             builder = builder.body(Body::BufBody(bytes, bytes.len()));
         }
 
-        let mut headers = Headers::new();
-        headers.set(UserAgent(constants::USER_AGENT.to_string()));
-        headers.set(Authorization(TOKEN.lock().clone()));
-        headers.set(ContentType::json());
-
-        if let Some(request_headers) = request_headers.clone() {
-            headers.extend(request_headers.iter());
-        }
+        let headers = build_headers(
+            request_headers.as_ref(),
+            &TOKEN.lock(),
+            &USER_AGENT.lock(),
+        );
 
         builder.headers(headers)
     }
@@ -114,3 +111,46 @@ impl<'a> Request<'a> {
         &mut self.route
     }
 }
+
+/// Builds the headers sent with every request, followed by the request's own
+/// headers.
+fn build_headers(request_headers: Option<&Headers>, token: &str, user_agent: &str) -> Headers {
+    let mut headers = Headers::new();
+    headers.set(UserAgent(user_agent.to_string()));
+
+    // Requests to endpoints that need no authorization, such as retrieving an
+    // invite, can be made before a token is set.
+    if !token.is_empty() {
+        headers.set(Authorization(token.to_string()));
+    }
+
+    headers.set(ContentType::json());
+
+    if let Some(request_headers) = request_headers {
+        headers.extend(request_headers.iter());
+    }
+
+    headers
+}
+
+#[cfg(test)]
+mod test {
+    use hyper::header::{Authorization, Headers, UserAgent};
+    use super::build_headers;
+
+    #[test]
+    fn test_build_headers() {
+        let headers = build_headers(None, "Bot token", "agent");
+        assert_eq!(headers.get::<Authorization<String>>().map(|a| &a.0[..]), Some("Bot token"));
+        assert_eq!(headers.get::<UserAgent>().map(|a| &a.0[..]), Some("agent"));
+
+        // No authorization is sent without a token.
+        let headers = build_headers(None, "", "agent");
+        assert!(headers.get::<Authorization<String>>().is_none());
+
+        let mut request_headers = Headers::new();
+        request_headers.set_raw("X-Audit-Log-Reason", vec![b"cleanup".to_vec()]);
+        let headers = build_headers(Some(&request_headers), "", "agent");
+        assert_eq!(headers.get_raw("X-Audit-Log-Reason"), Some(&[b"cleanup".to_vec()][..]));
+    }
+}
@@ -17,6 +17,12 @@ pub enum Route {
     ///
     /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
     ChannelsId(u64),
+    /// Route for the `/channels/:channel_id/followers` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    ChannelsIdFollowers(u64),
     /// Route for the `/channels/:channel_id/invites` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -233,14 +239,34 @@ pub enum Route {
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdVanityUrl(u64),
+    /// Route for the `/guilds/:guild_id/threads/active` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdThreadsActive(u64),
     /// Route for the `/guilds/:guild_id/webhooks` path.
     ///
     /// The data is the relevant [`GuildId`].
     ///
     /// [`GuildId`]: ../../model/id/struct.GuildId.html
     GuildsIdWebhooks(u64),
+    /// Route for the `/guilds/:guild_id/widget.json` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: ../../model/id/struct.GuildId.html
+    GuildsIdWidgetJson(u64),
     /// Route for the `/invites/:code` path.
     InvitesCode,
+    /// Route for the `/stage-instances` path.
+    StageInstances,
+    /// Route for the `/stage-instances/:channel_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: ../../model/id/struct.ChannelId.html
+    StageInstancesId(u64),
     /// Route for the `/users/:user_id` path.
     UsersId,
     /// Route for the `/users/@me` path.
@@ -264,10 +290,133 @@ pub enum Route {
 }
 
 impl Route {
+    /// Resolves the ratelimit bucket that a request with the given method to
+    /// the given path falls under.
+    ///
+    /// The path may either be absolute - as returned by the path-building
+    /// functions on this type - or relative to the API base, such as
+    /// `/channels/7/messages`. Any query string is ignored.
+    ///
+    /// Paths that are not recognised, as well as those that are not
+    /// ratelimited, resolve to [`Route::None`].
+    ///
+    /// # Examples
+    ///
+    /// Assert that editing two messages in the same channel counts towards the
+    /// same bucket:
+    ///
+    /// ```rust
+    /// use serenity::http::{LightMethod, routing::Route};
+    ///
+    /// let first = Route::from_path(LightMethod::Patch, "/channels/7/messages/1");
+    /// let second = Route::from_path(LightMethod::Patch, "/channels/7/messages/2");
+    ///
+    /// assert_eq!(first, second);
+    /// assert_eq!(first, Route::ChannelsIdMessagesId(LightMethod::Patch, 7));
+    /// ```
+    ///
+    /// [`Route::None`]: #variant.None
+    pub fn from_path(method: LightMethod, path: &str) -> Route {
+        let path = path.trim_start_matches(api!(""));
+        let path = path.split('?').next().unwrap_or("");
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+
+        // Parse the major parameter, if any, as the route's data.
+        let id = match segments.get(1).map(|id| id.parse::<u64>()) {
+            Some(Ok(id)) => id,
+            Some(Err(_)) | None => 0,
+        };
+
+        match segments[..] {
+            ["channels", _] => Route::ChannelsId(id),
+            ["channels", _, "followers"] => Route::ChannelsIdFollowers(id),
+            ["channels", _, "invites"] => Route::ChannelsIdInvites(id),
+            ["channels", _, "messages"] => Route::ChannelsIdMessages(id),
+            ["channels", _, "messages", "bulk-delete"] => {
+                Route::ChannelsIdMessagesBulkDelete(id)
+            },
+            ["channels", _, "messages", _] => {
+                Route::ChannelsIdMessagesId(method, id)
+            },
+            ["channels", _, "messages", _, "ack"] => {
+                Route::ChannelsIdMessagesIdAck(id)
+            },
+            ["channels", _, "messages", _, "reactions"]
+            | ["channels", _, "messages", _, "reactions", _] => {
+                Route::ChannelsIdMessagesIdReactions(id)
+            },
+            ["channels", _, "messages", _, "reactions", _, _] => {
+                Route::ChannelsIdMessagesIdReactionsUserIdType(id)
+            },
+            ["channels", _, "permissions", _] => {
+                Route::ChannelsIdPermissionsOverwriteId(id)
+            },
+            ["channels", _, "pins"] => Route::ChannelsIdPins(id),
+            ["channels", _, "pins", _] => Route::ChannelsIdPinsMessageId(id),
+            ["channels", _, "typing"] => Route::ChannelsIdTyping(id),
+            ["channels", _, "webhooks"] => Route::ChannelsIdWebhooks(id),
+            ["gateway"] => Route::Gateway,
+            ["gateway", "bot"] => Route::GatewayBot,
+            ["guilds"] => Route::Guilds,
+            ["guilds", _] => Route::GuildsId(id),
+            ["guilds", _, "audit-logs"] => Route::GuildsIdAuditLogs(id),
+            ["guilds", _, "bans"] => Route::GuildsIdBans(id),
+            ["guilds", _, "bans", _] => Route::GuildsIdBansUserId(id),
+            ["guilds", _, "channels"] => Route::GuildsIdChannels(id),
+            ["guilds", _, "embed"] => Route::GuildsIdEmbed(id),
+            ["guilds", _, "emojis"] => Route::GuildsIdEmojis(id),
+            ["guilds", _, "emojis", _] => Route::GuildsIdEmojisId(id),
+            ["guilds", _, "integrations"] => Route::GuildsIdIntegrations(id),
+            ["guilds", _, "integrations", _] => {
+                Route::GuildsIdIntegrationsId(id)
+            },
+            ["guilds", _, "integrations", _, "sync"] => {
+                Route::GuildsIdIntegrationsIdSync(id)
+            },
+            ["guilds", _, "invites"] => Route::GuildsIdInvites(id),
+            ["guilds", _, "members"] => Route::GuildsIdMembers(id),
+            ["guilds", _, "members", "@me", "nick"] => {
+                Route::GuildsIdMembersMeNick(id)
+            },
+            ["guilds", _, "members", _] => Route::GuildsIdMembersId(id),
+            ["guilds", _, "members", _, "roles", _] => {
+                Route::GuildsIdMembersIdRolesId(id)
+            },
+            ["guilds", _, "prune"] => Route::GuildsIdPrune(id),
+            ["guilds", _, "regions"] => Route::GuildsIdRegions(id),
+            ["guilds", _, "roles"] => Route::GuildsIdRoles(id),
+            ["guilds", _, "roles", _] => Route::GuildsIdRolesId(id),
+            ["guilds", _, "threads", "active"] => Route::GuildsIdThreadsActive(id),
+            ["guilds", _, "vanity-url"] => Route::GuildsIdVanityUrl(id),
+            ["guilds", _, "webhooks"] => Route::GuildsIdWebhooks(id),
+            ["guilds", _, "widget.json"] => Route::GuildsIdWidgetJson(id),
+            ["invites", _] => Route::InvitesCode,
+            ["stage-instances"] => Route::StageInstances,
+            ["stage-instances", _] => Route::StageInstancesId(id),
+            ["users", "@me"] => Route::UsersMe,
+            ["users", "@me", "channels"] => Route::UsersMeChannels,
+            ["users", "@me", "guilds"] => Route::UsersMeGuilds,
+            ["users", "@me", "guilds", _] => Route::UsersMeGuildsId,
+            ["users", _] => Route::UsersId,
+            ["voice", "regions"] => Route::VoiceRegions,
+            ["webhooks", _]
+            | ["webhooks", _, _]
+            | ["webhooks", _, _, "messages", _] => Route::WebhooksId(id),
+            _ => Route::None,
+        }
+    }
+
     pub fn channel(channel_id: u64) -> String {
         format!(api!("/channels/{}"), channel_id)
     }
 
+    pub fn channel_followers(channel_id: u64) -> String {
+        format!(api!("/channels/{}/followers"), channel_id)
+    }
+
     pub fn channel_invites(channel_id: u64) -> String {
         format!(api!("/channels/{}/invites"), channel_id)
     }
@@ -298,6 +447,19 @@ impl Route {
         api!("/channels/{}/messages/{}/reactions", channel_id, message_id)
     }
 
+    pub fn channel_message_reactions_emoji(
+        channel_id: u64,
+        message_id: u64,
+        reaction: &str,
+    ) -> String {
+        api!(
+            "/channels/{}/messages/{}/reactions/{}",
+            channel_id,
+            message_id,
+            reaction,
+        )
+    }
+
     pub fn channel_message_reactions_list(
         channel_id: u64,
         message_id: u64,
@@ -507,10 +669,19 @@ impl Route {
         format!(api!("/guilds/{}/members/@me/nick"), guild_id)
     }
 
-    pub fn guild_prune(guild_id: u64, days: u64) -> String {
+    pub fn guild_prune(guild_id: u64, days: u16) -> String {
         format!(api!("/guilds/{}/prune?days={}"), guild_id, days)
     }
 
+    pub fn guild_prune_start(guild_id: u64, days: u16, compute_count: bool) -> String {
+        format!(
+            api!("/guilds/{}/prune?days={}&compute_prune_count={}"),
+            guild_id,
+            days,
+            compute_count,
+        )
+    }
+
     pub fn guild_regions(guild_id: u64) -> String {
         format!(api!("/guilds/{}/regions"), guild_id)
     }
@@ -523,6 +694,10 @@ impl Route {
         format!(api!("/guilds/{}/roles"), guild_id)
     }
 
+    pub fn guild_threads_active(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/threads/active"), guild_id)
+    }
+
     pub fn guild_vanity_url(guild_id: u64) -> String {
         format!(api!("/guilds/{}/vanity-url"), guild_id)
     }
@@ -531,6 +706,10 @@ impl Route {
         format!(api!("/guilds/{}/webhooks"), guild_id)
     }
 
+    pub fn guild_widget(guild_id: u64) -> String {
+        format!(api!("/guilds/{}/widget.json"), guild_id)
+    }
+
     pub fn guilds() -> &'static str {
         api!("/guilds")
     }
@@ -551,6 +730,14 @@ impl Route {
         api!("/users/@me/channels")
     }
 
+    pub fn stage_instance(channel_id: u64) -> String {
+        format!(api!("/stage-instances/{}"), channel_id)
+    }
+
+    pub fn stage_instances() -> &'static str {
+        api!("/stage-instances")
+    }
+
     pub fn status_incidents_unresolved() -> &'static str {
         status!("/incidents/unresolved.json")
     }
@@ -611,6 +798,16 @@ impl Route {
         format!(api!("/webhooks/{}/{}"), webhook_id, token)
     }
 
+    pub fn webhook_message<D>(webhook_id: u64, token: D, message_id: u64)
+        -> String where D: Display {
+        format!(
+            api!("/webhooks/{}/{}/messages/{}"),
+            webhook_id,
+            token,
+            message_id,
+        )
+    }
+
     pub fn webhook_with_token_optioned<D>(webhook_id: u64, token: D, wait: bool)
         -> String where D: Display {
         format!(api!("/webhooks/{}/{}?wait={}"), webhook_id, token, wait)
@@ -667,6 +864,7 @@ pub enum RouteInfo<'a> {
     CreateRole {
         guild_id: u64,
     },
+    CreateStageInstance,
     CreateWebhook {
         channel_id: u64,
     },
@@ -698,6 +896,11 @@ pub enum RouteInfo<'a> {
         channel_id: u64,
         message_id: u64,
     },
+    DeleteMessageReactionEmoji {
+        channel_id: u64,
+        message_id: u64,
+        reaction: &'a str,
+    },
     DeletePermission {
         channel_id: u64,
         target_id: u64,
@@ -712,9 +915,17 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         role_id: u64,
     },
+    DeleteStageInstance {
+        channel_id: u64,
+    },
     DeleteWebhook {
         webhook_id: u64,
     },
+    DeleteWebhookMessage {
+        message_id: u64,
+        token: &'a str,
+        webhook_id: u64,
+    },
     DeleteWebhookWithToken {
         token: &'a str,
         webhook_id: u64,
@@ -751,9 +962,17 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         role_id: u64,
     },
+    EditStageInstance {
+        channel_id: u64,
+    },
     EditWebhook {
         webhook_id: u64,
     },
+    EditWebhookMessage {
+        message_id: u64,
+        token: &'a str,
+        webhook_id: u64,
+    },
     EditWebhookWithToken {
         token: &'a str,
         webhook_id: u64,
@@ -763,7 +982,13 @@ pub enum RouteInfo<'a> {
         wait: bool,
         webhook_id: u64,
     },
+    FollowNewsChannel {
+        channel_id: u64,
+    },
     GetActiveMaintenance,
+    GetActiveThreads {
+        guild_id: u64,
+    },
     GetAuditLogs {
         action_type: Option<u8>,
         before: Option<u64>,
@@ -771,6 +996,10 @@ pub enum RouteInfo<'a> {
         limit: Option<u8>,
         user_id: Option<u64>,
     },
+    GetBan {
+        guild_id: u64,
+        user_id: u64,
+    },
     GetBans {
         guild_id: u64,
     },
@@ -789,6 +1018,13 @@ pub enum RouteInfo<'a> {
     },
     GetCurrentApplicationInfo,
     GetCurrentUser,
+    GetEmoji {
+        emoji_id: u64,
+        guild_id: u64,
+    },
+    GetEmojis {
+        guild_id: u64,
+    },
     GetGateway,
     GetGuild {
         guild_id: u64,
@@ -808,7 +1044,7 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
     },
     GetGuildPruneCount {
-        days: u64,
+        days: u16,
         guild_id: u64,
     },
     GetGuildRegions {
@@ -823,6 +1059,9 @@ pub enum RouteInfo<'a> {
     GetGuildWebhooks {
         guild_id: u64,
     },
+    GetGuildWidget {
+        guild_id: u64,
+    },
     GetGuilds {
         after: Option<u64>,
         before: Option<u64>,
@@ -896,7 +1135,8 @@ pub enum RouteInfo<'a> {
         user_id: u64,
     },
     StartGuildPrune {
-        days: u64,
+        compute_count: bool,
+        days: u16,
         guild_id: u64,
     },
     StartIntegrationSync {
@@ -1001,6 +1241,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRoles(guild_id),
                 Cow::from(Route::guild_roles(guild_id)),
             ),
+            RouteInfo::CreateStageInstance => (
+                LightMethod::Post,
+                Route::StageInstances,
+                Cow::from(Route::stage_instances()),
+            ),
             RouteInfo::CreateWebhook { channel_id } => (
                 LightMethod::Post,
                 Route::ChannelsIdWebhooks(channel_id),
@@ -1039,9 +1284,22 @@ impl<'a> RouteInfo<'a> {
                     message_id,
                 )),
             ),
+            RouteInfo::DeleteMessageReactionEmoji {
+                channel_id,
+                message_id,
+                reaction,
+            } => (
+                LightMethod::Delete,
+                Route::ChannelsIdMessagesIdReactions(channel_id),
+                Cow::from(Route::channel_message_reactions_emoji(
+                    channel_id,
+                    message_id,
+                    reaction,
+                )),
+            ),
             RouteInfo::DeleteMessage { channel_id, message_id } => (
                 LightMethod::Delete,
-                Route::ChannelsIdMessagesId(LightMethod::Delete, message_id),
+                Route::ChannelsIdMessagesId(LightMethod::Delete, channel_id),
                 Cow::from(Route::channel_message(channel_id, message_id)),
             ),
             RouteInfo::DeleteMessages { channel_id } => (
@@ -1074,11 +1332,25 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            RouteInfo::DeleteStageInstance { channel_id } => (
+                LightMethod::Delete,
+                Route::StageInstancesId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
             RouteInfo::DeleteWebhook { webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook(webhook_id)),
             ),
+            RouteInfo::DeleteWebhookMessage {
+                message_id,
+                token,
+                webhook_id,
+            } => (
+                LightMethod::Delete,
+                Route::WebhooksId(webhook_id),
+                Cow::from(Route::webhook_message(webhook_id, token, message_id)),
+            ),
             RouteInfo::DeleteWebhookWithToken { token, webhook_id } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
@@ -1134,11 +1406,25 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdRolesId(guild_id),
                 Cow::from(Route::guild_role(guild_id, role_id)),
             ),
+            RouteInfo::EditStageInstance { channel_id } => (
+                LightMethod::Patch,
+                Route::StageInstancesId(channel_id),
+                Cow::from(Route::stage_instance(channel_id)),
+            ),
             RouteInfo::EditWebhook { webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
                 Cow::from(Route::webhook(webhook_id)),
             ),
+            RouteInfo::EditWebhookMessage {
+                message_id,
+                token,
+                webhook_id,
+            } => (
+                LightMethod::Patch,
+                Route::WebhooksId(webhook_id),
+                Cow::from(Route::webhook_message(webhook_id, token, message_id)),
+            ),
             RouteInfo::EditWebhookWithToken { token, webhook_id } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
@@ -1153,11 +1439,21 @@ impl<'a> RouteInfo<'a> {
                     wait,
                 )),
             ),
+            RouteInfo::FollowNewsChannel { channel_id } => (
+                LightMethod::Post,
+                Route::ChannelsIdFollowers(channel_id),
+                Cow::from(Route::channel_followers(channel_id)),
+            ),
             RouteInfo::GetActiveMaintenance => (
                 LightMethod::Get,
                 Route::None,
                 Cow::from(Route::status_maintenances_active()),
             ),
+            RouteInfo::GetActiveThreads { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdThreadsActive(guild_id),
+                Cow::from(Route::guild_threads_active(guild_id)),
+            ),
             RouteInfo::GetAuditLogs {
                 action_type,
                 before,
@@ -1175,6 +1471,11 @@ impl<'a> RouteInfo<'a> {
                     limit,
                 )),
             ),
+            RouteInfo::GetBan { guild_id, user_id } => (
+                LightMethod::Get,
+                Route::GuildsIdBansUserId(guild_id),
+                Cow::from(Route::guild_ban(guild_id, user_id)),
+            ),
             RouteInfo::GetBans { guild_id } => (
                 LightMethod::Get,
                 Route::GuildsIdBans(guild_id),
@@ -1215,6 +1516,16 @@ impl<'a> RouteInfo<'a> {
                 Route::UsersMe,
                 Cow::from(Route::user("@me")),
             ),
+            RouteInfo::GetEmoji { emoji_id, guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdEmojisId(guild_id),
+                Cow::from(Route::guild_emoji(guild_id, emoji_id)),
+            ),
+            RouteInfo::GetEmojis { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdEmojis(guild_id),
+                Cow::from(Route::guild_emojis(guild_id)),
+            ),
             RouteInfo::GetGateway => (
                 LightMethod::Get,
                 Route::Gateway,
@@ -1270,6 +1581,11 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdWebhooks(guild_id),
                 Cow::from(Route::guild_webhooks(guild_id)),
             ),
+            RouteInfo::GetGuildWidget { guild_id } => (
+                LightMethod::Get,
+                Route::GuildsIdWidgetJson(guild_id),
+                Cow::from(Route::guild_widget(guild_id)),
+            ),
             RouteInfo::GetGuilds { after, before, limit } => (
                 LightMethod::Get,
                 Route::UsersMeGuilds,
@@ -1382,7 +1698,7 @@ impl<'a> RouteInfo<'a> {
             ),
             RouteInfo::PinMessage { channel_id, message_id } => (
                 LightMethod::Put,
-                Route::ChannelsIdPins(channel_id),
+                Route::ChannelsIdPinsMessageId(channel_id),
                 Cow::from(Route::channel_pin(channel_id, message_id)),
             ),
             RouteInfo::RemoveBan { guild_id, user_id } => (
@@ -1395,14 +1711,14 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
-            RouteInfo::StartGuildPrune { days, guild_id } => (
+            RouteInfo::StartGuildPrune { compute_count, days, guild_id } => (
                 LightMethod::Post,
                 Route::GuildsIdPrune(guild_id),
-                Cow::from(Route::guild_prune(guild_id, days)),
+                Cow::from(Route::guild_prune_start(guild_id, days, compute_count)),
             ),
             RouteInfo::StartIntegrationSync { guild_id, integration_id } => (
                 LightMethod::Post,
-                Route::GuildsIdIntegrationsId(guild_id),
+                Route::GuildsIdIntegrationsIdSync(guild_id),
                 Cow::from(Route::guild_integration_sync(
                     guild_id,
                     integration_id,
@@ -1431,3 +1747,109 @@ impl<'a> RouteInfo<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{LightMethod, Route, RouteInfo};
+
+    fn assert_resolves(info: RouteInfo) {
+        let (method, route, path) = info.deconstruct();
+
+        assert_eq!(Route::from_path(method, &path), route, "{:?}", info);
+    }
+
+    #[test]
+    fn test_from_path_matches_deconstruct() {
+        assert_resolves(RouteInfo::BroadcastTyping { channel_id: 1 });
+        assert_resolves(RouteInfo::CreateReaction {
+            channel_id: 1,
+            message_id: 2,
+            reaction: "%F0%9F%91%8D",
+        });
+        assert_resolves(RouteInfo::CreateStageInstance);
+        assert_resolves(RouteInfo::DeleteMessage { channel_id: 1, message_id: 2 });
+        assert_resolves(RouteInfo::DeleteMessages { channel_id: 1 });
+        assert_resolves(RouteInfo::DeleteStageInstance { channel_id: 1 });
+        assert_resolves(RouteInfo::EditNickname { guild_id: 1 });
+        assert_resolves(RouteInfo::EditStageInstance { channel_id: 1 });
+        assert_resolves(RouteInfo::EditWebhookMessage {
+            message_id: 2,
+            token: "token",
+            webhook_id: 1,
+        });
+        assert_resolves(RouteInfo::ExecuteWebhook {
+            token: "token",
+            wait: true,
+            webhook_id: 1,
+        });
+        assert_resolves(RouteInfo::FollowNewsChannel { channel_id: 1 });
+        assert_resolves(RouteInfo::GetActiveThreads { guild_id: 1 });
+        assert_resolves(RouteInfo::GetAuditLogs {
+            action_type: None,
+            before: None,
+            guild_id: 1,
+            limit: Some(50),
+            user_id: None,
+        });
+        assert_resolves(RouteInfo::GetCurrentUser);
+        assert_resolves(RouteInfo::GetGuildMembers {
+            after: Some(2),
+            guild_id: 1,
+            limit: Some(100),
+        });
+        assert_resolves(RouteInfo::GetGuildVanityUrl { guild_id: 1 });
+        assert_resolves(RouteInfo::GetGuildWidget { guild_id: 1 });
+        assert_resolves(RouteInfo::GetUnresolvedIncidents);
+        assert_resolves(RouteInfo::LeaveGuild { guild_id: 1 });
+        assert_resolves(RouteInfo::PinMessage { channel_id: 1, message_id: 2 });
+        assert_resolves(RouteInfo::RemoveGroupRecipient { group_id: 1, user_id: 2 });
+        assert_resolves(RouteInfo::StartIntegrationSync {
+            guild_id: 1,
+            integration_id: 2,
+        });
+        assert_resolves(RouteInfo::UnpinMessage { channel_id: 1, message_id: 2 });
+    }
+
+    #[test]
+    fn test_buckets() {
+        let route = |info: RouteInfo| info.deconstruct().1;
+
+        // Message deletions are limited per channel, not per message.
+        assert_eq!(
+            route(RouteInfo::DeleteMessage { channel_id: 1, message_id: 2 }),
+            route(RouteInfo::DeleteMessage { channel_id: 1, message_id: 3 }),
+        );
+        assert_ne!(
+            route(RouteInfo::DeleteMessage { channel_id: 1, message_id: 2 }),
+            route(RouteInfo::DeleteMessage { channel_id: 4, message_id: 2 }),
+        );
+
+        // Pinning shares its bucket with unpinning, not with getting the pins.
+        assert_eq!(
+            route(RouteInfo::PinMessage { channel_id: 1, message_id: 2 }),
+            route(RouteInfo::UnpinMessage { channel_id: 1, message_id: 3 }),
+        );
+        assert_ne!(
+            route(RouteInfo::PinMessage { channel_id: 1, message_id: 2 }),
+            route(RouteInfo::GetPins { channel_id: 1 }),
+        );
+
+        assert_ne!(
+            route(RouteInfo::StartIntegrationSync { guild_id: 1, integration_id: 2 }),
+            route(RouteInfo::DeleteGuildIntegration { guild_id: 1, integration_id: 2 }),
+        );
+    }
+
+    #[test]
+    fn test_from_path_major_parameters() {
+        let first = Route::from_path(LightMethod::Delete, "/channels/1/messages/2");
+        let second = Route::from_path(LightMethod::Delete, "/channels/1/messages/3");
+        let other = Route::from_path(LightMethod::Delete, "/channels/4/messages/2");
+        let edit = Route::from_path(LightMethod::Patch, "/channels/1/messages/2");
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_ne!(first, edit);
+        assert_eq!(Route::from_path(LightMethod::Get, "/unknown/1"), Route::None);
+    }
+}
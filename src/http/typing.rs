@@ -0,0 +1,81 @@
+//! A guard which keeps a typing indicator active in a channel.
+
+use crate::internal::prelude::*;
+use std::{
+    sync::mpsc::{self, RecvTimeoutError, Sender},
+    thread,
+    time::Duration,
+};
+use super::raw;
+
+/// A guard which broadcasts that the current user is typing in a channel until
+/// it is dropped or [`stop`]ped.
+///
+/// A single typing indicator only lasts for about 10 seconds, so it is
+/// re-triggered in the background every 8 seconds.
+///
+/// This is created by [`ChannelId::start_typing`].
+///
+/// # Examples
+///
+/// Show that the bot is typing while running a long command:
+///
+/// ```rust,no_run
+/// # use serenity::model::id::ChannelId;
+/// # use std::error::Error;
+/// #
+/// # fn try_main() -> Result<(), Box<Error>> {
+/// let channel_id = ChannelId(81384788765712384);
+/// let _typing = channel_id.start_typing()?;
+///
+/// // some long-running work
+///
+/// channel_id.say("Done!")?;
+/// #     Ok(())
+/// # }
+/// #
+/// # fn main() {
+/// #     try_main().unwrap();
+/// # }
+/// ```
+///
+/// [`ChannelId::start_typing`]: ../../model/id/struct.ChannelId.html#method.start_typing
+/// [`stop`]: #method.stop
+#[derive(Debug)]
+pub struct Typing(Sender<()>);
+
+impl Typing {
+    /// The interval at which the typing indicator is re-triggered.
+    const INTERVAL: Duration = Duration::from_secs(8);
+
+    /// Starts broadcasting that the current user is typing in the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the first typing indicator could not be sent, in
+    /// which case no background thread is started.
+    pub fn start(channel_id: u64) -> Result<Self> {
+        raw::broadcast_typing(channel_id)?;
+
+        let (tx, rx) = mpsc::channel();
+
+        // The loop ends once either `stop` is called or the guard is dropped.
+        thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = rx.recv_timeout(Self::INTERVAL) {
+                if let Err(why) = raw::broadcast_typing(channel_id) {
+                    warn!("Error re-triggering typing in {}: {:?}", channel_id, why);
+                }
+            }
+        });
+
+        Ok(Typing(tx))
+    }
+
+    /// Stops broadcasting the typing indicator.
+    ///
+    /// This is equivalent to dropping the guard. The indicator may remain shown
+    /// for a few seconds, until Discord expires the last one sent.
+    pub fn stop(self) {
+        let _ = self.0.send(());
+    }
+}
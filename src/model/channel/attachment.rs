@@ -4,6 +4,8 @@ use hyper::Client as HyperClient;
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use std::io::Read;
+#[cfg(feature = "model")]
+use std::thread::{self, JoinHandle};
 
 /// A file uploaded with a message. Not to be confused with [`Embed`]s.
 ///
@@ -38,6 +40,11 @@ impl Attachment {
 
     /// Downloads the attachment, returning back a vector of bytes.
     ///
+    /// **Note**: This blocks the current thread until the download has
+    /// completed. When downloading from within an [`EventHandler`], where this
+    /// would stall the processing of further events, consider using
+    /// [`download_spawn`] instead.
+    ///
     /// # Examples
     ///
     /// Download all of the attachments associated with a [`Message`]:
@@ -107,14 +114,72 @@ impl Attachment {
     ///
     /// [`Error::Hyper`]: ../../enum.Error.html#variant.Hyper
     /// [`Error::Io`]: ../../enum.Error.html#variant.Io
+    /// [`EventHandler`]: ../../client/trait.EventHandler.html
     /// [`Message`]: struct.Message.html
+    /// [`download_spawn`]: #method.download_spawn
     pub fn download(&self) -> Result<Vec<u8>> {
-        let hyper = request_client!();
-        let mut response = hyper.get(&self.url).send()?;
+        download_url(&self.url)
+    }
 
-        let mut bytes = vec![];
-        response.read_to_end(&mut bytes)?;
+    /// Downloads the attachment on a new thread, returning a handle which can
+    /// be joined to retrieve the vector of bytes.
+    ///
+    /// Unlike [`download`], this does not block the current thread.
+    ///
+    /// # Examples
+    ///
+    /// Start downloading all of the attachments associated with a [`Message`],
+    /// and wait on them from a separate thread:
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "client")] {
+    /// use serenity::model::prelude::*;
+    /// use serenity::prelude::*;
+    /// use std::thread;
+    ///
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {
+    ///     fn message(&self, _: Context, message: Message) {
+    ///         let downloads = message.attachments
+    ///             .iter()
+    ///             .map(|attachment| (attachment.filename.clone(), attachment.download_spawn()))
+    ///             .collect::<Vec<_>>();
+    ///
+    ///         thread::spawn(move || {
+    ///             for (filename, download) in downloads {
+    ///                 match download.join() {
+    ///                     Ok(Ok(content)) => println!("{}: {} bytes", filename, content.len()),
+    ///                     Ok(Err(why)) => println!("Error downloading {}: {:?}", filename, why),
+    ///                     Err(_) => println!("Download of {} panicked", filename),
+    ///                 }
+    ///             }
+    ///         });
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The joined result contains the same errors as [`download`].
+    ///
+    /// [`Message`]: struct.Message.html
+    /// [`download`]: #method.download
+    pub fn download_spawn(&self) -> JoinHandle<Result<Vec<u8>>> {
+        let url = self.url.clone();
 
-        Ok(bytes)
+        thread::spawn(move || download_url(&url))
     }
 }
+
+#[cfg(feature = "model")]
+fn download_url(url: &str) -> Result<Vec<u8>> {
+    let hyper = request_client!();
+    let mut response = hyper.get(url).send()?;
+
+    let mut bytes = vec![];
+    response.read_to_end(&mut bytes)?;
+
+    Ok(bytes)
+}
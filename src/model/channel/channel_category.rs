@@ -1,7 +1,7 @@
 use crate::model::prelude::*;
 
 #[cfg(all(feature = "builder", feature = "model"))]
-use crate::builder::EditChannel;
+use crate::builder::{EditChannel, EditPermissionOverwrite};
 #[cfg(all(feature = "builder", feature = "model"))]
 use crate::http;
 #[cfg(all(feature = "model", feature = "utils"))]
@@ -45,6 +45,21 @@ impl ChannelCategory {
         self.id.create_permission(target)
     }
 
+    /// Sets a permission overwrite for the category's channels.
+    ///
+    /// Refer to [`ChannelId::edit_permission`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channel] permission.
+    ///
+    /// [`ChannelId::edit_permission`]: ../id/struct.ChannelId.html#method.edit_permission
+    /// [Manage Channel]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[cfg(all(feature = "builder", feature = "utils"))]
+    #[inline]
+    pub fn edit_permission<F>(&self, target: PermissionOverwriteType, f: F) -> Result<()>
+        where F: FnOnce(EditPermissionOverwrite) -> EditPermissionOverwrite {
+        self.id.edit_permission(target, f)
+    }
+
     /// Deletes all permission overrides in the category from the channels.
     ///
     /// **Note**: Requires the [Manage Channel] permission.
@@ -1,23 +1,31 @@
 use crate::internal::RwLockExt;
 use crate::model::prelude::*;
 
+#[cfg(feature = "model")]
+use chrono::{DateTime, Duration, Utc};
 #[cfg(feature = "model")]
 use std::borrow::Cow;
 #[cfg(feature = "model")]
 use std::fmt::Write as FmtWrite;
 #[cfg(feature = "model")]
 use crate::builder::{
+    CreateInvite,
     CreateMessage,
     EditChannel,
     EditMessage,
-    GetMessages
+    EditPermissionOverwrite,
+    EditStageInstance,
+    GetMessages,
+    MessageFile
 };
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::CACHE;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::Cache;
 #[cfg(feature = "model")]
-use crate::http::{self, AttachmentType};
+use crate::http::{self, AttachmentType, HttpError, Typing};
+#[cfg(feature = "model")]
+use hyper::status::StatusCode;
 #[cfg(feature = "model")]
 use crate::utils;
 
@@ -46,6 +54,87 @@ impl ChannelId {
     #[inline]
     pub fn broadcast_typing(&self) -> Result<()> { http::broadcast_typing(self.0) }
 
+    /// Broadcasts that the current user is typing to the channel until the
+    /// returned [`Typing`] guard is dropped.
+    ///
+    /// Unlike [`broadcast_typing`], the indicator is re-sent in the background
+    /// so that it does not expire while a long-running command is executed.
+    ///
+    /// **Note**: Requires the [Send Messages] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// let _typing = ChannelId(7).start_typing()?;
+    ///
+    /// // the indicator is shown until `_typing` goes out of scope
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [`Typing`]: ../../http/typing/struct.Typing.html
+    /// [`broadcast_typing`]: #method.broadcast_typing
+    /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
+    #[inline]
+    pub fn start_typing(&self) -> Result<Typing> { Typing::start(self.0) }
+
+    /// Creates an invite leading to the channel, without requiring the channel
+    /// to be retrieved first.
+    ///
+    /// Refer to the documentation for [`CreateInvite`] for the options that
+    /// can be set.
+    ///
+    /// **Note**: Requires the [Create Invite] permission.
+    ///
+    /// # Examples
+    ///
+    /// Create an invite that can only be used 5 times:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// #
+    /// let invite = ChannelId(81384788765712384).create_invite(|i| i.max_uses(5));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidChannelType`]
+    /// if the channel is cached and is a category, group, or private channel,
+    /// none of which support invites.
+    ///
+    /// [`CreateInvite`]: ../../builder/struct.CreateInvite.html
+    /// [`ModelError::InvalidChannelType`]: ../error/enum.Error.html#variant.InvalidChannelType
+    /// [Create Invite]: ../permissions/struct.Permissions.html#associatedconstant.CREATE_INVITE
+    #[cfg(feature = "utils")]
+    pub fn create_invite<F>(&self, f: F) -> Result<RichInvite>
+        where F: FnOnce(CreateInvite) -> CreateInvite {
+        #[cfg(feature = "cache")]
+        {
+            let kind = match CACHE.read().channel(*self) {
+                Some(Channel::Category(_)) => Some(ChannelType::Category),
+                Some(Channel::Group(_)) => Some(ChannelType::Group),
+                Some(Channel::Private(_)) => Some(ChannelType::Private),
+                Some(Channel::Guild(_)) | None => None,
+            };
+
+            if let Some(kind) = kind {
+                return Err(Error::Model(ModelError::InvalidChannelType(kind)));
+            }
+        }
+
+        let map = utils::vecmap_to_json_map(f(CreateInvite::default()).0);
+
+        http::create_invite(self.0, &map)
+    }
+
     /// Creates a [permission overwrite][`PermissionOverwrite`] for either a
     /// single [`Member`] or [`Role`] within the channel.
     ///
@@ -101,6 +190,46 @@ impl ChannelId {
         http::create_reaction(self.0, message_id.0, reaction_type)
     }
 
+    /// Starts a [`StageInstance`] in the stage channel, with the given topic.
+    ///
+    /// Only [stage channels][`ChannelType::Stage`] can hold a stage instance.
+    /// Use [`GuildChannel::create_stage_instance`] to check the type of the
+    /// channel before making the request.
+    ///
+    /// **Note**: Requires the [Manage Channels], [Mute Members], and
+    /// [Move Members] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`HttpError::UnsuccessfulRequest`] if the channel is not a
+    /// stage channel, or if a stage instance is already being held in it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::channel::StagePrivacyLevel;
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let stage = ChannelId(7)
+    ///     .create_stage_instance("Weekly talk", StagePrivacyLevel::GuildOnly)
+    ///     .unwrap();
+    ///
+    /// println!("Started stage {}", stage.id);
+    /// ```
+    ///
+    /// [`ChannelType::Stage`]: ../channel/enum.ChannelType.html#variant.Stage
+    /// [`GuildChannel::create_stage_instance`]: ../channel/struct.GuildChannel.html#method.create_stage_instance
+    /// [`HttpError::UnsuccessfulRequest`]: ../../http/enum.HttpError.html#variant.UnsuccessfulRequest
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    /// [Move Members]: ../permissions/struct.Permissions.html#associatedconstant.MOVE_MEMBERS
+    /// [Mute Members]: ../permissions/struct.Permissions.html#associatedconstant.MUTE_MEMBERS
+    #[inline]
+    pub fn create_stage_instance(&self, topic: &str, privacy_level: StagePrivacyLevel)
+        -> Result<StageInstance> {
+        http::create_stage_instance(self.0, topic, privacy_level)
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     #[inline]
     pub fn delete(&self) -> Result<Channel> { http::delete_channel(self.0) }
@@ -215,6 +344,17 @@ impl ChannelId {
         )
     }
 
+    /// Ends the [`StageInstance`] being held in the stage channel.
+    ///
+    /// **Note**: Requires the same permissions as [`create_stage_instance`].
+    ///
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    /// [`create_stage_instance`]: #method.create_stage_instance
+    #[inline]
+    pub fn delete_stage_instance(&self) -> Result<()> {
+        http::delete_stage_instance(self.0)
+    }
+
 
     /// Edits the settings of a [`Channel`], optionally setting new values.
     ///
@@ -285,6 +425,101 @@ impl ChannelId {
         http::edit_message(self.0, message_id.0, &Value::Object(map))
     }
 
+    /// Sets the [permission overwrite][`PermissionOverwrite`] for either a
+    /// single [`Member`] or [`Role`] within the channel, replacing any
+    /// existing overwrite for the target.
+    ///
+    /// Refer to [`EditPermissionOverwrite`]'s documentation for its methods.
+    /// To remove an overwrite, use [`delete_permission`].
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// Deny the @everyone role the ability to send messages, locking the
+    /// channel down:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::channel::PermissionOverwriteType;
+    /// use serenity::model::id::{ChannelId, GuildId, RoleId};
+    /// use serenity::model::permissions::Permissions;
+    ///
+    /// let guild_id = GuildId(81384788765712384);
+    /// let everyone = PermissionOverwriteType::Role(RoleId(guild_id.0));
+    ///
+    /// let _ = ChannelId(81384788765712384)
+    ///     .edit_permission(everyone, |p| p.deny(Permissions::SEND_MESSAGES));
+    /// ```
+    ///
+    /// [`EditPermissionOverwrite`]: ../../builder/struct.EditPermissionOverwrite.html
+    /// [`Member`]: ../guild/struct.Member.html
+    /// [`PermissionOverwrite`]: ../channel/struct.PermissionOverwrite.html
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [`delete_permission`]: #method.delete_permission
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[cfg(feature = "utils")]
+    pub fn edit_permission<F>(&self, target: PermissionOverwriteType, f: F) -> Result<()>
+        where F: FnOnce(EditPermissionOverwrite) -> EditPermissionOverwrite {
+        let (id, kind) = match target {
+            PermissionOverwriteType::Member(id) => (id.0, "member"),
+            PermissionOverwriteType::Role(id) => (id.0, "role"),
+        };
+
+        let mut map = utils::vecmap_to_json_map(f(EditPermissionOverwrite::default()).0);
+        map.insert("id".to_string(), Value::Number(Number::from(id)));
+        map.insert("type".to_string(), Value::String(kind.to_string()));
+
+        http::create_permission(self.0, id, &Value::Object(map))
+    }
+
+    /// Edits the [`StageInstance`] being held in the stage channel.
+    ///
+    /// Refer to [`EditStageInstance`]'s documentation for its methods.
+    ///
+    /// **Note**: Requires the same permissions as [`create_stage_instance`].
+    ///
+    /// [`EditStageInstance`]: ../../builder/struct.EditStageInstance.html
+    /// [`StageInstance`]: ../channel/struct.StageInstance.html
+    /// [`create_stage_instance`]: #method.create_stage_instance
+    #[cfg(feature = "utils")]
+    #[inline]
+    pub fn edit_stage_instance<F>(&self, f: F) -> Result<StageInstance>
+        where F: FnOnce(EditStageInstance) -> EditStageInstance {
+        let map = utils::vecmap_to_json_map(f(EditStageInstance::default()).0);
+
+        http::edit_stage_instance(self.0, &map)
+    }
+
+    /// Follows the news channel, crossposting its announcements to the target
+    /// channel via a newly created webhook.
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission in the target
+    /// channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`HttpError::UnsuccessfulRequest`] if the channel is not a
+    /// news channel, as Discord rejects the request.
+    ///
+    /// # Examples
+    ///
+    /// Crossposting the announcements of a news channel into another channel:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let followed = ChannelId(7).follow(ChannelId(8)).unwrap();
+    ///
+    /// println!("Announcements are posted by webhook {}", followed.webhook_id);
+    /// ```
+    ///
+    /// [`HttpError::UnsuccessfulRequest`]: ../../http/enum.HttpError.html#variant.UnsuccessfulRequest
+    /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    #[inline]
+    pub fn follow<C: Into<ChannelId>>(&self, target_channel_id: C) -> Result<FollowedChannel> {
+        http::follow_news_channel(self.0, target_channel_id.into().0)
+    }
+
     /// Search the cache for the channel with the Id.
     #[cfg(feature = "cache")]
     #[deprecated(since = "0.5.8", note = "Use the `to_channel_cached`-method instead.")]
@@ -341,10 +576,32 @@ impl ChannelId {
     #[inline]
     pub fn invites(&self) -> Result<Vec<RichInvite>> { http::get_channel_invites(self.0) }
 
-    /// Gets a message from the channel.
+    /// Gets a message from the channel by its Id, in a single request.
     ///
     /// Requires the [Read Message History] permission.
     ///
+    /// # Examples
+    ///
+    /// Fetch the message a reaction was added to:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Reaction;
+    /// #
+    /// # fn run(reaction: Reaction) {
+    /// if let Ok(message) = reaction.channel_id.message(reaction.message_id) {
+    ///     println!("Reacted to: {}", message.content);
+    /// }
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageNotFound`] if no message with the Id
+    /// exists in the channel, such as when it has been deleted.
+    ///
+    /// [`ModelError::MessageNotFound`]: ../error/enum.Error.html#variant.MessageNotFound
     /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
     #[inline]
     pub fn message<M: Into<MessageId>>(&self, message_id: M) -> Result<Message> {
@@ -352,11 +609,17 @@ impl ChannelId {
     }
 
     fn _message(self, message_id: MessageId) -> Result<Message> {
-        http::get_message(self.0, message_id.0).map(|mut msg| {
-            msg.transform_content();
+        let mut message = match http::get_message(self.0, message_id.0) {
+            Err(Error::Http(HttpError::UnsuccessfulRequest(ref response)))
+                if response.status == StatusCode::NotFound => {
+                return Err(Error::Model(ModelError::MessageNotFound(message_id)));
+            },
+            other => other?,
+        };
 
-            msg
-        })
+        message.transform_content();
+
+        Ok(message)
     }
 
     /// Gets messages from the channel.
@@ -391,6 +654,54 @@ impl ChannelId {
         })
     }
 
+    /// Iterates over all of the channel's messages, newest first, lazily
+    /// retrieving them from the REST API in pages of 100.
+    ///
+    /// Iteration ends once the start of the channel is reached. If a request
+    /// fails, the error is yielded and iteration ends.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// # Examples
+    ///
+    /// Counting the messages sent by bots in a channel:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// #
+    /// let channel_id = ChannelId(81384788765712384);
+    /// let mut count = 0;
+    ///
+    /// for message in channel_id.messages_iter() {
+    ///     match message {
+    ///         Ok(message) => if message.author.bot {
+    ///             count += 1;
+    ///         },
+    ///         Err(why) => {
+    ///             println!("Error retrieving messages: {:?}", why);
+    ///
+    ///             break;
+    ///         },
+    ///     }
+    /// }
+    ///
+    /// println!("{} messages by bots", count);
+    /// ```
+    ///
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub fn messages_iter(&self) -> MessagesIter {
+        let channel_id = *self;
+
+        Paginated::new(
+            100,
+            |page: &[Message]| page.iter().map(|message| message.id.0).min(),
+            Box::new(move |before| channel_id.messages(|g| match before {
+                Some(before) => g.before(before).limit(100),
+                None => g.limit(100),
+            })),
+        )
+    }
+
     /// Returns the name of whatever channel this id holds.
     #[cfg(feature = "model")]
     pub fn name(&self) -> Option<String> {
@@ -421,7 +732,13 @@ impl ChannelId {
 
     /// Pins a [`Message`] to the channel.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PinLimitReached`] if the channel already holds
+    /// the maximum number of pinned messages.
+    ///
     /// [`Message`]: ../channel/struct.Message.html
+    /// [`ModelError::PinLimitReached`]: ../error/enum.Error.html#variant.PinLimitReached
     #[inline]
     pub fn pin<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
         self._pin(message_id.into())
@@ -437,6 +754,64 @@ impl ChannelId {
     #[inline]
     pub fn pins(&self) -> Result<Vec<Message>> { http::get_pins(self.0) }
 
+    /// Deletes the messages matching a filter from among the channel's `limit`
+    /// most recent messages.
+    ///
+    /// Messages newer than 2 weeks are deleted in bulk, in chunks of 100,
+    /// while older messages - which can't be bulk deleted - are deleted one at
+    /// a time.
+    ///
+    /// Returns the number of messages deleted in bulk and individually, in
+    /// that order.
+    ///
+    /// Requires the [Manage Messages] and [Read Message History]
+    /// permissions.
+    ///
+    /// **Note**: Deleting old messages individually takes one request per
+    /// message, so this may be slow if many of them match.
+    ///
+    /// # Examples
+    ///
+    /// Delete the messages sent by bots among the last 100 messages:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// #
+    /// # let channel_id = ChannelId(81384788765712384);
+    /// #
+    /// let (bulk, individual) = channel_id.purge(100, |m| m.author.bot)
+    ///     .expect("Error purging");
+    ///
+    /// println!("Deleted {} messages", bulk + individual);
+    /// ```
+    ///
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    /// [Read Message History]: ../permissions/struct.Permissions.html#associatedconstant.READ_MESSAGE_HISTORY
+    pub fn purge<F>(&self, limit: usize, filter: F) -> Result<(usize, usize)>
+        where F: Fn(&Message) -> bool {
+        let mut ids = Vec::new();
+
+        for message in self.messages_iter().take(limit) {
+            let message = message?;
+
+            if filter(&message) {
+                ids.push(message.id);
+            }
+        }
+
+        let (bulk, individual) = split_bulk_deletable(ids, Utc::now());
+
+        for chunk in bulk.chunks(100) {
+            self._delete_messages(chunk)?;
+        }
+
+        for id in &individual {
+            self._delete_message(MessageId(*id))?;
+        }
+
+        Ok((bulk.len(), individual.len()))
+    }
+
     /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
     /// certain [`Emoji`].
     ///
@@ -502,7 +877,8 @@ impl ChannelId {
     /// be specified.
     ///
     /// Message contents may be passed by using the [`CreateMessage::content`]
-    /// method.
+    /// method. Files added via [`CreateMessage::add_file`] are uploaded after
+    /// the given files.
     ///
     /// The [Attach Files] and [Send Messages] permissions are required.
     ///
@@ -538,6 +914,19 @@ impl ChannelId {
     /// let _ = channel_id.send_files(files, |m| m.content("a file"));
     /// ```
     ///
+    /// Send the file at `/tmp/abc123` with the filename `report.pdf`:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::ChannelId;
+    /// use std::path::Path;
+    ///
+    /// let channel_id = ChannelId(7);
+    ///
+    /// let files = vec![(Path::new("/tmp/abc123"), "report.pdf")];
+    ///
+    /// let _ = channel_id.send_files(files, |m| m.content("a file"));
+    /// ```
+    ///
     /// # Errors
     ///
     /// If the content of the message is over the above limit, then a
@@ -550,6 +939,7 @@ impl ChannelId {
     ///
     /// [`ClientError::MessageTooLong`]: ../../client/enum.ClientError.html#variant.MessageTooLong
     /// [`HttpError::InvalidRequest`]: ../../http/enum.HttpError.html#variant.InvalidRequest
+    /// [`CreateMessage::add_file`]: ../../builder/struct.CreateMessage.html#method.add_file
     /// [`CreateMessage::content`]: ../../builder/struct.CreateMessage.html#method.content
     /// [`GuildChannel`]: struct.GuildChannel.html
     /// [Attach Files]: ../permissions/struct.Permissions.html#associatedconstant.ATTACH_FILES
@@ -572,7 +962,7 @@ impl ChannelId {
         }
 
         let map = utils::vecmap_to_json_map(msg.0);
-        http::send_files(self.0, files, map)
+        http::send_files(self.0, message_attachments(files, &msg.2), map)
     }
 
     /// Sends a message to the channel.
@@ -582,6 +972,10 @@ impl ChannelId {
     ///
     /// Requires the [Send Messages] permission.
     ///
+    /// If any files were added via [`CreateMessage::add_file`], then they are
+    /// uploaded in the same request as the rest of the message, which also
+    /// requires the [Attach Files] permission.
+    ///
     /// **Note**: Message contents must be under 2000 unicode code points.
     ///
     /// # Errors
@@ -590,9 +984,15 @@ impl ChannelId {
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns an [`HttpError::UnsuccessfulRequest`] if the files are over
+    /// Discord's upload limit.
+    ///
     /// [`Channel`]: ../channel/enum.Channel.html
+    /// [`CreateMessage::add_file`]: ../../builder/struct.CreateMessage.html#method.add_file
+    /// [`HttpError::UnsuccessfulRequest`]: ../../http/enum.HttpError.html#variant.UnsuccessfulRequest
     /// [`ModelError::MessageTooLong`]: ../error/enum.Error.html#variant.MessageTooLong
     /// [`CreateMessage`]: ../../builder/struct.CreateMessage.html
+    /// [Attach Files]: ../permissions/struct.Permissions.html#associatedconstant.ATTACH_FILES
     /// [Send Messages]: ../permissions/struct.Permissions.html#associatedconstant.SEND_MESSAGES
     #[cfg(feature = "utils")]
     pub fn send_message<F>(&self, f: F) -> Result<Message>
@@ -600,10 +1000,16 @@ impl ChannelId {
         let msg = f(CreateMessage::default());
         let map = utils::vecmap_to_json_map(msg.0);
 
-        Message::check_content_length(&map)?;
-        Message::check_embed_length(&map)?;
+        let message = if msg.2.is_empty() {
+            http::send_message(self.0, &Value::Object(map))?
+        } else {
+            http::check_message_length(&map)?;
+
+            let mut multipart = JsonMap::new();
+            multipart.insert("payload_json".to_string(), Value::Object(map));
 
-        let message = http::send_message(self.0, &Value::Object(map))?;
+            http::send_files(self.0, message_attachments(None::<AttachmentType>, &msg.2), multipart)?
+        };
 
         if let Some(reactions) = msg.1 {
             for reaction in reactions {
@@ -638,6 +1044,15 @@ impl ChannelId {
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { http::get_channel_webhooks(self.0) }
 }
 
+/// An iterator over all of a channel's messages, newest first, retrieving
+/// them from the REST API one page at a time.
+///
+/// This is created by [`ChannelId::messages_iter`].
+///
+/// [`ChannelId::messages_iter`]: ../id/struct.ChannelId.html#method.messages_iter
+#[cfg(feature = "model")]
+pub type MessagesIter = Paginated<Message>;
+
 impl From<Channel> for ChannelId {
     /// Gets the Id of a `Channel`.
     fn from(channel: Channel) -> ChannelId {
@@ -680,3 +1095,77 @@ impl<'a> From<&'a GuildChannel> for ChannelId {
     /// Gets the Id of a guild channel.
     fn from(public_channel: &GuildChannel) -> ChannelId { public_channel.id }
 }
+
+/// Combines the files given to [`ChannelId::send_files`] with those added to
+/// the message via [`CreateMessage::add_file`], in that order.
+///
+/// [`ChannelId::send_files`]: ../id/struct.ChannelId.html#method.send_files
+/// [`CreateMessage::add_file`]: ../../builder/struct.CreateMessage.html#method.add_file
+#[cfg(feature = "model")]
+fn message_attachments<'a: 'b, 'b, T, It>(files: It, added: &'b [MessageFile])
+    -> Vec<AttachmentType<'b>> where It: IntoIterator<Item=T>, T: Into<AttachmentType<'a>> {
+    files
+        .into_iter()
+        .map(|file| -> AttachmentType<'b> { file.into() })
+        .chain(added.iter().map(MessageFile::as_attachment))
+        .collect()
+}
+
+/// Splits message Ids into those that can be bulk deleted, being newer than 2
+/// weeks, and those that must be deleted individually.
+#[cfg(feature = "model")]
+fn split_bulk_deletable(ids: Vec<MessageId>, now: DateTime<Utc>) -> (Vec<u64>, Vec<u64>) {
+    let cutoff = now - Duration::weeks(2);
+
+    let (bulk, individual): (Vec<_>, Vec<_>) = ids
+        .into_iter()
+        .partition(|id| id.created_at() > cutoff);
+
+    (
+        bulk.into_iter().map(|id| id.0).collect(),
+        individual.into_iter().map(|id| id.0).collect(),
+    )
+}
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use chrono::{Duration, TimeZone, Utc};
+    use crate::builder::CreateMessage;
+    use crate::http::AttachmentType;
+    use crate::model::id::MessageId;
+    use super::{message_attachments, split_bulk_deletable};
+
+    fn id_at(secs: i64) -> MessageId {
+        MessageId(((secs * 1000 - 1_420_070_400_000) as u64) << 22)
+    }
+
+    #[test]
+    fn test_split_bulk_deletable() {
+        let now = Utc.timestamp(1_600_000_000, 0);
+        let recent = id_at(1_600_000_000 - 60);
+        let old = id_at(1_600_000_000 - Duration::weeks(3).num_seconds());
+
+        let (bulk, individual) = split_bulk_deletable(vec![recent, old, recent], now);
+        assert_eq!(bulk, vec![recent.0, recent.0]);
+        assert_eq!(individual, vec![old.0]);
+    }
+
+    #[test]
+    fn test_message_attachments() {
+        let msg = CreateMessage::default()
+            .add_file((&b"added"[..], "added.txt"))
+            .add_file("/tmp/added.png");
+        let given = vec![(&b"given"[..], "given.txt")];
+
+        let names = message_attachments(given, &msg.2)
+            .iter()
+            .map(|file| match *file {
+                AttachmentType::Bytes((_, filename)) => filename.to_string(),
+                AttachmentType::Path(path) => path.display().to_string(),
+                ref other => panic!("Unexpected attachment: {:?}", other),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, ["given.txt", "added.txt", "/tmp/added.png"]);
+    }
+}
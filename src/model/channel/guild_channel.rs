@@ -9,11 +9,13 @@ use crate::builder::{
     CreateMessage,
     EditChannel,
     EditMessage,
+    EditPermissionOverwrite,
+    EditStageInstance,
     GetMessages
 };
 #[cfg(feature = "model")]
 use crate::http::{self, AttachmentType};
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use std::fmt::{
@@ -240,6 +242,26 @@ impl GuildChannel {
         self.id.create_permission(target)
     }
 
+    /// Starts a [`StageInstance`] in the channel, with the given topic.
+    ///
+    /// Refer to [`ChannelId::create_stage_instance`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if the channel is not a
+    /// [stage channel][`ChannelType::Stage`].
+    ///
+    /// [`ChannelId::create_stage_instance`]: ../id/struct.ChannelId.html#method.create_stage_instance
+    /// [`ChannelType::Stage`]: enum.ChannelType.html#variant.Stage
+    /// [`ModelError::InvalidChannelType`]: ../error/enum.Error.html#variant.InvalidChannelType
+    /// [`StageInstance`]: struct.StageInstance.html
+    pub fn create_stage_instance(&self, topic: &str, privacy_level: StagePrivacyLevel)
+        -> Result<StageInstance> {
+        self.check_stage()?;
+
+        self.id.create_stage_instance(topic, privacy_level)
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     pub fn delete(&self) -> Result<Channel> {
         #[cfg(feature = "cache")]
@@ -304,6 +326,22 @@ impl GuildChannel {
         self.id.delete_reaction(message_id, user_id, reaction_type)
     }
 
+    /// Ends the [`StageInstance`] being held in the channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if the channel is not a
+    /// [stage channel][`ChannelType::Stage`].
+    ///
+    /// [`ChannelType::Stage`]: enum.ChannelType.html#variant.Stage
+    /// [`ModelError::InvalidChannelType`]: ../error/enum.Error.html#variant.InvalidChannelType
+    /// [`StageInstance`]: struct.StageInstance.html
+    pub fn delete_stage_instance(&self) -> Result<()> {
+        self.check_stage()?;
+
+        self.id.delete_stage_instance()
+    }
+
     /// Modifies a channel's settings, such as its position or name.
     ///
     /// Refer to `EditChannel`s documentation for a full list of methods.
@@ -368,6 +406,46 @@ impl GuildChannel {
         self.id.edit_message(message_id, f)
     }
 
+    /// Sets the [permission overwrite][`PermissionOverwrite`] for either a
+    /// single [`Member`] or [`Role`] within the channel.
+    ///
+    /// Refer to [`ChannelId::edit_permission`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [`ChannelId::edit_permission`]: ../id/struct.ChannelId.html#method.edit_permission
+    /// [`Member`]: ../guild/struct.Member.html
+    /// [`PermissionOverwrite`]: struct.PermissionOverwrite.html
+    /// [`Role`]: ../guild/struct.Role.html
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[cfg(feature = "utils")]
+    #[inline]
+    pub fn edit_permission<F>(&self, target: PermissionOverwriteType, f: F) -> Result<()>
+        where F: FnOnce(EditPermissionOverwrite) -> EditPermissionOverwrite {
+        self.id.edit_permission(target, f)
+    }
+
+    /// Edits the [`StageInstance`] being held in the channel.
+    ///
+    /// Refer to [`EditStageInstance`]'s documentation for its methods.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if the channel is not a
+    /// [stage channel][`ChannelType::Stage`].
+    ///
+    /// [`ChannelType::Stage`]: enum.ChannelType.html#variant.Stage
+    /// [`EditStageInstance`]: ../../builder/struct.EditStageInstance.html
+    /// [`ModelError::InvalidChannelType`]: ../error/enum.Error.html#variant.InvalidChannelType
+    /// [`StageInstance`]: struct.StageInstance.html
+    #[cfg(feature = "utils")]
+    pub fn edit_stage_instance<F>(&self, f: F) -> Result<StageInstance>
+        where F: FnOnce(EditStageInstance) -> EditStageInstance {
+        self.check_stage()?;
+
+        self.id.edit_stage_instance(f)
+    }
+
     /// Attempts to find this channel's guild in the Cache.
     ///
     /// **Note**: Right now this performs a clone of the guild. This will be
@@ -647,6 +725,15 @@ impl GuildChannel {
     /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
     #[inline]
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { self.id.webhooks() }
+
+    // Stage instances can only be held in stage channels.
+    fn check_stage(&self) -> Result<()> {
+        if self.kind == ChannelType::Stage {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::InvalidChannelType(self.kind)))
+        }
+    }
 }
 
 #[cfg(feature = "model")]
@@ -2,7 +2,11 @@
 
 use chrono::{DateTime, FixedOffset};
 use crate::model::prelude::*;
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
 use serde_json::Value;
+use std::result::Result as StdResult;
+use super::super::utils::U64Visitor;
 
 #[cfg(feature = "model")]
 use crate::builder::{CreateEmbed, EditMessage};
@@ -182,6 +186,32 @@ impl Message {
         http::delete_message_reactions(self.channel_id.0, self.id.0)
     }
 
+    /// Deletes all of the [`Reaction`]s of the given emoji from the message.
+    ///
+    /// **Note**: Requires the [Manage Messages] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    ///
+    /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [`Reaction`]: struct.Reaction.html
+    /// [Manage Messages]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_MESSAGES
+    pub fn delete_reaction_emoji<R: Into<ReactionType>>(&self, reaction_type: R) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::MANAGE_MESSAGES;
+
+            if !utils::user_has_perms(self.channel_id, req)? {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        http::delete_reaction_emoji(self.channel_id.0, self.id.0, &reaction_type.into())
+    }
+
     /// Edits this message, replacing the original content with new content.
     ///
     /// Message editing preserves all unchanged message data.
@@ -419,6 +449,19 @@ impl Message {
     ///
     /// **Note**: Requires the [Add Reactions] permission.
     ///
+    /// # Examples
+    ///
+    /// React with both a unicode emoji and a custom emoji:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// #
+    /// # fn react(message: &Message, emoji: &Emoji) {
+    /// let _ = message.react("👍");
+    /// let _ = message.react(emoji);
+    /// # }
+    /// ```
+    ///
     /// # Errors
     ///
     /// If the `cache` is enabled, returns a
@@ -519,6 +562,62 @@ impl Message {
         self.mentions_user_id(user.id)
     }
 
+    /// Checks whether the message mentions the current user, either directly
+    /// or through one of their roles in the guild.
+    ///
+    /// Pass `true` for `everyone` to also count `@everyone` and `@here`
+    /// mentions.
+    ///
+    /// # Examples
+    ///
+    /// Reply whenever someone pings the bot:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::prelude::*;
+    /// #
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {
+    ///     fn message(&self, _: Context, msg: Message) {
+    ///         if msg.mentions_me(false).unwrap_or(false) {
+    ///             let _ = msg.reply("You rang?");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user, or their member in the
+    /// guild, could not be retrieved. If the `cache` is enabled, these are
+    /// looked up there first.
+    ///
+    /// [`Error::Http`]: ../../enum.Error.html#variant.Http
+    pub fn mentions_me(&self, everyone: bool) -> Result<bool> {
+        if everyone && self.mention_everyone {
+            return Ok(true);
+        }
+
+        #[cfg(feature = "cache")]
+        let current_user_id = CACHE.read().user.id;
+        #[cfg(not(feature = "cache"))]
+        let current_user_id = http::get_current_user()?.id;
+
+        if self.mentions_user_id(current_user_id) {
+            return Ok(true);
+        }
+
+        let guild_id = match self.guild_id {
+            Some(guild_id) if !self.mention_roles.is_empty() => guild_id,
+            _ => return Ok(false),
+        };
+
+        let member = guild_id.member(current_user_id)?;
+
+        Ok(member.roles.iter().any(|role| self.mention_roles.contains(role)))
+    }
+
     /// Unpins the message from its channel.
     ///
     /// **Note**: Requires the [Manage Messages] permission.
@@ -554,69 +653,6 @@ impl Message {
     pub fn author_nick(&self) -> Option<String> {
         self.guild_id.as_ref().and_then(|guild_id| self.author.nick_in(*guild_id))
     }
-
-    pub(crate) fn check_content_length(map: &JsonMap) -> Result<()> {
-        if let Some(content) = map.get("content") {
-            if let Value::String(ref content) = *content {
-                if let Some(length_over) = Message::overflow_length(content) {
-                    return Err(Error::Model(ModelError::MessageTooLong(length_over)));
-                }
-            }
-        }
-
-        Ok(())
-    }
-
-    pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
-        let embed = match map.get("embed") {
-            Some(&Value::Object(ref value)) => value,
-            _ => return Ok(()),
-        };
-
-        let mut total: usize = 0;
-
-        if let Some(&Value::Object(ref author)) = embed.get("author") {
-            if let Some(&Value::Object(ref name)) = author.get("name") {
-                total += name.len();
-            }
-        }
-
-        if let Some(&Value::String(ref description)) = embed.get("description") {
-            total += description.len();
-        }
-
-        if let Some(&Value::Array(ref fields)) = embed.get("fields") {
-            for field_as_value in fields {
-                if let Value::Object(ref field) = *field_as_value {
-                    if let Some(&Value::String(ref field_name)) = field.get("name") {
-                        total += field_name.len();
-                    }
-
-                    if let Some(&Value::String(ref field_value)) = field.get("value") {
-                        total += field_value.len();
-                    }
-                }
-            }
-        }
-
-        if let Some(&Value::Object(ref footer)) = embed.get("footer") {
-            if let Some(&Value::String(ref text)) = footer.get("text") {
-                total += text.len();
-            }
-        }
-
-        if let Some(&Value::String(ref title)) = embed.get("title") {
-            total += title.len();
-        }
-
-        if total <= constants::EMBED_MAX_LENGTH as usize {
-            Ok(())
-        } else {
-            let overflow = total as u64 - u64::from(constants::EMBED_MAX_LENGTH);
-
-            Err(Error::Model(ModelError::EmbedTooLarge(overflow)))
-        }
-    }
 }
 
 impl AsRef<MessageId> for Message {
@@ -704,3 +740,53 @@ impl MessageType {
         }
     }
 }
+
+/// A set of flags describing extra features of a [`Message`].
+///
+/// [`Message`]: struct.Message.html
+#[derive(Copy, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+pub struct MessageFlags {
+    bits: u64
+}
+
+__impl_bitflags! {
+    MessageFlags: u64 {
+        /// The message has been published to following channels.
+        CROSSPOSTED = 0b0000_0001;
+        /// The message originated from a message in another channel.
+        IS_CROSSPOST = 0b0000_0010;
+        /// The message will not include any embeds when serialized.
+        SUPPRESS_EMBEDS = 0b0000_0100;
+        /// The source message for this crosspost has been deleted.
+        SOURCE_MESSAGE_DELETED = 0b0000_1000;
+        /// The message came from the urgent message system.
+        URGENT = 0b0001_0000;
+        /// The message is only visible to the user who invoked an interaction.
+        ///
+        /// **Note**: This can only be set on interaction responses.
+        EPHEMERAL = 0b0100_0000;
+    }
+}
+
+impl MessageFlags {
+    /// Shorthand for checking that the set of flags contains the
+    /// [Suppress Embeds] flag.
+    ///
+    /// [Suppress Embeds]: #associatedconstant.SUPPRESS_EMBEDS
+    pub fn suppress_embeds(&self) -> bool { self.contains(Self::SUPPRESS_EMBEDS) }
+}
+
+impl<'de> Deserialize<'de> for MessageFlags {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        Ok(MessageFlags::from_bits_truncate(
+            deserializer.deserialize_u64(U64Visitor)?,
+        ))
+    }
+}
+
+impl Serialize for MessageFlags {
+    fn serialize<S>(&self, serializer: S) -> StdResult<S::Ok, S::Error>
+        where S: Serializer {
+        serializer.serialize_u64(self.bits())
+    }
+}
@@ -9,6 +9,8 @@ mod message;
 mod private_channel;
 mod reaction;
 mod channel_category;
+mod stage_instance;
+mod thread;
 
 pub use self::attachment::*;
 pub use self::channel_id::*;
@@ -19,6 +21,8 @@ pub use self::message::*;
 pub use self::private_channel::*;
 pub use self::reaction::*;
 pub use self::channel_category::*;
+pub use self::stage_instance::*;
+pub use self::thread::*;
 
 use crate::internal::RwLockExt;
 use crate::model::prelude::*;
@@ -532,7 +536,7 @@ impl<'de> Deserialize<'de> for Channel {
         };
 
         match kind {
-            0 | 2 => serde_json::from_value::<GuildChannel>(Value::Object(v))
+            0 | 2 | 13 => serde_json::from_value::<GuildChannel>(Value::Object(v))
                 .map(|x| Channel::Guild(Arc::new(RwLock::new(x))))
                 .map_err(DeError::custom),
             1 => serde_json::from_value::<PrivateChannel>(Value::Object(v))
@@ -622,6 +626,27 @@ pub enum ChannelType {
     ///
     /// [`ChannelCategory`]: struct.ChannelCategory.html
     Category = 4,
+    /// An indicator that the channel is a [`GuildThread`] within a news
+    /// channel.
+    ///
+    /// [`GuildThread`]: struct.GuildThread.html
+    NewsThread = 10,
+    /// An indicator that the channel is a public [`GuildThread`].
+    ///
+    /// [`GuildThread`]: struct.GuildThread.html
+    PublicThread = 11,
+    /// An indicator that the channel is a private [`GuildThread`], only
+    /// visible to those invited to it and those with the Manage Threads
+    /// permission.
+    ///
+    /// [`GuildThread`]: struct.GuildThread.html
+    PrivateThread = 12,
+    /// An indicator that the channel is a stage [`GuildChannel`], which
+    /// [`StageInstance`]s can be held in.
+    ///
+    /// [`GuildChannel`]: struct.GuildChannel.html
+    /// [`StageInstance`]: struct.StageInstance.html
+    Stage = 13,
 }
 
 enum_number!(
@@ -631,6 +656,10 @@ enum_number!(
         Voice,
         Group,
         Category,
+        NewsThread,
+        PublicThread,
+        PrivateThread,
+        Stage,
     }
 );
 
@@ -642,6 +671,10 @@ impl ChannelType {
             ChannelType::Text => "text",
             ChannelType::Voice => "voice",
             ChannelType::Category => "category",
+            ChannelType::NewsThread => "news_thread",
+            ChannelType::PublicThread => "public_thread",
+            ChannelType::PrivateThread => "private_thread",
+            ChannelType::Stage => "stage",
         }
     }
 
@@ -652,10 +685,27 @@ impl ChannelType {
             ChannelType::Voice => 2,
             ChannelType::Group => 3,
             ChannelType::Category => 4,
+            ChannelType::NewsThread => 10,
+            ChannelType::PublicThread => 11,
+            ChannelType::PrivateThread => 12,
+            ChannelType::Stage => 13,
         }
     }
 }
 
+/// A news channel being followed by another channel, as created by
+/// [`ChannelId::follow`].
+///
+/// [`ChannelId::follow`]: struct.ChannelId.html#method.follow
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct FollowedChannel {
+    /// The Id of the news channel being followed.
+    pub channel_id: ChannelId,
+    /// The Id of the webhook in the following channel that announcements are
+    /// crossposted to.
+    pub webhook_id: WebhookId,
+}
+
 #[derive(Deserialize, Serialize)]
 struct PermissionOverwriteData {
     allow: Permissions,
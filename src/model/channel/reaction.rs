@@ -358,6 +358,23 @@ impl From<Emoji> for ReactionType {
     }
 }
 
+impl<'a> From<&'a Emoji> for ReactionType {
+    /// Creates a `ReactionType` from a reference to a custom [`Emoji`],
+    /// cloning its name.
+    ///
+    /// This allows reacting with an emoji without giving up ownership of it,
+    /// such as one retrieved from a guild's emojis.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    fn from(emoji: &'a Emoji) -> ReactionType {
+        ReactionType::Custom {
+            animated: emoji.animated,
+            id: emoji.id,
+            name: Some(emoji.name.clone()),
+        }
+    }
+}
+
 impl From<EmojiId> for ReactionType {
     fn from(emoji_id: EmojiId) -> ReactionType {
         ReactionType::Custom {
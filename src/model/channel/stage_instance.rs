@@ -0,0 +1,46 @@
+use crate::model::prelude::*;
+
+/// A live stage being held in a [stage channel], with a topic that is shown to
+/// the members of the guild.
+///
+/// [stage channel]: enum.ChannelType.html#variant.Stage
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StageInstance {
+    /// The unique Id of the stage instance.
+    pub id: StageInstanceId,
+    /// The Id of the guild that the stage channel is in.
+    pub guild_id: GuildId,
+    /// The Id of the stage channel that the stage instance is held in.
+    pub channel_id: ChannelId,
+    /// The topic of the stage instance.
+    pub topic: String,
+    /// Who the stage instance is visible to.
+    pub privacy_level: StagePrivacyLevel,
+}
+
+/// The visibility of a [`StageInstance`].
+///
+/// [`StageInstance`]: struct.StageInstance.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum StagePrivacyLevel {
+    /// The stage instance is visible publicly, such as on stage discovery.
+    Public = 1,
+    /// The stage instance is only visible to the members of the guild.
+    GuildOnly = 2,
+}
+
+enum_number!(
+    StagePrivacyLevel {
+        Public,
+        GuildOnly,
+    }
+);
+
+impl StagePrivacyLevel {
+    pub fn num(&self) -> u64 {
+        match *self {
+            StagePrivacyLevel::Public => 1,
+            StagePrivacyLevel::GuildOnly => 2,
+        }
+    }
+}
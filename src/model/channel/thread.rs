@@ -0,0 +1,86 @@
+use chrono::{DateTime, FixedOffset};
+use crate::model::prelude::*;
+
+/// A thread created within a guild's text or news channel.
+///
+/// [Refer to the Discord docs][docs] for more information.
+///
+/// [docs]: https://discord.com/developers/docs/topics/threads
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildThread {
+    /// The unique Id of the thread.
+    pub id: ChannelId,
+    /// The Id of the guild the thread is located in.
+    pub guild_id: GuildId,
+    /// The Id of the channel the thread was created in.
+    pub parent_id: Option<ChannelId>,
+    /// The Id of the user that created the thread.
+    pub owner_id: Option<UserId>,
+    /// The type of the thread.
+    ///
+    /// This is one of [`ChannelType::NewsThread`],
+    /// [`ChannelType::PublicThread`] or [`ChannelType::PrivateThread`].
+    ///
+    /// [`ChannelType::NewsThread`]: enum.ChannelType.html#variant.NewsThread
+    /// [`ChannelType::PrivateThread`]: enum.ChannelType.html#variant.PrivateThread
+    /// [`ChannelType::PublicThread`]: enum.ChannelType.html#variant.PublicThread
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The name of the thread.
+    pub name: String,
+    /// The Id of the last message sent in the thread.
+    pub last_message_id: Option<MessageId>,
+    /// The timestamp of the time a pin was most recently made.
+    pub last_pin_timestamp: Option<DateTime<FixedOffset>>,
+    /// An approximate count of the messages in the thread, stopping at 50.
+    #[serde(default)]
+    pub message_count: u64,
+    /// An approximate count of the members in the thread, stopping at 50.
+    #[serde(default)]
+    pub member_count: u64,
+    /// The amount of seconds a user has to wait before sending another
+    /// message.
+    #[serde(default)]
+    pub rate_limit_per_user: u64,
+    /// The archival state of the thread.
+    pub thread_metadata: ThreadMetadata,
+    /// The current user's membership of the thread, if they have joined it.
+    #[serde(default)]
+    pub member: Option<ThreadMember>,
+}
+
+/// The archival state of a [`GuildThread`].
+///
+/// [`GuildThread`]: struct.GuildThread.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMetadata {
+    /// Whether the thread is archived.
+    pub archived: bool,
+    /// The number of minutes of inactivity after which the thread is
+    /// automatically archived.
+    pub auto_archive_duration: u64,
+    /// The timestamp of the time the thread's archival state was last
+    /// changed.
+    pub archive_timestamp: DateTime<FixedOffset>,
+    /// Whether the thread is locked, in which case only members with the
+    /// [Manage Threads] permission can unarchive it.
+    ///
+    /// [Manage Threads]: https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags
+    #[serde(default)]
+    pub locked: bool,
+}
+
+/// A user's membership of a [`GuildThread`].
+///
+/// [`GuildThread`]: struct.GuildThread.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadMember {
+    /// The Id of the thread.
+    pub id: Option<ChannelId>,
+    /// The Id of the user.
+    pub user_id: Option<UserId>,
+    /// The timestamp of the time the user last joined the thread.
+    pub join_timestamp: DateTime<FixedOffset>,
+    /// Notification settings of the user for the thread.
+    pub flags: u64,
+}
@@ -8,7 +8,7 @@ use std::{
         Result as FmtResult
     }
 };
-use super::Permissions;
+use super::{channel::ChannelType, id::{EmojiId, MessageId}, Permissions};
 
 /// An error returned from the [`model`] module.
 ///
@@ -79,12 +79,42 @@ pub enum Error {
     /// When attempting to delete below or above the minimum and maximum allowed
     /// number of messages.
     BulkDeleteAmount,
+    /// When attempting to disable a member's communication until a time too
+    /// far in the future.
+    ///
+    /// Refer to [`constants::MEMBER_TIMEOUT_MAX_DAYS`] for the limit.
+    ///
+    /// [`constants::MEMBER_TIMEOUT_MAX_DAYS`]: ../../constants/constant.MEMBER_TIMEOUT_MAX_DAYS.html
+    CommunicationDisabledTooLong,
     /// When attempting to delete a number of days' worth of messages that is
     /// not allowed.
     DeleteMessageDaysAmount(u8),
+    /// When a direct message could not be sent to a user, as they do not
+    /// accept direct messages from the current user, such as when they have
+    /// disabled direct messages from guild members.
+    DirectMessagesClosed,
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(u64),
+    /// Indicates that an emoji requested by Id does not exist in the guild,
+    /// such as when it has been deleted.
+    ///
+    /// The Id of the emoji is provided.
+    EmojiNotFound(EmojiId),
+    /// Indicates that a part of an embed exceeds Discord's limit for it.
+    ///
+    /// The part and the amount over its limit are provided.
+    EmbedLimitExceeded(EmbedLimit, u64),
+    /// Indicates that an image URL of an embed uses a scheme other than
+    /// `http`, `https`, or `attachment`, which Discord would not display.
+    ///
+    /// The invalid URL is provided.
+    InvalidEmbedUrl(String),
+    /// Indicates that the timestamp of an embed is not in ISO-8601 format,
+    /// which Discord would reject.
+    ///
+    /// The invalid timestamp is provided.
+    InvalidEmbedTimestamp(String),
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
     ///
@@ -108,10 +138,27 @@ pub enum Error {
     ///
     /// [`Permission`]: ../permissions/struct.Permissions.html
     InvalidPermissions(Permissions),
+    /// Indicates that an action can not be performed on a channel of the
+    /// given type, such as creating an invite to a [`ChannelCategory`].
+    ///
+    /// [`ChannelCategory`]: ../channel/struct.ChannelCategory.html
+    InvalidChannelType(ChannelType),
+    /// Indicates that the URL given to [`Game::streaming`] is not a Twitch or
+    /// YouTube link, which Discord would not display as a stream.
+    ///
+    /// The invalid URL is provided.
+    ///
+    /// [`Game::streaming`]: ../gateway/struct.Game.html#method.streaming
+    InvalidStreamUrl(String),
     /// An indicator that the [current user] can not perform an action.
     ///
     /// [current user]: ../user/struct.CurrentUser.html
     InvalidUser,
+    /// Indicates that a username for a [`Webhook`] would be rejected by
+    /// Discord, for the provided reason.
+    ///
+    /// [`Webhook`]: ../webhook/struct.Webhook.html
+    InvalidWebhookUsername(WebhookUsernameError),
     /// An indicator that an item is missing from the [`Cache`], and the action
     /// can not be continued.
     ///
@@ -124,9 +171,33 @@ pub enum Error {
     ///
     /// [`Message`]: ../channel/struct.Message.html
     MessageTooLong(u64),
+    /// Indicates that a message requested by Id does not exist in the
+    /// channel, such as when it has been deleted.
+    ///
+    /// The Id of the message is provided.
+    MessageNotFound(MessageId),
     /// Indicates that the current user is attempting to Direct Message another
     /// bot user, which is disallowed by the API.
     MessagingBot,
+    /// Indicates that a message could not be pinned, as the channel already
+    /// holds the maximum number of pinned messages.
+    ///
+    /// Refer to [`constants::PINS_LIMIT`] for the limit.
+    ///
+    /// [`constants::PINS_LIMIT`]: ../../constants/constant.PINS_LIMIT.html
+    PinLimitReached,
+    /// When attempting to prune members with a number of days of inactivity
+    /// outside of the allowed range of 1 to 30.
+    ///
+    /// The invalid number of days is provided.
+    PruneDaysAmount(u16),
+    /// Indicates that a message has more embeds than Discord allows.
+    ///
+    /// Refer to [`constants::EMBED_COUNT_LIMIT`] for the limit. The number of
+    /// embeds over the limit is provided.
+    ///
+    /// [`constants::EMBED_COUNT_LIMIT`]: ../../constants/constant.EMBED_COUNT_LIMIT.html
+    TooManyEmbeds(u64),
 }
 
 impl Display for Error {
@@ -137,15 +208,69 @@ impl StdError for Error {
     fn description(&self) -> &str {
         match *self {
             Error::BulkDeleteAmount => "Too few/many messages to bulk delete",
+            Error::CommunicationDisabledTooLong => "Communication disabled for too long",
             Error::DeleteMessageDaysAmount(_) => "Invalid delete message days",
+            Error::DirectMessagesClosed => "The user does not accept direct messages",
+            Error::EmbedLimitExceeded(..) => "Embed part over its limit",
             Error::EmbedTooLarge(_) => "Embed too large",
+            Error::EmojiNotFound(_) => "Emoji not found in the guild",
             Error::GuildNotFound => "Guild not found in the cache",
             Error::Hierarchy => "Role hierarchy prevents this action",
+            Error::InvalidChannelType(_) => "Invalid channel type for this action",
+            Error::InvalidEmbedUrl(_) => "Invalid embed image URL scheme",
+            Error::InvalidEmbedTimestamp(_) => "Invalid embed timestamp",
             Error::InvalidPermissions(_) => "Invalid permissions",
+            Error::InvalidStreamUrl(_) => "Invalid stream URL",
             Error::InvalidUser => "The current user can not perform the action",
+            Error::InvalidWebhookUsername(_) => "Invalid webhook username",
             Error::ItemMissing => "The required item is missing from the cache",
+            Error::MessageNotFound(_) => "Message not found in the channel",
             Error::MessageTooLong(_) => "Message too large",
             Error::MessagingBot => "Attempted to message another bot user",
+            Error::PinLimitReached => "The channel's pin limit has been reached",
+            Error::PruneDaysAmount(_) => "Invalid prune days",
+            Error::TooManyEmbeds(_) => "Too many embeds in the message",
         }
     }
 }
+
+/// A part of an embed that is limited in size by Discord.
+///
+/// This is provided by [`Error::EmbedLimitExceeded`].
+///
+/// [`Error::EmbedLimitExceeded`]: enum.Error.html#variant.EmbedLimitExceeded
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EmbedLimit {
+    /// The name of the author, limited to 256 unicode code points.
+    AuthorName,
+    /// The description, limited to 4096 unicode code points.
+    Description,
+    /// The name of a field, limited to 256 unicode code points.
+    FieldName,
+    /// The value of a field, limited to 1024 unicode code points.
+    FieldValue,
+    /// The number of fields, limited to 25.
+    Fields,
+    /// The footer text, limited to 2048 unicode code points.
+    Footer,
+    /// The title, limited to 256 unicode code points.
+    Title,
+}
+
+/// The reason that a username for a [`Webhook`] is invalid.
+///
+/// This is provided by [`Error::InvalidWebhookUsername`].
+///
+/// [`Error::InvalidWebhookUsername`]: enum.Error.html#variant.InvalidWebhookUsername
+/// [`Webhook`]: ../webhook/struct.Webhook.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum WebhookUsernameError {
+    /// The username is empty or only contains whitespace.
+    Blank,
+    /// The username contains "clyde", in any case.
+    ContainsClyde,
+    /// The username is over 80 unicode code points long.
+    ///
+    /// The number of code points over the limit is provided.
+    TooLong(u64),
+}
@@ -154,6 +154,7 @@ impl CacheUpdate for ChannelDeleteEvent {
 
         // Remove the cached messages for the channel.
         cache.messages.remove(&self.channel.id());
+        cache.message_queue.remove(&self.channel.id());
 
         None
     }
@@ -407,6 +408,7 @@ impl CacheUpdate for GuildDeleteEvent {
 
                 // Remove the channel's cached messages.
                 cache.messages.remove(channel_id);
+                cache.message_queue.remove(channel_id);
             }
 
             guild
@@ -522,6 +524,8 @@ impl CacheUpdate for GuildMemberRemoveEvent {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct GuildMemberUpdateEvent {
+    #[serde(default)]
+    pub communication_disabled_until: Option<DateTime<FixedOffset>>,
     pub guild_id: GuildId,
     pub nick: Option<String>,
     pub roles: Vec<RoleId>,
@@ -543,6 +547,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
             let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
 
+                member.communication_disabled_until = self.communication_disabled_until;
                 member.nick.clone_from(&self.nick);
                 member.roles.clone_from(&self.roles);
                 member.user.write().clone_from(&self.user);
@@ -558,6 +563,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 guild.members.insert(
                     self.user.id,
                     Member {
+                        communication_disabled_until: self.communication_disabled_until,
                         deaf: false,
                         guild_id: self.guild_id,
                         joined_at: None,
@@ -784,11 +790,21 @@ impl CacheUpdate for MessageCreateEvent {
             .entry(self.message.channel_id)
             .or_insert_with(Default::default);
 
+        // A message that is already cached keeps its place in the queue.
+        if let Some(message) = messages.get_mut(&self.message.id) {
+            message.clone_from(&self.message);
+
+            return None;
+        }
+
         let mut removed_msg = None;
 
-        if messages.len() == max {
-            if let Some(id) = queue.pop_front() {
-                removed_msg = messages.remove(&id);
+        // More than one message is evicted if the maximum was lowered since
+        // the channel's messages were cached.
+        while messages.len() >= max {
+            match queue.pop_front() {
+                Some(id) => removed_msg = messages.remove(&id),
+                None => break,
             }
         }
 
@@ -820,12 +836,42 @@ pub struct MessageDeleteBulkEvent {
     pub ids: Vec<MessageId>,
 }
 
+#[cfg(feature = "cache")]
+impl CacheUpdate for MessageDeleteBulkEvent {
+    /// The deleted messages that were cached.
+    type Output = Vec<Message>;
+
+    fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
+        let channel_id = self.channel_id;
+        let removed = self.ids
+            .iter()
+            .filter_map(|id| cache.remove_message(channel_id, *id))
+            .collect::<Vec<_>>();
+
+        if removed.is_empty() {
+            None
+        } else {
+            Some(removed)
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct MessageDeleteEvent {
     pub channel_id: ChannelId,
     #[serde(rename = "id")] pub message_id: MessageId,
 }
 
+#[cfg(feature = "cache")]
+impl CacheUpdate for MessageDeleteEvent {
+    /// The deleted message, if it was cached.
+    type Output = Message;
+
+    fn update(&mut self, cache: &mut Cache) -> Option<Self::Output> {
+        cache.remove_message(self.channel_id, self.message_id)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MessageUpdateEvent {
     pub id: MessageId,
@@ -925,6 +971,7 @@ impl CacheUpdate for PresenceUpdateEvent {
                         let roles = self.roles.clone().unwrap_or_default();
 
                         guild.members.insert(self.presence.user_id, Member {
+                            communication_disabled_until: None,
                             deaf: false,
                             guild_id,
                             joined_at: None,
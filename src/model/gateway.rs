@@ -1,5 +1,6 @@
 //! Models pertaining to the gateway.
 
+use crate::internal::prelude::*;
 use parking_lot::RwLock;
 use serde::de::Error as DeError;
 use serde::ser::{SerializeStruct, Serialize, Serializer};
@@ -81,6 +82,12 @@ impl Game {
     ///
     /// **Note**: Maximum `name` length is 128.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidStreamUrl`] if the `url` is not a Twitch
+    /// or YouTube link, as Discord would otherwise not make the stream
+    /// clickable.
+    ///
     /// # Examples
     ///
     /// Create a command that sets the current game and stream:
@@ -95,19 +102,25 @@ impl Game {
     /// // Assumes command has min_args set to 2.
     /// # #[cfg(all(feature = "client", feature = "standard_framework", feature = "model"))]
     /// command!(stream(ctx, _msg, args) {
-    ///     # let stream_url = String::from("");
-    ///     let name = args.full();
-    ///     ctx.set_game(Game::streaming(&name, &stream_url));
+    ///     let stream_url = args.single::<String>()?;
+    ///     let name = args.rest();
+    ///     ctx.set_game(Game::streaming(&name, &stream_url)?);
     /// });
     /// #
     /// # fn main() {}
     /// ```
-    pub fn streaming(name: &str, url: &str) -> Game {
-        Game {
+    ///
+    /// [`ModelError::InvalidStreamUrl`]: ../error/enum.Error.html#variant.InvalidStreamUrl
+    pub fn streaming(name: &str, url: &str) -> Result<Game> {
+        if !is_stream_url(url) {
+            return Err(Error::Model(ModelError::InvalidStreamUrl(url.to_string())));
+        }
+
+        Ok(Game {
             kind: GameType::Streaming,
             name: name.to_string(),
             url: Some(url.to_string()),
-        }
+        })
     }
 
     /// Creates a `Game` struct that appears as a `Listening to <name>` status.
@@ -142,6 +155,26 @@ impl Game {
     }
 }
 
+/// Whether a URL points to Twitch or YouTube, the only services Discord
+/// displays streams from.
+#[cfg(feature = "model")]
+fn is_stream_url(url: &str) -> bool {
+    let rest = ["https://", "http://"].iter().filter_map(|scheme| {
+        match url.get(..scheme.len()) {
+            Some(start) if start.eq_ignore_ascii_case(scheme) => url.get(scheme.len()..),
+            _ => None,
+        }
+    }).next();
+
+    let host = match rest {
+        Some(rest) => rest.split('/').next().unwrap_or("").to_lowercase(),
+        None => return false,
+    };
+
+    ["twitch.tv", "www.twitch.tv", "youtube.com", "www.youtube.com", "youtu.be"]
+        .contains(&&host[..])
+}
+
 impl<'a> From<&'a str> for Game {
     fn from(name: &'a str) -> Self {
         Game {
@@ -392,6 +425,12 @@ pub struct Ready {
 /// ratelimit period.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SessionStartLimit {
+    /// The number of identify requests that may be started concurrently, in
+    /// buckets of shards.
+    ///
+    /// This defaults to `1` if Discord does not provide it.
+    #[serde(default = "default_max_concurrency")]
+    pub max_concurrency: u64,
     /// The number of sessions that you can still initiate within the current
     /// ratelimit period.
     pub remaining: u64,
@@ -399,4 +438,24 @@ pub struct SessionStartLimit {
     pub reset_after: u64,
     /// The total number of session starts within the ratelimit period allowed.
     pub total: u64,
-}
\ No newline at end of file
+}
+
+fn default_max_concurrency() -> u64 { 1 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::Game;
+
+    #[test]
+    fn test_streaming_url() {
+        assert!(Game::streaming("a", "https://twitch.tv/a").is_ok());
+        assert!(Game::streaming("a", "HTTPS://www.Twitch.tv/a").is_ok());
+        assert!(Game::streaming("a", "https://www.youtube.com/watch?v=a").is_ok());
+        assert!(Game::streaming("a", "http://youtu.be/a").is_ok());
+
+        assert!(Game::streaming("a", "").is_err());
+        assert!(Game::streaming("a", "twitch.tv/a").is_err());
+        assert!(Game::streaming("a", "https://example.com/a").is_err());
+        assert!(Game::streaming("a", "https://twitch.tv.example.com/a").is_err());
+    }
+}
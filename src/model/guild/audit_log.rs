@@ -9,6 +9,7 @@ use serde::de::{
 use serde::ser::Serializer;
 use super::super::prelude::*;
 use std::{
+    cmp::Reverse,
     collections::HashMap,
     mem::transmute,
     fmt
@@ -206,6 +207,59 @@ pub struct AuditLogs {
     pub users: Vec<User>,
 }
 
+impl AuditLogs {
+    /// Resolves the users and webhooks referenced by each entry from the
+    /// accompanying [`users`] and [`webhooks`], returning the entries from
+    /// newest to oldest.
+    ///
+    /// [`users`]: #structfield.users
+    /// [`webhooks`]: #structfield.webhooks
+    pub fn resolve(self) -> Vec<ResolvedAuditLogEntry> {
+        let users = self.users
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect::<HashMap<_, _>>();
+        let webhooks = self.webhooks
+            .into_iter()
+            .map(|webhook| (webhook.id, webhook))
+            .collect::<HashMap<_, _>>();
+
+        let mut entries = self.entries.into_iter().collect::<Vec<_>>();
+        entries.sort_by_key(|&(id, _)| Reverse(id));
+
+        entries
+            .into_iter()
+            .map(|(_, entry)| ResolvedAuditLogEntry {
+                user: users.get(&entry.user_id).cloned(),
+                target_user: users.get(&UserId(entry.target_id)).cloned(),
+                target_webhook: webhooks.get(&WebhookId(entry.target_id)).cloned(),
+                entry,
+            })
+            .collect()
+    }
+}
+
+/// An [`AuditLogEntry`] along with the users and webhook that it references.
+///
+/// This is returned by [`AuditLogs::resolve`], and yielded by
+/// [`AuditLogsIter`].
+///
+/// [`AuditLogEntry`]: struct.AuditLogEntry.html
+/// [`AuditLogs::resolve`]: struct.AuditLogs.html#method.resolve
+/// [`AuditLogsIter`]: type.AuditLogsIter.html
+#[derive(Debug)]
+pub struct ResolvedAuditLogEntry {
+    /// The entry itself.
+    pub entry: AuditLogEntry,
+    /// The user that performed the action, if they were included.
+    pub user: Option<User>,
+    /// The user that the action was performed on, if the target is a user.
+    pub target_user: Option<User>,
+    /// The webhook that the action was performed on, if the target is a
+    /// webhook.
+    pub target_webhook: Option<Webhook>,
+}
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AuditLogEntry {
     /// Determines to what entity an [`action`] was used on.
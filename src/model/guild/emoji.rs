@@ -5,17 +5,24 @@ use std::fmt::{
     Write as FmtWrite
 };
 use super::super::id::{EmojiId, RoleId};
+use super::super::user::User;
 
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
+use super::super::channel::ReactionType;
+#[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use std::mem;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use super::super::ModelError;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use super::super::id::GuildId;
+#[cfg(feature = "model")]
+use crate::http::{self, HttpError};
+#[cfg(feature = "model")]
+use hyper::status::StatusCode;
 #[cfg(all(feature = "cache", feature = "model"))]
-use crate::{CACHE, http};
+use crate::CACHE;
 
 /// Represents a custom guild emoji, which can either be created using the API,
 /// or via an integration. Emojis created using the API only work within the
@@ -42,6 +49,14 @@ pub struct Emoji {
     ///
     /// [`Role`]: struct.Role.html
     pub roles: Vec<RoleId>,
+    /// The user who created the emoji.
+    ///
+    /// This is only provided when the emoji is retrieved over the REST API
+    /// with the [Manage Emojis] permission.
+    ///
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[serde(default)]
+    pub user: Option<User>,
 }
 
 #[cfg(feature = "model")]
@@ -70,6 +85,7 @@ impl Emoji {
     /// #     managed: false,
     /// #     require_colons: false,
     /// #     roles: vec![],
+    /// #     user: None,
     /// # };
     /// #
     /// // assuming emoji has been set already
@@ -109,6 +125,7 @@ impl Emoji {
     /// #     managed: false,
     /// #     require_colons: false,
     /// #     roles: vec![],
+    /// #     user: None,
     /// # };
     /// #
     /// // assuming emoji has been set already
@@ -155,6 +172,7 @@ impl Emoji {
     /// #     managed: false,
     /// #     require_colons: false,
     /// #     roles: vec![],
+    /// #     user: None,
     /// # };
     /// #
     /// // assuming emoji has been set already
@@ -175,6 +193,80 @@ impl Emoji {
         None
     }
 
+    /// Re-fetches the emoji from the guild that owns it, replacing its fields
+    /// with the current data.
+    ///
+    /// The guild is found via [`find_guild_id`]. Use [`refresh_in`] when the
+    /// guild is already known or is not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::guild::Emoji;
+    /// # use serenity::model::id::EmojiId;
+    /// #
+    /// # let mut emoji = Emoji {
+    /// #     animated: false,
+    /// #     id: EmojiId(7),
+    /// #     name: String::from("blobface"),
+    /// #     managed: false,
+    /// #     require_colons: false,
+    /// #     roles: vec![],
+    /// #     user: None,
+    /// # };
+    /// #
+    /// // assuming emoji has been set already
+    /// if emoji.refresh().is_ok() {
+    ///     println!("The emoji is now named {}", emoji.name);
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ItemMissing`] if the owning guild is not in
+    /// the cache, and a [`ModelError::EmojiNotFound`] if the emoji no longer
+    /// exists in the guild.
+    ///
+    /// [`find_guild_id`]: #method.find_guild_id
+    /// [`refresh_in`]: #method.refresh_in
+    /// [`ModelError::EmojiNotFound`]: ../error/enum.Error.html#variant.EmojiNotFound
+    /// [`ModelError::ItemMissing`]: ../error/enum.Error.html#variant.ItemMissing
+    #[cfg(feature = "cache")]
+    pub fn refresh(&mut self) -> Result<()> {
+        match self.find_guild_id() {
+            Some(guild_id) => self.refresh_in(guild_id),
+            None => Err(Error::Model(ModelError::ItemMissing)),
+        }
+    }
+
+    /// Re-fetches the emoji from the given guild, replacing its fields with
+    /// the current data.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::EmojiNotFound`] if the emoji does not exist in
+    /// the guild, such as when it has been deleted.
+    ///
+    /// [`ModelError::EmojiNotFound`]: ../error/enum.Error.html#variant.EmojiNotFound
+    #[inline]
+    pub fn refresh_in<G: Into<GuildId>>(&mut self, guild_id: G) -> Result<()> {
+        self._refresh_in(guild_id.into())
+    }
+
+    fn _refresh_in(&mut self, guild_id: GuildId) -> Result<()> {
+        let emoji = match http::get_emoji(guild_id.0, self.id.0) {
+            Err(Error::Http(HttpError::UnsuccessfulRequest(ref response)))
+                if response.status == StatusCode::NotFound => {
+                return Err(Error::Model(ModelError::EmojiNotFound(self.id)));
+            },
+            other => other?,
+        };
+
+        *self = emoji;
+
+        Ok(())
+    }
+
     /// Generates a URL to the emoji's image.
     ///
     /// # Examples
@@ -192,6 +284,7 @@ impl Emoji {
     /// #     managed: false,
     /// #     require_colons: false,
     /// #     roles: vec![],
+    /// #     user: None,
     /// # };
     /// #
     /// // assuming emoji has been set already
@@ -202,15 +295,25 @@ impl Emoji {
         let extension = if self.animated {"gif"} else {"png"};
         format!(cdn!("/emojis/{}.{}"), self.id, extension)
     }
+
+    /// Creates a [`ReactionType`] for the emoji, for use when reacting to a
+    /// message.
+    ///
+    /// This is the same as `ReactionType::from(&emoji)`.
+    ///
+    /// [`ReactionType`]: ../channel/enum.ReactionType.html
+    #[inline]
+    pub fn as_reaction(&self) -> ReactionType { ReactionType::from(self) }
 }
 
 impl Display for Emoji {
     /// Formats the emoji into a string that will cause Discord clients to
     /// render the emoji.
     ///
-    /// This is in the format of: `<:NAME:EMOJI_ID>`.
+    /// This is in the format of: `<:NAME:EMOJI_ID>`, or `<a:NAME:EMOJI_ID>` if
+    /// the emoji is animated.
     fn fmt(&self, f: &mut Formatter) -> FmtResult {
-        f.write_str("<:")?;
+        f.write_str(if self.animated { "<a:" } else { "<:" })?;
         f.write_str(&self.name)?;
         FmtWrite::write_char(f, ':')?;
         Display::fmt(&self.id, f)?;
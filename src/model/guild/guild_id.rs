@@ -3,13 +3,17 @@ use crate::model::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::CACHE;
 #[cfg(feature = "model")]
-use crate::builder::{EditGuild, EditMember, EditRole};
+use crate::builder::{EditChannelPositions, EditGuild, EditMember, EditRole};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::model::guild::BanOptions;
 #[cfg(feature = "model")]
-use crate::{http, utils};
+use crate::{constants, http, utils};
+#[cfg(feature = "model")]
+use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature = "model")]
+use std::fmt::Display;
 
 #[cfg(feature = "model")]
 impl GuildId {
@@ -20,6 +24,31 @@ impl GuildId {
                          concept.")]
     pub fn as_channel_id(&self) -> ChannelId { ChannelId(self.0) }
 
+    /// Gets the guild's active threads, without needing to walk its channels.
+    ///
+    /// Each thread's [`member`] is set if the current user has joined it.
+    ///
+    /// # Examples
+    ///
+    /// List the names of the open threads in a guild:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::GuildId;
+    ///
+    /// let threads = GuildId(81384788765712384).active_threads()
+    ///     .expect("Error getting threads");
+    ///
+    /// for thread in threads {
+    ///     println!("{} (joined: {})", thread.name, thread.member.is_some());
+    /// }
+    /// ```
+    ///
+    /// [`member`]: ../channel/struct.GuildThread.html#structfield.member
+    #[inline]
+    pub fn active_threads(&self) -> Result<Vec<GuildThread>> {
+        http::get_active_threads(self.0)
+    }
+
     /// Ban a [`User`] from the guild. All messages by the
     /// user within the last given number of days given will be deleted.
     ///
@@ -75,6 +104,33 @@ impl GuildId {
     #[inline]
     pub fn bans(&self) -> Result<Vec<Ban>> { http::get_bans(self.0) }
 
+    /// Gets the ban of a [`User`] in the guild, if they are banned.
+    ///
+    /// Returns `Ok(None)` if the user is not banned.
+    ///
+    /// Requires the [Ban Members] permission.
+    ///
+    /// # Examples
+    ///
+    /// Show the reason a user was banned for:
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::id::{GuildId, UserId};
+    ///
+    /// match GuildId(81384788765712384).get_ban(UserId(114941315417899012)) {
+    ///     Ok(Some(ban)) => println!("Banned for: {:?}", ban.reason),
+    ///     Ok(None) => println!("Not banned"),
+    ///     Err(why) => println!("Error retrieving ban: {:?}", why),
+    /// }
+    /// ```
+    ///
+    /// [`User`]: ../user/struct.User.html
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[inline]
+    pub fn get_ban<U: Into<UserId>>(&self, user_id: U) -> Result<Option<Ban>> {
+        http::get_ban(self.0, user_id.into().0)
+    }
+
     /// Gets a list of the guild's audit log entries
     #[inline]
     pub fn audit_logs(&self, action_type: Option<u8>,
@@ -84,6 +140,55 @@ impl GuildId {
         http::get_audit_logs(self.0, action_type, user_id.map(|u| u.0), before.map(|a| a.0), limit)
     }
 
+    /// Iterates over all of the guild's audit log entries from newest to
+    /// oldest, lazily retrieving them from the REST API in pages of 100.
+    ///
+    /// The entries can optionally be filtered by the type of action and the
+    /// user that performed it. The users and webhooks referenced by each entry
+    /// are resolved from each page.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Examples
+    ///
+    /// Exporting who made each change to a guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// #
+    /// let guild_id = GuildId(81384788765712384);
+    ///
+    /// for resolved in guild_id.audit_logs_iter(None, None) {
+    ///     match resolved {
+    ///         Ok(resolved) => {
+    ///             let name = resolved.user.map_or_else(|| "unknown".to_string(), |u| u.tag());
+    ///
+    ///             println!("{}: {:?} by {}", resolved.entry.id.0, resolved.entry.action, name);
+    ///         },
+    ///         Err(why) => {
+    ///             println!("Error retrieving audit logs: {:?}", why);
+    ///
+    ///             break;
+    ///         },
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [View Audit Log]: ../permissions/struct.Permissions.html#associatedconstant.VIEW_AUDIT_LOG
+    pub fn audit_logs_iter(&self, action_type: Option<u8>, user_id: Option<UserId>) -> AuditLogsIter {
+        let guild_id = *self;
+
+        Paginated::new(
+            100,
+            |page: &[ResolvedAuditLogEntry]| page.last().map(|oldest| oldest.entry.id.0),
+            Box::new(move |before| {
+                let before = before.map(AuditLogEntryId);
+
+                Ok(guild_id.audit_logs(action_type, user_id, before, Some(100))?.resolve())
+            }),
+        )
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// [`Guild`]: ../guild/struct.Guild.html
@@ -334,6 +439,147 @@ impl GuildId {
         http::edit_member(self.0, user_id.0, &map)
     }
 
+    /// Replaces the roles of a member of the guild with the given set of roles,
+    /// in a single request.
+    ///
+    /// This _replaces_ the member's roles: any role not in `role_ids` is
+    /// removed. Use [`add_member_roles`] or [`remove_member_roles`] to change
+    /// only some of the member's roles.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`add_member_roles`]: #method.add_member_roles
+    /// [`remove_member_roles`]: #method.remove_member_roles
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn edit_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self._edit_member(user_id.into(), |m| m.roles(role_ids))
+    }
+
+    /// Disables the communication of a member of the guild until the given
+    /// time, timing them out.
+    ///
+    /// A timed out member can not send messages, react, or speak in voice
+    /// channels until the time has passed or [`enable_member_communication`]
+    /// is used.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// # Examples
+    ///
+    /// Time a member out for ten minutes:
+    ///
+    /// ```rust,no_run
+    /// extern crate chrono;
+    /// # extern crate serenity;
+    ///
+    /// use chrono::{Duration, Utc};
+    /// use serenity::model::id::{GuildId, UserId};
+    ///
+    /// # fn main() {
+    /// let until = Utc::now() + Duration::minutes(10);
+    ///
+    /// let _ = GuildId(81384788765712384)
+    ///     .disable_member_communication_until(UserId(114941315417899012), &until);
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::CommunicationDisabledTooLong`] if the time is
+    /// more than [`constants::MEMBER_TIMEOUT_MAX_DAYS`] in the future.
+    ///
+    /// [`ModelError::CommunicationDisabledTooLong`]: ../error/enum.Error.html#variant.CommunicationDisabledTooLong
+    /// [`constants::MEMBER_TIMEOUT_MAX_DAYS`]: ../../constants/constant.MEMBER_TIMEOUT_MAX_DAYS.html
+    /// [`enable_member_communication`]: #method.enable_member_communication
+    /// [Moderate Members]: https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags
+    #[inline]
+    pub fn disable_member_communication_until<U, Tz>(&self, user_id: U, time: &DateTime<Tz>) -> Result<()>
+        where U: Into<UserId>, Tz: TimeZone, Tz::Offset: Display {
+        self._disable_member_communication_until(user_id.into(), time)
+    }
+
+    fn _disable_member_communication_until<Tz>(self, user_id: UserId, time: &DateTime<Tz>) -> Result<()>
+        where Tz: TimeZone, Tz::Offset: Display {
+        let max = constants::MEMBER_TIMEOUT_MAX_DAYS * 24 * 60 * 60;
+
+        if time.timestamp() - Utc::now().timestamp() > max {
+            return Err(Error::Model(ModelError::CommunicationDisabledTooLong));
+        }
+
+        self._edit_member(user_id, |m| m.disable_communication_until(time))
+    }
+
+    /// Enables the communication of a timed out member of the guild again.
+    ///
+    /// **Note**: Requires the [Moderate Members] permission.
+    ///
+    /// [Moderate Members]: https://discord.com/developers/docs/topics/permissions#permissions-bitwise-permission-flags
+    #[inline]
+    pub fn enable_member_communication<U: Into<UserId>>(&self, user_id: U) -> Result<()> {
+        self._edit_member(user_id.into(), |m| m.enable_communication())
+    }
+
+    /// Adds one or multiple roles to a member of the guild, in a single
+    /// request.
+    ///
+    /// The member's current roles are taken from the cache if possible, and
+    /// are otherwise retrieved over the REST API. Roles the member already has
+    /// are ignored, and no request is made if the member has all of them.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn add_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self._add_member_roles(user_id.into(), role_ids)
+    }
+
+    fn _add_member_roles(self, user_id: UserId, role_ids: &[RoleId]) -> Result<()> {
+        let mut roles = self._member(user_id)?.roles;
+        let len = roles.len();
+
+        for role_id in role_ids {
+            if !roles.contains(role_id) {
+                roles.push(*role_id);
+            }
+        }
+
+        if roles.len() == len {
+            return Ok(());
+        }
+
+        self._edit_member(user_id, |m| m.roles(&roles))
+    }
+
+    /// Removes one or multiple roles from a member of the guild, in a single
+    /// request.
+    ///
+    /// The member's current roles are taken from the cache if possible, and
+    /// are otherwise retrieved over the REST API. Roles the member does not
+    /// have are ignored, and no request is made if the member has none of them.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn remove_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self._remove_member_roles(user_id.into(), role_ids)
+    }
+
+    fn _remove_member_roles(self, user_id: UserId, role_ids: &[RoleId]) -> Result<()> {
+        let mut roles = self._member(user_id)?.roles;
+        let len = roles.len();
+
+        roles.retain(|r| !role_ids.contains(r));
+
+        if roles.len() == len {
+            return Ok(());
+        }
+
+        self._edit_member(user_id, |m| m.roles(&roles))
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -403,6 +649,32 @@ impl GuildId {
         http::edit_role_position(self.0, role_id.0, position)
     }
 
+    /// Gets an [`Emoji`] of the guild by Id over the REST API, regardless of
+    /// whether the guild is in the cache.
+    ///
+    /// The emoji's [`user`] is only provided if the current user has the
+    /// [Manage Emojis] permission.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    /// [`user`]: ../guild/struct.Emoji.html#structfield.user
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub fn emoji<E: Into<EmojiId>>(&self, emoji_id: E) -> Result<Emoji> {
+        http::get_emoji(self.0, emoji_id.into().0)
+    }
+
+    /// Gets all of the guild's [`Emoji`]s over the REST API, regardless of
+    /// whether the guild is in the cache.
+    ///
+    /// Each emoji's [`user`] is only provided if the current user has the
+    /// [Manage Emojis] permission.
+    ///
+    /// [`Emoji`]: ../guild/struct.Emoji.html
+    /// [`user`]: ../guild/struct.Emoji.html#structfield.user
+    /// [Manage Emojis]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_EMOJIS
+    #[inline]
+    pub fn emojis(&self) -> Result<Vec<Emoji>> { http::get_emojis(self.0) }
+
     /// Search the cache for the guild.
     #[cfg(feature = "cache")]
     #[deprecated(since = "0.5.8", note = "Use the `to_guild_cached`-method instead.")]
@@ -443,6 +715,30 @@ impl GuildId {
     ///
     /// Requires the [Manage Guild] permission.
     ///
+    /// # Examples
+    ///
+    /// Revoke every invite that has been used more than 100 times:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// # use std::error::Error;
+    /// #
+    /// # fn try_main() -> Result<(), Box<Error>> {
+    /// for invite in GuildId(7).invites()? {
+    ///     if invite.uses > 100 {
+    ///         println!("Revoking {} by {}", invite.code, invite.inviter.name);
+    ///
+    ///         invite.delete()?;
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     try_main().unwrap();
+    /// # }
+    /// ```
+    ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
     pub fn invites(&self) -> Result<Vec<RichInvite>> { http::get_guild_invites(self.0) }
@@ -502,6 +798,49 @@ impl GuildId {
         http::get_guild_members(self.0, limit, after.map(|x| x.0))
     }
 
+    /// Iterates over all of the guild's members, lazily retrieving them from
+    /// the REST API in pages of 1000.
+    ///
+    /// Unlike [`Guild::members`], this does not rely on the cache or on the
+    /// gateway chunking members.
+    ///
+    /// # Examples
+    ///
+    /// Counting the bots in a guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::GuildId;
+    /// #
+    /// let guild_id = GuildId(81384788765712384);
+    /// let mut bots = 0;
+    ///
+    /// for member in guild_id.members_iter() {
+    ///     match member {
+    ///         Ok(member) => if member.user.read().bot {
+    ///             bots += 1;
+    ///         },
+    ///         Err(why) => {
+    ///             println!("Error retrieving members: {:?}", why);
+    ///
+    ///             break;
+    ///         },
+    ///     }
+    /// }
+    ///
+    /// println!("{} bots", bots);
+    /// ```
+    ///
+    /// [`Guild::members`]: struct.Guild.html#structfield.members
+    pub fn members_iter(&self) -> MembersIter {
+        let guild_id = *self;
+
+        Paginated::new(
+            1000,
+            |page: &[Member]| page.iter().map(|member| member.user.read().id.0).max(),
+            Box::new(move |after| guild_id.members(Some(1000), after.map(UserId))),
+        )
+    }
+
     /// Moves a member to a specific voice channel.
     ///
     /// Requires the [Move Members] permission.
@@ -528,18 +867,22 @@ impl GuildId {
     }
 
     /// Gets the number of [`Member`]s that would be pruned with the given
-    /// number of days.
+    /// number of days, without removing them.
     ///
     /// Requires the [Kick Members] permission.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// within 1 and 30.
+    ///
     /// [`Member`]: ../guild/struct.Member.html
+    /// [`ModelError::PruneDaysAmount`]: ../error/enum.Error.html#variant.PruneDaysAmount
     /// [Kick Members]: ../permissions/struct.Permissions.html#associatedconstant.KICK_MEMBERS
     pub fn prune_count(&self, days: u16) -> Result<GuildPrune> {
-        let map = json!({
-            "days": days,
-        });
+        check_prune_days(days)?;
 
-        http::get_guild_prune_count(self.0, &map)
+        http::get_guild_prune_count(self.0, days)
     }
 
     /// Re-orders the channels of the guild.
@@ -550,6 +893,11 @@ impl GuildId {
     /// Although not required, you should specify all channels' positions,
     /// regardless of whether they were updated. Otherwise, positioning can
     /// sometimes get weird.
+    ///
+    /// To also move channels between categories, use
+    /// [`edit_channel_positions`].
+    ///
+    /// [`edit_channel_positions`]: #method.edit_channel_positions
     #[inline]
     pub fn reorder_channels<It>(&self, channels: It) -> Result<()>
         where It: IntoIterator<Item = (ChannelId, u64)> {
@@ -565,6 +913,23 @@ impl GuildId {
         http::edit_guild_channel_positions(self.0, &Value::Array(items))
     }
 
+    /// Edits the positions of the guild's channels in a single request, and
+    /// optionally moves them into or out of categories.
+    ///
+    /// Refer to the documentation for [`EditChannelPositions`] for more
+    /// information.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [`EditChannelPositions`]: ../../builder/struct.EditChannelPositions.html
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    pub fn edit_channel_positions<F>(&self, f: F) -> Result<()>
+        where F: FnOnce(EditChannelPositions) -> EditChannelPositions {
+        let positions = f(EditChannelPositions::default()).0;
+
+        http::edit_guild_channel_positions(self.0, &Value::Array(positions))
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total
@@ -621,22 +986,32 @@ impl GuildId {
         http::start_integration_sync(self.0, integration_id.0)
     }
 
-    /// Starts a prune of [`Member`]s.
+    /// Starts a prune of the [`Member`]s that have not been seen in the given
+    /// number of days, removing them from the guild.
+    ///
+    /// If `compute_count` is `true`, the number of removed members is returned.
+    /// Passing `false` avoids counting them, which is recommended for large
+    /// guilds, in which case `None` is returned. Use [`prune_count`] to only
+    /// find out how many members would be removed.
     ///
     /// See the documentation on [`GuildPrune`] for more information.
     ///
     /// **Note**: Requires the [Kick Members] permission.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// within 1 and 30.
+    ///
     /// [`GuildPrune`]: ../guild/struct.GuildPrune.html
     /// [`Member`]: ../guild/struct.Member.html
+    /// [`ModelError::PruneDaysAmount`]: ../error/enum.Error.html#variant.PruneDaysAmount
+    /// [`prune_count`]: #method.prune_count
     /// [Kick Members]: ../permissions/struct.Permissions.html#associatedconstant.KICK_MEMBERS
-    #[inline]
-    pub fn start_prune(&self, days: u16) -> Result<GuildPrune> {
-        let map = json!({
-            "days": days,
-        });
+    pub fn start_prune(&self, days: u16, compute_count: bool) -> Result<Option<GuildPrune>> {
+        check_prune_days(days)?;
 
-        http::start_guild_prune(self.0, &map)
+        http::start_guild_prune(self.0, days, compute_count)
     }
 
     /// Unbans a [`User`] from the guild.
@@ -654,13 +1029,17 @@ impl GuildId {
         http::remove_ban(self.0, user_id.0)
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieve's the guild's vanity invite code and its number of uses, if
+    /// it has one.
+    ///
+    /// Returns `None` if the guild does not have the vanity URL feature or has
+    /// not set a vanity invite.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
-    pub fn vanity_url(&self) -> Result<String> {
+    pub fn vanity_url(&self) -> Result<Option<(String, u32)>> {
         http::get_guild_vanity_url(self.0)
     }
 
@@ -671,8 +1050,49 @@ impl GuildId {
     /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
     #[inline]
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { http::get_guild_webhooks(self.0) }
+
+    /// Retrieves the guild's public widget, containing its online member
+    /// count and some of its online members and voice channels.
+    ///
+    /// This does not require authentication, but the guild must have its
+    /// widget enabled.
+    ///
+    /// [Refer to the Discord docs][docs] for more information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/guild#get-guild-widget
+    #[inline]
+    pub fn widget(&self) -> Result<GuildWidget> { http::get_guild_widget(self.0) }
+}
+
+/// Checks that a number of days of inactivity is one that Discord accepts for
+/// a prune.
+#[cfg(feature = "model")]
+fn check_prune_days(days: u16) -> Result<()> {
+    if !(1..=30).contains(&days) {
+        return Err(Error::Model(ModelError::PruneDaysAmount(days)));
+    }
+
+    Ok(())
 }
 
+/// An iterator over all of a guild's members, retrieving them from the REST
+/// API one page at a time.
+///
+/// This is created by [`GuildId::members_iter`].
+///
+/// [`GuildId::members_iter`]: ../id/struct.GuildId.html#method.members_iter
+#[cfg(feature = "model")]
+pub type MembersIter = Paginated<Member>;
+
+/// An iterator over a guild's audit log entries, retrieving them from the REST
+/// API one page at a time.
+///
+/// This is created by [`GuildId::audit_logs_iter`].
+///
+/// [`GuildId::audit_logs_iter`]: ../id/struct.GuildId.html#method.audit_logs_iter
+#[cfg(feature = "model")]
+pub type AuditLogsIter = Paginated<ResolvedAuditLogEntry>;
+
 impl From<PartialGuild> for GuildId {
     /// Gets the Id of a partial guild.
     fn from(guild: PartialGuild) -> GuildId { guild.id }
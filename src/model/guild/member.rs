@@ -1,5 +1,7 @@
 use crate::model::prelude::*;
 use chrono::{DateTime, FixedOffset};
+#[cfg(feature = "model")]
+use chrono::TimeZone;
 use std::fmt::{
     Display,
     Formatter,
@@ -55,6 +57,13 @@ impl BanOptions for (u8, String) {
 /// Information about a member of a guild.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Member {
+    /// The time until which the member's communication is disabled, if it
+    /// has been disabled.
+    ///
+    /// **Note**: This may be in the past, in which case the member is able to
+    /// communicate again.
+    #[serde(default)]
+    pub communication_disabled_until: Option<DateTime<FixedOffset>>,
     /// Indicator of whether the member can hear in voice channels.
     pub deaf: bool,
     /// The unique Id of the guild that the member is a part of.
@@ -228,6 +237,25 @@ impl Member {
         )
     }
 
+    /// Disables the member's communication until the given time, timing them
+    /// out.
+    ///
+    /// Refer to [`GuildId::disable_member_communication_until`] for more
+    /// information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::CommunicationDisabledTooLong`] if the time is
+    /// more than 28 days in the future.
+    ///
+    /// [`GuildId::disable_member_communication_until`]: ../id/struct.GuildId.html#method.disable_member_communication_until
+    /// [`ModelError::CommunicationDisabledTooLong`]: ../error/enum.Error.html#variant.CommunicationDisabledTooLong
+    #[inline]
+    pub fn disable_communication_until<Tz: TimeZone>(&self, time: &DateTime<Tz>) -> Result<()>
+        where Tz::Offset: Display {
+        self.guild_id.disable_member_communication_until(self.user.read().id, time)
+    }
+
     /// Edits the member with the given data. See [`Guild::edit_member`] for
     /// more information.
     ///
@@ -243,6 +271,17 @@ impl Member {
         http::edit_member(self.guild_id.0, self.user.read().id.0, &map)
     }
 
+    /// Enables the member's communication again, ending their timeout.
+    ///
+    /// Refer to [`GuildId::enable_member_communication`] for more
+    /// information.
+    ///
+    /// [`GuildId::enable_member_communication`]: ../id/struct.GuildId.html#method.enable_member_communication
+    #[inline]
+    pub fn enable_communication(&self) -> Result<()> {
+        self.guild_id.enable_member_communication(self.user.read().id)
+    }
+
     /// Retrieves the ID and position of the member's highest role in the
     /// hierarchy, if they have one.
     ///
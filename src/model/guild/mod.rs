@@ -7,6 +7,7 @@ mod member;
 mod partial_guild;
 mod role;
 mod audit_log;
+mod widget;
 
 pub use self::emoji::*;
 pub use self::guild_id::*;
@@ -15,6 +16,7 @@ pub use self::member::*;
 pub use self::partial_guild::*;
 pub use self::role::*;
 pub use self::audit_log::*;
+pub use self::widget::*;
 
 use chrono::{DateTime, FixedOffset};
 use crate::model::prelude::*;
@@ -27,7 +29,7 @@ use crate::CACHE;
 #[cfg(feature = "model")]
 use crate::http;
 #[cfg(feature = "model")]
-use crate::builder::{EditGuild, EditMember, EditRole};
+use crate::builder::{EditChannelPositions, EditGuild, EditMember, EditRole};
 #[cfg(feature = "model")]
 use crate::constants::LARGE_THRESHOLD;
 #[cfg(feature = "model")]
@@ -198,6 +200,14 @@ impl Guild {
         permissions.is_empty()
     }
 
+    /// Gets the guild's active threads.
+    ///
+    /// Refer to [`GuildId::active_threads`] for more information.
+    ///
+    /// [`GuildId::active_threads`]: ../id/struct.GuildId.html#method.active_threads
+    #[inline]
+    pub fn active_threads(&self) -> Result<Vec<GuildThread>> { self.id.active_threads() }
+
     /// Ban a [`User`] from the guild. All messages by the
     /// user within the last given number of days given will be deleted.
     ///
@@ -272,6 +282,33 @@ impl Guild {
         self.id.bans()
     }
 
+    /// Retrieves the [`Ban`] of a user in the guild, if they are banned.
+    ///
+    /// Returns `Ok(None)` if the user is not banned.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to perform bans.
+    ///
+    /// [`Ban`]: struct.Ban.html
+    /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    pub fn get_ban<U: Into<UserId>>(&self, user_id: U) -> Result<Option<Ban>> {
+        #[cfg(feature = "cache")]
+        {
+            let req = Permissions::BAN_MEMBERS;
+
+            if !self.has_perms(req) {
+                return Err(Error::Model(ModelError::InvalidPermissions(req)));
+            }
+        }
+
+        self.id.get_ban(user_id)
+    }
+
     /// Retrieves a list of [`AuditLogs`] for the guild.
     ///
     /// [`AuditLogs`]: audit_log/struct.AuditLogs.html
@@ -586,6 +623,48 @@ impl Guild {
         self.id.edit_member(user_id, f)
     }
 
+    /// Replaces the roles of a member of the guild with the given set of roles,
+    /// in a single request.
+    ///
+    /// Refer to [`GuildId::edit_member_roles`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`GuildId::edit_member_roles`]: ../id/struct.GuildId.html#method.edit_member_roles
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn edit_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self.id.edit_member_roles(user_id, role_ids)
+    }
+
+    /// Adds one or multiple roles to a member of the guild, in a single
+    /// request.
+    ///
+    /// Refer to [`GuildId::add_member_roles`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`GuildId::add_member_roles`]: ../id/struct.GuildId.html#method.add_member_roles
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn add_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self.id.add_member_roles(user_id, role_ids)
+    }
+
+    /// Removes one or multiple roles from a member of the guild, in a single
+    /// request.
+    ///
+    /// Refer to [`GuildId::remove_member_roles`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`GuildId::remove_member_roles`]: ../id/struct.GuildId.html#method.remove_member_roles
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn remove_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self.id.remove_member_roles(user_id, role_ids)
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -1191,7 +1270,13 @@ impl Guild {
 
     /// Calculate a [`User`]'s permissions in a given channel in the guild.
     ///
+    /// If the user is in the guild's [`members`], this resolves the
+    /// permissions like [`user_permissions_in`]. Otherwise only the
+    /// permissions of the `@everyone` role are returned.
+    ///
     /// [`User`]: ../user/struct.User.html
+    /// [`members`]: #structfield.members
+    /// [`user_permissions_in`]: #method.user_permissions_in
     #[inline]
     pub fn permissions_in<C, U>(&self, channel_id: C, user_id: U) -> Permissions
         where C: Into<ChannelId>, U: Into<UserId> {
@@ -1208,6 +1293,61 @@ impl Guild {
             return Permissions::all();
         }
 
+        match self.members.get(&user_id) {
+            Some(member) => self._user_permissions_in(channel_id, member),
+            None => self.roles
+                .get(&RoleId(self.id.0))
+                .map(|everyone| everyone.permissions)
+                .unwrap_or_else(Permissions::empty),
+        }
+    }
+
+    /// Calculate a [`Member`]'s permissions in a given channel in the guild,
+    /// without needing the member to be in the guild's [`members`].
+    ///
+    /// The permissions are resolved the way Discord does: starting from the
+    /// `@everyone` role and the member's roles, the channel's `@everyone`
+    /// overwrite is applied, then the overwrites of the member's roles
+    /// together, and lastly the member's own overwrite.
+    ///
+    /// **Note**: The guild owner and members with the [Administrator]
+    /// permission implicitly have all permissions.
+    ///
+    /// # Examples
+    ///
+    /// Check whether a member may send messages before replying to them:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::prelude::*;
+    /// #
+    /// # fn check(guild: &Guild, msg: &Message, member: &Member) {
+    /// let permissions = guild.user_permissions_in(msg.channel_id, member);
+    ///
+    /// if permissions.send_messages() {
+    ///     let _ = msg.channel_id.say("You have permission to speak here.");
+    /// }
+    /// # }
+    /// #
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`Member`]: struct.Member.html
+    /// [`members`]: #structfield.members
+    /// [Administrator]: ../permissions/struct.Permissions.html#associatedconstant.ADMINISTRATOR
+    #[inline]
+    pub fn user_permissions_in<C>(&self, channel_id: C, member: &Member) -> Permissions
+        where C: Into<ChannelId> {
+        self._user_permissions_in(channel_id.into(), member)
+    }
+
+    fn _user_permissions_in(&self, channel_id: ChannelId, member: &Member) -> Permissions {
+        let user_id = member.user.read().id;
+
+        // The owner has all permissions in all cases.
+        if user_id == self.owner_id {
+            return Permissions::all();
+        }
+
         // Start by retrieving the @everyone role's permissions.
         let everyone = match self.roles.get(&RoleId(self.id.0)) {
             Some(everyone) => everyone,
@@ -1225,18 +1365,13 @@ impl Guild {
         // Create a base set of permissions, starting with `@everyone`s.
         let mut permissions = everyone.permissions;
 
-        let member = match self.members.get(&user_id) {
-            Some(member) => member,
-            None => return everyone.permissions,
-        };
-
         for &role in &member.roles {
             if let Some(role) = self.roles.get(&role) {
                 permissions |= role.permissions;
             } else {
                 warn!(
                     "(╯°□°）╯︵ ┻━┻ {} on {} has non-existent role {:?}",
-                    member.user.read().id,
+                    user_id,
                     self.id,
                     role
                 );
@@ -1261,41 +1396,37 @@ impl Guild {
                     | Permissions::USE_VAD);
             }
 
-            // Apply the permission overwrites for the channel for each of the
-            // overwrites that - first - applies to the member's roles, and then
-            // the member itself.
+            // Apply the permission overwrites for the channel: first the one
+            // for `@everyone`, then those of all of the member's roles at
+            // once, and lastly the one for the member itself.
             //
-            // First apply the denied permission overwrites for each, then apply
-            // the allowed.
+            // For each, first apply the denied permissions, then the allowed.
+            let everyone_kind = PermissionOverwriteType::Role(RoleId(self.id.0));
+
+            for overwrite in &channel.permission_overwrites {
+                if overwrite.kind == everyone_kind {
+                    permissions = (permissions & !overwrite.deny) | overwrite.allow;
+                }
+            }
 
-            let mut data = Vec::with_capacity(member.roles.len());
+            let mut role_deny = Permissions::empty();
+            let mut role_allow = Permissions::empty();
 
-            // Roles
             for overwrite in &channel.permission_overwrites {
                 if let PermissionOverwriteType::Role(role) = overwrite.kind {
-                    if role.0 != self.id.0 && !member.roles.contains(&role) {
-                        continue;
-                    }
-
-                    if let Some(role) = self.roles.get(&role) {
-                        data.push((role.position, overwrite.deny, overwrite.allow));
+                    if role.0 != self.id.0 && member.roles.contains(&role) {
+                        role_deny |= overwrite.deny;
+                        role_allow |= overwrite.allow;
                     }
                 }
             }
 
-            data.sort_by(|a, b| a.0.cmp(&b.0));
-
-            for overwrite in data {
-                permissions = (permissions & !overwrite.1) | overwrite.2;
-            }
+            permissions = (permissions & !role_deny) | role_allow;
 
-            // Member
             for overwrite in &channel.permission_overwrites {
-                if PermissionOverwriteType::Member(user_id) != overwrite.kind {
-                    continue;
+                if PermissionOverwriteType::Member(user_id) == overwrite.kind {
+                    permissions = (permissions & !overwrite.deny) | overwrite.allow;
                 }
-
-                permissions = (permissions & !overwrite.deny) | overwrite.allow;
             }
         } else {
             warn!(
@@ -1335,7 +1466,7 @@ impl Guild {
     }
 
     /// Retrieves the count of the number of [`Member`]s that would be pruned
-    /// with the number of given days.
+    /// with the number of given days, without removing them.
     ///
     /// See the documentation on [`GuildPrune`] for more information.
     ///
@@ -1346,7 +1477,11 @@ impl Guild {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have permission to perform bans.
     ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// within 1 and 30.
+    ///
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [`ModelError::PruneDaysAmount`]: ../error/enum.Error.html#variant.PruneDaysAmount
     /// [`GuildPrune`]: struct.GuildPrune.html
     /// [`Member`]: struct.Member.html
     /// [Kick Members]: ../permissions/struct.Permissions.html#associatedconstant.KICK_MEMBERS
@@ -1373,6 +1508,21 @@ impl Guild {
         self.id.reorder_channels(channels)
     }
 
+    /// Edits the positions of the guild's channels in a single request, and
+    /// optionally moves them into or out of categories.
+    ///
+    /// Refer to [`GuildId::edit_channel_positions`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// [`GuildId::edit_channel_positions`]: ../id/struct.GuildId.html#method.edit_channel_positions
+    /// [Manage Channels]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_CHANNELS
+    #[inline]
+    pub fn edit_channel_positions<F>(&self, f: F) -> Result<()>
+        where F: FnOnce(EditChannelPositions) -> EditChannelPositions {
+        self.id.edit_channel_positions(f)
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total
@@ -1428,7 +1578,13 @@ impl Guild {
         self.id.start_integration_sync(integration_id)
     }
 
-    /// Starts a prune of [`Member`]s.
+    /// Starts a prune of the [`Member`]s that have not been seen in the given
+    /// number of days, removing them from the guild.
+    ///
+    /// If `compute_count` is `true`, the number of removed members is returned.
+    /// Passing `false` avoids counting them, which is recommended for large
+    /// guilds, in which case `None` is returned. Use [`prune_count`] to only
+    /// find out how many members would be removed.
     ///
     /// See the documentation on [`GuildPrune`] for more information.
     ///
@@ -1439,11 +1595,16 @@ impl Guild {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have permission to perform bans.
     ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// within 1 and 30.
+    ///
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
+    /// [`ModelError::PruneDaysAmount`]: ../error/enum.Error.html#variant.PruneDaysAmount
     /// [`GuildPrune`]: struct.GuildPrune.html
     /// [`Member`]: struct.Member.html
+    /// [`prune_count`]: #method.prune_count
     /// [Kick Members]: ../permissions/struct.Permissions.html#associatedconstant.KICK_MEMBERS
-    pub fn start_prune(&self, days: u16) -> Result<GuildPrune> {
+    pub fn start_prune(&self, days: u16, compute_count: bool) -> Result<Option<GuildPrune>> {
         #[cfg(feature = "cache")]
         {
             let req = Permissions::KICK_MEMBERS;
@@ -1453,7 +1614,7 @@ impl Guild {
             }
         }
 
-        self.id.start_prune(days)
+        self.id.start_prune(days, compute_count)
     }
 
     /// Unbans the given [`User`] from the guild.
@@ -1481,13 +1642,14 @@ impl Guild {
         self.id.unban(user_id)
     }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieve's the guild's vanity invite code and its number of uses, if
+    /// it has one.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
-    pub fn vanity_url(&self) -> Result<String> {
+    pub fn vanity_url(&self) -> Result<Option<(String, u32)>> {
         self.id.vanity_url()
     }
 
@@ -1499,6 +1661,15 @@ impl Guild {
     #[inline]
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { self.id.webhooks() }
 
+    /// Retrieves the guild's public widget, containing its online member
+    /// count and some of its online members and voice channels.
+    ///
+    /// Refer to [`GuildId::widget`] for more information.
+    ///
+    /// [`GuildId::widget`]: ../id/struct.GuildId.html#method.widget
+    #[inline]
+    pub fn widget(&self) -> Result<GuildWidget> { self.id.widget() }
+
     /// Obtain a reference to a role by its name.
     ///
     /// **Note**: If two or more roles have the same name, obtained reference will be one of
@@ -2045,6 +2216,7 @@ mod test {
             let u = Arc::new(RwLock::new(gen_user()));
 
             Member {
+                communication_disabled_until: None,
                 deaf: false,
                 guild_id: GuildId(1),
                 joined_at: Some(dt),
@@ -2121,5 +2293,108 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        fn gen_role(id: u64, permissions: Permissions) -> Role {
+            Role {
+                id: RoleId(id),
+                colour: crate::utils::Colour::default(),
+                hoist: false,
+                icon: None,
+                managed: false,
+                mentionable: false,
+                name: "role".to_string(),
+                permissions,
+                position: id as i64,
+                unicode_emoji: None,
+            }
+        }
+
+        #[test]
+        fn user_permissions_in() {
+            let mut guild = gen();
+            let base = Permissions::READ_MESSAGES | Permissions::SEND_MESSAGES;
+            guild.roles.insert(RoleId(1), gen_role(1, base));
+            guild.roles.insert(RoleId(2), gen_role(2, Permissions::empty()));
+            guild.roles.insert(RoleId(3), gen_role(3, Permissions::empty()));
+            guild.roles.insert(RoleId(4), gen_role(4, Permissions::ADMINISTRATOR));
+
+            let overwrite = |kind, allow, deny| PermissionOverwrite { allow, deny, kind };
+            let channel = GuildChannel {
+                bitrate: None,
+                category_id: None,
+                guild_id: GuildId(1),
+                kind: ChannelType::Text,
+                id: ChannelId(5),
+                last_message_id: None,
+                last_pin_timestamp: None,
+                name: "a".to_string(),
+                permission_overwrites: vec![
+                    overwrite(
+                        PermissionOverwriteType::Role(RoleId(1)),
+                        Permissions::empty(),
+                        Permissions::SEND_MESSAGES,
+                    ),
+                    overwrite(
+                        PermissionOverwriteType::Role(RoleId(2)),
+                        Permissions::SEND_MESSAGES,
+                        Permissions::empty(),
+                    ),
+                    overwrite(
+                        PermissionOverwriteType::Role(RoleId(3)),
+                        Permissions::empty(),
+                        Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS,
+                    ),
+                    overwrite(
+                        PermissionOverwriteType::Member(UserId(211)),
+                        Permissions::ADD_REACTIONS,
+                        Permissions::empty(),
+                    ),
+                ],
+                position: 1,
+                topic: None,
+                user_limit: None,
+                nsfw: false,
+            };
+            guild.channels.insert(ChannelId(5), Arc::new(RwLock::new(channel)));
+
+            let mut member = gen_member();
+            member.user = Arc::new(RwLock::new(User {
+                id: UserId(211),
+                ..gen_user()
+            }));
+
+            // Without roles, only the `@everyone` and member overwrites apply.
+            assert_eq!(
+                guild.user_permissions_in(ChannelId(5), &member),
+                Permissions::READ_MESSAGES | Permissions::ADD_REACTIONS,
+            );
+
+            // Role overwrites are combined, with allows taking precedence, and
+            // the member's own overwrite is applied last.
+            member.roles = vec![RoleId(2), RoleId(3)];
+            assert_eq!(
+                guild.user_permissions_in(ChannelId(5), &member),
+                base | Permissions::ADD_REACTIONS,
+            );
+
+            member.roles.push(RoleId(4));
+            assert_eq!(guild.user_permissions_in(ChannelId(5), &member), Permissions::all());
+
+            assert_eq!(guild.user_permissions_in(ChannelId(5), &gen_member()), Permissions::all());
+        }
+
+        #[test]
+        fn prune_days_range() {
+            for &days in &[0, 31] {
+                assert!(match GuildId(1).prune_count(days) {
+                    Err(Error::Model(ModelError::PruneDaysAmount(amount))) => amount == days,
+                    _ => false,
+                });
+                assert!(match GuildId(1).start_prune(days, false) {
+                    Err(Error::Model(ModelError::PruneDaysAmount(amount))) => amount == days,
+                    _ => false,
+                });
+            }
+        }
     }
 }
@@ -35,6 +35,14 @@ pub struct PartialGuild {
 
 #[cfg(feature = "model")]
 impl PartialGuild {
+    /// Gets the guild's active threads.
+    ///
+    /// Refer to [`GuildId::active_threads`] for more information.
+    ///
+    /// [`GuildId::active_threads`]: ../id/struct.GuildId.html#method.active_threads
+    #[inline]
+    pub fn active_threads(&self) -> Result<Vec<GuildThread>> { self.id.active_threads() }
+
     /// Ban a [`User`] from the guild. All messages by the
     /// user within the last given number of days given will be deleted. This
     /// may be a range between `0` and `7`.
@@ -76,6 +84,19 @@ impl PartialGuild {
     #[inline]
     pub fn bans(&self) -> Result<Vec<Ban>> { self.id.bans() }
 
+    /// Gets the ban of a user in the guild, if they are banned.
+    ///
+    /// Refer to [`GuildId::get_ban`] for more information.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// [`GuildId::get_ban`]: ../id/struct.GuildId.html#method.get_ban
+    /// [Ban Members]: ../permissions/struct.Permissions.html#associatedconstant.BAN_MEMBERS
+    #[inline]
+    pub fn get_ban<U: Into<UserId>>(&self, user_id: U) -> Result<Option<Ban>> {
+        self.id.get_ban(user_id)
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// [`Guild`]: struct.Guild.html
@@ -269,6 +290,48 @@ impl PartialGuild {
         self.id.edit_member(user_id, f)
     }
 
+    /// Replaces the roles of a member of the guild with the given set of roles,
+    /// in a single request.
+    ///
+    /// Refer to [`GuildId::edit_member_roles`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`GuildId::edit_member_roles`]: ../id/struct.GuildId.html#method.edit_member_roles
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn edit_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self.id.edit_member_roles(user_id, role_ids)
+    }
+
+    /// Adds one or multiple roles to a member of the guild, in a single
+    /// request.
+    ///
+    /// Refer to [`GuildId::add_member_roles`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`GuildId::add_member_roles`]: ../id/struct.GuildId.html#method.add_member_roles
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn add_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self.id.add_member_roles(user_id, role_ids)
+    }
+
+    /// Removes one or multiple roles from a member of the guild, in a single
+    /// request.
+    ///
+    /// Refer to [`GuildId::remove_member_roles`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Roles] permission.
+    ///
+    /// [`GuildId::remove_member_roles`]: ../id/struct.GuildId.html#method.remove_member_roles
+    /// [Manage Roles]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_ROLES
+    #[inline]
+    pub fn remove_member_roles<U: Into<UserId>>(&self, user_id: U, role_ids: &[RoleId]) -> Result<()> {
+        self.id.remove_member_roles(user_id, role_ids)
+    }
+
     /// Edits the current user's nickname for the guild.
     ///
     /// Pass `None` to reset the nickname.
@@ -358,11 +421,17 @@ impl PartialGuild {
     }
 
     /// Gets the number of [`Member`]s that would be pruned with the given
-    /// number of days.
+    /// number of days, without removing them.
     ///
     /// Requires the [Kick Members] permission.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::PruneDaysAmount`] if the number of days is not
+    /// within 1 and 30.
+    ///
     /// [`Member`]: struct.Member.html
+    /// [`ModelError::PruneDaysAmount`]: ../error/enum.Error.html#variant.PruneDaysAmount
     /// [Kick Members]: ../permissions/struct.Permissions.html#associatedconstant.KICK_MEMBERS
     #[inline]
     pub fn prune_count(&self, days: u16) -> Result<GuildPrune> { self.id.prune_count(days) }
@@ -431,13 +500,14 @@ impl PartialGuild {
     #[inline]
     pub fn unban<U: Into<UserId>>(&self, user_id: U) -> Result<()> { self.id.unban(user_id) }
 
-    /// Retrieve's the guild's vanity URL.
+    /// Retrieve's the guild's vanity invite code and its number of uses, if
+    /// it has one.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     #[inline]
-    pub fn vanity_url(&self) -> Result<String> {
+    pub fn vanity_url(&self) -> Result<Option<(String, u32)>> {
         self.id.vanity_url()
     }
 
@@ -449,6 +519,15 @@ impl PartialGuild {
     #[inline]
     pub fn webhooks(&self) -> Result<Vec<Webhook>> { self.id.webhooks() }
 
+    /// Retrieves the guild's public widget, containing its online member
+    /// count and some of its online members and voice channels.
+    ///
+    /// Refer to [`GuildId::widget`] for more information.
+    ///
+    /// [`GuildId::widget`]: ../id/struct.GuildId.html#method.widget
+    #[inline]
+    pub fn widget(&self) -> Result<GuildWidget> { self.id.widget() }
+
     /// Obtain a reference to a role by its name.
     ///
     /// **Note**: If two or more roles have the same name, obtained reference will be one of
@@ -42,6 +42,11 @@ pub struct Role {
     /// [`Member`]: struct.Member.html
     /// [`position`]: #structfield.position
     pub hoist: bool,
+    /// The hash of the role's icon, if one has been set.
+    ///
+    /// **Note**: Only guilds with the `ROLE_ICONS` feature can set role icons.
+    #[serde(default)]
+    pub icon: Option<String>,
     /// Indicator of whether the role is managed by an integration service.
     pub managed: bool,
     /// Indicator of whether the role can be mentioned, similar to mentioning a
@@ -64,6 +69,11 @@ pub struct Role {
     ///
     /// The `@everyone` role is usually either `-1` or `0`.
     pub position: i64,
+    /// The unicode emoji displayed as the role's icon, if one has been set.
+    ///
+    /// **Note**: Only guilds with the `ROLE_ICONS` feature can set role icons.
+    #[serde(default)]
+    pub unicode_emoji: Option<String>,
 }
 
 #[cfg(feature = "model")]
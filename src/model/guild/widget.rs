@@ -0,0 +1,62 @@
+use crate::model::prelude::*;
+
+/// The public information of a guild shown by its widget, retrieved via
+/// [`GuildId::widget`].
+///
+/// **Note**: This is only available if the guild's widget is enabled.
+///
+/// [`GuildId::widget`]: struct.GuildId.html#method.widget
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidget {
+    /// The Id of the guild.
+    pub id: GuildId,
+    /// The name of the guild.
+    pub name: String,
+    /// The URL of an invite to the guild, if the widget has an invite channel
+    /// set.
+    pub instant_invite: Option<String>,
+    /// The voice channels of the guild which are visible to everyone.
+    #[serde(default)]
+    pub channels: Vec<GuildWidgetChannel>,
+    /// The online members of the guild, limited to 100 by Discord.
+    #[serde(default)]
+    pub members: Vec<GuildWidgetMember>,
+    /// The number of members of the guild which are online.
+    pub presence_count: u64,
+}
+
+/// A voice channel shown in a [`GuildWidget`].
+///
+/// [`GuildWidget`]: struct.GuildWidget.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidgetChannel {
+    /// The Id of the channel.
+    pub id: ChannelId,
+    /// The name of the channel.
+    pub name: String,
+    /// The position of the channel in the guild's channel list.
+    pub position: i64,
+}
+
+/// An online member shown in a [`GuildWidget`].
+///
+/// Members are anonymised, so the `id` is only an index into the widget's
+/// list of members rather than the Id of the user.
+///
+/// [`GuildWidget`]: struct.GuildWidget.html
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GuildWidgetMember {
+    /// The index of the member in the widget.
+    pub id: String,
+    /// The username of the member.
+    pub username: String,
+    /// The discriminator of the member, usually `"0000"` as members are
+    /// anonymised.
+    pub discriminator: String,
+    /// The avatar hash of the member, if they have one.
+    pub avatar: Option<String>,
+    /// A URL to the avatar of the member.
+    pub avatar_url: String,
+    /// The online status of the member.
+    pub status: OnlineStatus,
+}
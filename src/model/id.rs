@@ -1,20 +1,27 @@
 //! A collection of newtypes defining type-strong IDs.
 
-use chrono::NaiveDateTime;
+use chrono::{DateTime, TimeZone, Utc};
 use crate::internal::prelude::*;
 use serde::de::{Deserialize, Deserializer};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use super::utils::U64Visitor;
 
+/// The Unix timestamp, in milliseconds, that snowflakes are relative to.
+const DISCORD_EPOCH: i64 = 1_420_070_400_000;
+
 macro_rules! id_u64 {
     ($($name:ident;)*) => {
         $(
             impl $name {
                 /// Retrieves the time that the Id was created at.
-                pub fn created_at(&self) -> NaiveDateTime {
-                    let offset = (self.0 >> 22) / 1000;
-
-                    NaiveDateTime::from_timestamp(1_420_070_400 + offset as i64, 0)
+                ///
+                /// This is extracted from the snowflake, with millisecond
+                /// precision, relative to Discord's epoch of the first second
+                /// of 2015.
+                pub fn created_at(&self) -> DateTime<Utc> {
+                    let offset = self.0 >> 22;
+
+                    Utc.timestamp_millis_opt(DISCORD_EPOCH + offset as i64).unwrap()
                 }
 
                 /// Immutably borrow inner Id.
@@ -125,6 +132,11 @@ pub struct MessageId(pub u64);
 #[allow(derive_hash_xor_eq)]
 pub struct RoleId(pub u64);
 
+/// An identifier for a [`StageInstance`](../channel/struct.StageInstance.html).
+#[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[allow(derive_hash_xor_eq)]
+pub struct StageInstanceId(pub u64);
+
 /// An identifier for a User
 #[derive(Copy, Clone, Default, Debug, Eq, Hash, PartialOrd, Ord, Serialize)]
 #[allow(derive_hash_xor_eq)]
@@ -148,7 +160,21 @@ id_u64! {
     IntegrationId;
     MessageId;
     RoleId;
+    StageInstanceId;
     UserId;
     WebhookId;
     AuditLogEntryId;
 }
+
+#[cfg(test)]
+mod test {
+    use super::{GuildId, MessageId};
+
+    #[test]
+    fn test_created_at() {
+        let id = MessageId(175_928_847_299_117_063);
+        assert_eq!(id.created_at().to_rfc3339(), "2016-04-30T11:18:25.796+00:00");
+
+        assert_eq!(GuildId(0).created_at().timestamp(), 1_420_070_400);
+    }
+}
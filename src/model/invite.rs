@@ -100,6 +100,10 @@ impl Invite {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have the required [permission].
     ///
+    /// Returns an [`HttpError::UnknownInvite`] if the invite no longer exists,
+    /// such as when it has expired.
+    ///
+    /// [`HttpError::UnknownInvite`]: ../../http/enum.HttpError.html#variant.UnknownInvite
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [Manage Guild]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_GUILD
     /// [permission]: ../permissions/index.html
@@ -116,7 +120,31 @@ impl Invite {
         http::delete_invite(&self.code)
     }
 
-    /// Gets the information about an invite.
+    /// Gets the information about an invite, optionally including the
+    /// [approximate member count] of its guild if `stats` is `true`.
+    ///
+    /// This does not require authentication, nor the current user to be in
+    /// the guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`HttpError::UnknownInvite`] if the invite code does not
+    /// exist or has expired.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use serenity::model::invite::Invite;
+    ///
+    /// let invite = Invite::get("https://discord.gg/WxZumR", true).unwrap();
+    ///
+    /// if let (Some(guild), Some(members)) = (invite.guild, invite.approximate_member_count) {
+    ///     println!("This invite leads to {} ({} members)", guild.name, members);
+    /// }
+    /// ```
+    ///
+    /// [`HttpError::UnknownInvite`]: ../../http/enum.HttpError.html#variant.UnknownInvite
+    /// [approximate member count]: #structfield.approximate_member_count
     #[allow(unused_mut)]
     pub fn get(code: &str, stats: bool) -> Result<Invite> {
         let mut invite = code;
@@ -283,6 +311,10 @@ impl RichInvite {
     /// [`ModelError::InvalidPermissions`] if the current user does not have
     /// the required [permission].
     ///
+    /// Returns an [`HttpError::UnknownInvite`] if the invite no longer exists,
+    /// such as when it has expired.
+    ///
+    /// [`HttpError::UnknownInvite`]: ../../http/enum.HttpError.html#variant.UnknownInvite
     /// [`ModelError::InvalidPermissions`]: ../error/enum.Error.html#variant.InvalidPermissions
     /// [`Invite::delete`]: struct.Invite.html#method.delete
     /// [`http::delete_invite`]: ../../http/fn.delete_invite.html
@@ -343,3 +375,29 @@ impl RichInvite {
     /// ```
     pub fn url(&self) -> String { format!("https://discord.gg/{}", self.code) }
 }
+
+/// The type of target of an invite to a voice channel.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub enum InviteTargetType {
+    /// An invite to watch a user's stream in the channel.
+    Stream = 1,
+    /// An invite to an embedded application in the channel, such as an
+    /// activity.
+    EmbeddedApplication = 2,
+}
+
+enum_number!(
+    InviteTargetType {
+        Stream,
+        EmbeddedApplication,
+    }
+);
+
+impl InviteTargetType {
+    pub fn num(&self) -> u64 {
+        match *self {
+            InviteTargetType::Stream => 1,
+            InviteTargetType::EmbeddedApplication => 2,
+        }
+    }
+}
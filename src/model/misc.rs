@@ -13,6 +13,8 @@ use std::str::FromStr;
 use std::fmt;
 #[cfg(all(feature = "model", any(feature = "cache", feature = "utils")))]
 use crate::utils;
+#[cfg(feature = "model")]
+use std::fmt::{Debug, Formatter, Result as FmtResult};
 
 /// Allows something - such as a channel or role - to be mentioned in a message.
 pub trait Mentionable {
@@ -294,10 +296,130 @@ pub struct Maintenance {
     pub stop: String,
 }
 
+/// An iterator over items retrieved from the REST API one page at a time,
+/// such as a [`MembersIter`].
+///
+/// Each page is retrieved using a cursor taken from the previous page, such as
+/// the Id of its oldest message to retrieve the messages before it. Retrieval
+/// stops after a page that is not full, or after an error, which is yielded as
+/// the last item.
+///
+/// [`MembersIter`]: ../guild/type.MembersIter.html
+#[cfg(feature = "model")]
+pub struct Paginated<T, F = Box<dyn FnMut(Option<u64>) -> Result<Vec<T>> + Send>> {
+    buffer: Vec<T>,
+    cursor: Option<u64>,
+    done: bool,
+    fetch: F,
+    next_cursor: fn(&[T]) -> Option<u64>,
+    page_size: usize,
+}
+
+#[cfg(feature = "model")]
+impl<T, F: FnMut(Option<u64>) -> Result<Vec<T>>> Paginated<T, F> {
+    /// Creates an iterator retrieving pages of up to `page_size` items with
+    /// `fetch`, given the cursor that `next_cursor` took from the previous
+    /// page, or `None` for the first page.
+    pub(crate) fn new(page_size: usize, next_cursor: fn(&[T]) -> Option<u64>, fetch: F) -> Self {
+        Self {
+            buffer: Vec::new(),
+            cursor: None,
+            done: false,
+            fetch,
+            next_cursor,
+            page_size,
+        }
+    }
+
+    fn refresh(&mut self) -> Result<()> {
+        let mut page = (self.fetch)(self.cursor)?;
+
+        self.done = page.len() < self.page_size;
+
+        match (self.next_cursor)(&page) {
+            Some(cursor) => self.cursor = Some(cursor),
+            None => self.done = true,
+        }
+
+        // Reverse the page so that items can be popped off in order.
+        page.reverse();
+        self.buffer = page;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "model")]
+impl<T, F: FnMut(Option<u64>) -> Result<Vec<T>>> Iterator for Paginated<T, F> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.buffer.is_empty() && !self.done {
+            if let Err(why) = self.refresh() {
+                self.done = true;
+
+                return Some(Err(why));
+            }
+        }
+
+        self.buffer.pop().map(Ok)
+    }
+}
+
+#[cfg(feature = "model")]
+impl<T: Debug, F> Debug for Paginated<T, F> {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.debug_struct("Paginated")
+            .field("buffer", &self.buffer)
+            .field("cursor", &self.cursor)
+            .field("done", &self.done)
+            .field("page_size", &self.page_size)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::model::prelude::*;
 
+    #[cfg(feature = "model")]
+    #[test]
+    fn test_paginated() {
+        use crate::Error;
+        use std::cell::RefCell;
+
+        // Ids 250 down to 1, retrieved newest first in pages of 100.
+        let cursors = RefCell::new(Vec::new());
+        let iter = Paginated::new(100, |page: &[u64]| page.iter().cloned().min(), |before| {
+            cursors.borrow_mut().push(before);
+
+            let below = before.unwrap_or(251);
+
+            Ok((1..below).rev().take(100).collect())
+        });
+
+        let ids = iter.map(|id| id.unwrap()).collect::<Vec<_>>();
+        assert_eq!(ids, (1..=250).rev().collect::<Vec<_>>());
+        assert_eq!(*cursors.borrow(), vec![None, Some(151), Some(51)]);
+
+        // A full last page is followed by one more, empty, page.
+        cursors.borrow_mut().clear();
+        let iter = Paginated::new(2, |page: &[u64]| page.iter().cloned().min(), |before| {
+            cursors.borrow_mut().push(before);
+
+            Ok((1..before.unwrap_or(5)).rev().take(2).collect())
+        });
+        assert_eq!(iter.count(), 4);
+        assert_eq!(*cursors.borrow(), vec![None, Some(3), Some(1)]);
+
+        // An error is yielded once, ending the iteration.
+        let mut iter = Paginated::new(2, |page: &[u64]| page.first().cloned(), |_| {
+            Err::<Vec<u64>, _>(Error::Other("failed"))
+        });
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+
     #[test]
     fn test_formatters() {
         assert_eq!(ChannelId(1).to_string(), "1");
@@ -338,16 +460,19 @@ mod test {
                 managed: true,
                 require_colons: true,
                 roles: vec![],
+                user: None,
             };
             let role = Role {
                 id: RoleId(2),
                 colour: Colour::ROSEWATER,
                 hoist: false,
+                icon: None,
                 managed: false,
                 mentionable: false,
                 name: "fake role".to_string(),
                 permissions: Permissions::empty(),
                 position: 1,
+                unicode_emoji: None,
             };
             let user = User {
                 id: UserId(6),
@@ -357,6 +482,7 @@ mod test {
                 name: "fake".to_string(),
             };
             let member = Member {
+                communication_disabled_until: None,
                 deaf: false,
                 guild_id: GuildId(2),
                 joined_at: None,
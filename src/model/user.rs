@@ -12,9 +12,11 @@ use crate::CACHE;
 #[cfg(feature = "model")]
 use crate::builder::{CreateMessage, EditProfile};
 #[cfg(feature = "model")]
-use chrono::NaiveDateTime;
+use chrono::{DateTime, Utc};
 #[cfg(feature = "model")]
 use crate::http::{self, GuildPagination};
+#[cfg(feature = "model")]
+use hyper::status::StatusCode;
 #[cfg(all(feature = "cache", feature = "model"))]
 use parking_lot::RwLock;
 #[cfg(feature = "model")]
@@ -385,7 +387,7 @@ impl User {
 
     /// Retrieves the time that this user was created at.
     #[inline]
-    pub fn created_at(&self) -> NaiveDateTime { self.id.created_at() }
+    pub fn created_at(&self) -> DateTime<Utc> { self.id.created_at() }
 
     /// Returns the formatted URL to the user's default avatar URL.
     ///
@@ -461,24 +463,13 @@ impl User {
     /// Returns a [`ModelError::MessagingBot`] if the user being direct messaged
     /// is a bot user.
     ///
+    /// Returns a [`ModelError::DirectMessagesClosed`] if the user does not
+    /// accept direct messages from the current user.
+    ///
+    /// [`ModelError::DirectMessagesClosed`]: ../error/enum.Error.html#variant.DirectMessagesClosed
     /// [`ModelError::MessagingBot`]: ../error/enum.Error.html#variant.MessagingBot
     /// [`PrivateChannel`]: struct.PrivateChannel.html
     /// [`User::dm`]: struct.User.html#method.dm
-    // A tale with Clippy:
-    //
-    // A person named Clippy once asked you to unlock a box and take something
-    // from it, but you never re-locked it, so you'll die and the universe will
-    // implode because the box must remain locked unless you're there, and you
-    // can't just borrow that item from it and take it with you forever.
-    //
-    // Instead what you do is unlock the box, take the item out of it, make a
-    // copy of said item, and then re-lock the box, and take your copy of the
-    // item with you.
-    //
-    // The universe is still fine, and nothing implodes.
-    //
-    // (AKA: Clippy is wrong and so we have to mark as allowing this lint.)
-    #[allow(let_and_return)]
     #[cfg(feature = "builder")]
     pub fn direct_message<F>(&self, f: F) -> Result<Message>
         where F: FnOnce(CreateMessage) -> CreateMessage {
@@ -486,39 +477,7 @@ impl User {
             return Err(Error::Model(ModelError::MessagingBot));
         }
 
-        let private_channel_id = feature_cache! {
-            {
-                let finding = {
-                    let cache = CACHE.read();
-
-                    let finding = cache.private_channels
-                        .values()
-                        .map(|ch| ch.read())
-                        .find(|ch| ch.recipient.read().id == self.id)
-                        .map(|ch| ch.id);
-
-                    finding
-                };
-
-                if let Some(finding) = finding {
-                    finding
-                } else {
-                    let map = json!({
-                        "recipient_id": self.id.0,
-                    });
-
-                    http::create_private_channel(&map)?.id
-                }
-            } else {
-                let map = json!({
-                    "recipient_id": self.id.0,
-                });
-
-                http::create_private_channel(&map)?.id
-            }
-        };
-
-        private_channel_id.send_message(f)
+        self.id.direct_message(f)
     }
 
     /// This is an alias of [direct_message].
@@ -807,6 +766,102 @@ impl UserId {
         http::create_private_channel(&map)
     }
 
+    /// Sends a message to the user through a direct message channel, creating
+    /// the channel if needed.
+    ///
+    /// The direct message channel is taken from the cache if one is present,
+    /// so that it doesn't need to be created again.
+    ///
+    /// Unlike [`User::direct_message`], this does not check whether the user
+    /// is a bot, as that is not known from the Id alone.
+    ///
+    /// # Examples
+    ///
+    /// DM a user, falling back to a reply in the channel if their DMs are
+    /// closed:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{ChannelId, UserId};
+    /// # let (channel_id, user_id) = (ChannelId(1), UserId(2));
+    /// #
+    /// use serenity::Error;
+    /// use serenity::model::ModelError;
+    ///
+    /// match user_id.direct_message(|m| m.content("Hello!")) {
+    ///     Err(Error::Model(ModelError::DirectMessagesClosed)) => {
+    ///         let _ = channel_id.say("I can't DM you, so here you go: Hello!");
+    ///     },
+    ///     other => println!("Sent: {:?}", other.is_ok()),
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DirectMessagesClosed`] if the user does not
+    /// accept direct messages from the current user.
+    ///
+    /// [`ModelError::DirectMessagesClosed`]: ../error/enum.Error.html#variant.DirectMessagesClosed
+    /// [`User::direct_message`]: ../user/struct.User.html#method.direct_message
+    // A tale with Clippy:
+    //
+    // A person named Clippy once asked you to unlock a box and take something
+    // from it, but you never re-locked it, so you'll die and the universe will
+    // implode because the box must remain locked unless you're there, and you
+    // can't just borrow that item from it and take it with you forever.
+    //
+    // Instead what you do is unlock the box, take the item out of it, make a
+    // copy of said item, and then re-lock the box, and take your copy of the
+    // item with you.
+    //
+    // The universe is still fine, and nothing implodes.
+    //
+    // (AKA: Clippy is wrong and so we have to mark as allowing this lint.)
+    #[allow(let_and_return)]
+    #[cfg(feature = "builder")]
+    pub fn direct_message<F>(&self, f: F) -> Result<Message>
+        where F: FnOnce(CreateMessage) -> CreateMessage {
+        let private_channel_id = feature_cache! {
+            {
+                let finding = {
+                    let cache = CACHE.read();
+
+                    let finding = cache.private_channels
+                        .values()
+                        .map(|ch| ch.read())
+                        .find(|ch| ch.recipient.read().id == *self)
+                        .map(|ch| ch.id);
+
+                    finding
+                };
+
+                if let Some(finding) = finding {
+                    finding
+                } else {
+                    let map = json!({
+                        "recipient_id": self.0,
+                    });
+
+                    http::create_private_channel(&map)?.id
+                }
+            } else {
+                let map = json!({
+                    "recipient_id": self.0,
+                });
+
+                http::create_private_channel(&map)?.id
+            }
+        };
+
+        // Discord responds with the JSON error code 50007 when the user does
+        // not accept direct messages from the current user.
+        http::map_error_code(
+            private_channel_id.send_message(f),
+            StatusCode::Forbidden,
+            50007,
+            Error::Model(ModelError::DirectMessagesClosed),
+        )
+    }
+
     /// Search the cache for the user with the Id.
     #[cfg(feature = "cache")]
     #[deprecated(since = "0.5.8", note = "Use the `to_user_cached`-method instead.")]
@@ -4,13 +4,14 @@ use super::{
     id::{
         ChannelId,
         GuildId,
+        MessageId,
         WebhookId
     },
     user::User
 };
 
 #[cfg(feature = "model")]
-use crate::builder::ExecuteWebhook;
+use crate::builder::{self, EditWebhookMessage, ExecuteWebhook};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -36,6 +37,10 @@ pub struct Webhook {
     /// [`ExecuteWebhook::avatar`]: ../../builder/struct.ExecuteWebhook.html#method.avatar
     pub avatar: Option<String>,
     /// The Id of the channel that owns the webhook.
+    ///
+    /// This can be modified via [`Webhook::edit_channel`].
+    ///
+    /// [`Webhook::edit_channel`]: #method.edit_channel
     pub channel_id: ChannelId,
     /// The Id of the guild that owns the webhook.
     pub guild_id: Option<GuildId>,
@@ -64,6 +69,17 @@ impl Webhook {
     #[inline]
     pub fn delete(&self) -> Result<()> { http::delete_webhook_with_token(self.id.0, &self.token) }
 
+    /// Deletes a message previously sent by the webhook.
+    ///
+    /// As this calls the [`http::delete_webhook_message`] function,
+    /// authentication is not required.
+    ///
+    /// [`http::delete_webhook_message`]: ../../http/fn.delete_webhook_message.html
+    #[inline]
+    pub fn delete_message<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        delete_message(self.id, &self.token, message_id.into())
+    }
+
     ///
     /// Edits the webhook in-place. All fields are optional.
     ///
@@ -75,6 +91,11 @@ impl Webhook {
     /// As this calls the [`http::edit_webhook_with_token`] function,
     /// authentication is not required.
     ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidWebhookUsername`] if the name is blank,
+    /// over 80 unicode code points long, or contains "clyde".
+    ///
     /// # Examples
     ///
     /// Editing a webhook's name:
@@ -108,6 +129,7 @@ impl Webhook {
     /// let _ = webhook.edit(None, Some(&image)).expect("Error editing");
     /// ```
     ///
+    /// [`ModelError::InvalidWebhookUsername`]: ../error/enum.Error.html#variant.InvalidWebhookUsername
     /// [`http::edit_webhook`]: ../../http/fn.edit_webhook.html
     /// [`http::edit_webhook_with_token`]: ../../http/fn.edit_webhook_with_token.html
     pub fn edit(&mut self, name: Option<&str>, avatar: Option<&str>) -> Result<()> {
@@ -129,7 +151,9 @@ impl Webhook {
         }
 
         if let Some(name) = name {
-            map.insert("name".to_string(), Value::String(name.to_string()));
+            builder::validate_username(name)?;
+
+            map.insert("name".to_string(), Value::String(name.trim().to_string()));
         }
 
         match http::edit_webhook_with_token(self.id.0, &self.token, &map) {
@@ -142,6 +166,95 @@ impl Webhook {
         }
     }
 
+    /// Edits a message previously sent by the webhook, with the fields set via
+    /// the given builder.
+    ///
+    /// Refer to the documentation for [`EditWebhookMessage`] for all of the
+    /// fields that can be edited.
+    ///
+    /// As this calls the [`http::edit_webhook_message`] function,
+    /// authentication is not required.
+    ///
+    /// # Examples
+    ///
+    /// Execute a webhook, waiting for the sent message, and then edit its
+    /// content:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http;
+    ///
+    /// let id = 245037420704169985;
+    /// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    ///
+    /// let webhook = http::get_webhook_with_token(id, token)
+    ///     .expect("valid webhook");
+    ///
+    /// let message = webhook.execute(true, |w| w.content("Deploying..."))
+    ///     .expect("Error executing")
+    ///     .expect("Expected a message");
+    ///
+    /// let _ = webhook.edit_message(message.id, |m| m.content("Deployed!"))
+    ///     .expect("Error editing");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyEmbeds`] if more than 10 embeds are set.
+    ///
+    /// [`EditWebhookMessage`]: ../../builder/struct.EditWebhookMessage.html
+    /// [`ModelError::TooManyEmbeds`]: ../error/enum.Error.html#variant.TooManyEmbeds
+    /// [`http::edit_webhook_message`]: ../../http/fn.edit_webhook_message.html
+    pub fn edit_message<F, M>(&self, message_id: M, f: F) -> Result<Message>
+        where F: FnOnce(EditWebhookMessage) -> EditWebhookMessage,
+              M: Into<MessageId> {
+        edit_message(self.id, &self.token, message_id.into(), f)
+    }
+
+    /// Moves the webhook to another channel in the same guild, updating it
+    /// in-place.
+    ///
+    /// Unlike [`edit`] and [`execute`], the webhook's token is not sufficient
+    /// for this, as it calls the [`http::edit_webhook`] function. A bot token
+    /// must be set, and the current user must have the [Manage Webhooks]
+    /// permission.
+    ///
+    /// # Examples
+    ///
+    /// Move a relay webhook to another channel:
+    ///
+    /// ```rust,no_run
+    /// use serenity::http;
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let id = 245037420704169985;
+    /// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    ///
+    /// let mut webhook = http::get_webhook_with_token(id, token)
+    ///     .expect("valid webhook");
+    ///
+    /// let _ = webhook.edit_channel(ChannelId(81384788765712384))
+    ///     .expect("Error moving webhook");
+    /// ```
+    ///
+    /// [`edit`]: #method.edit
+    /// [`execute`]: #method.execute
+    /// [`http::edit_webhook`]: ../../http/fn.edit_webhook.html
+    /// [Manage Webhooks]: ../permissions/struct.Permissions.html#associatedconstant.MANAGE_WEBHOOKS
+    #[inline]
+    pub fn edit_channel<C: Into<ChannelId>>(&mut self, channel_id: C) -> Result<()> {
+        self._edit_channel(channel_id.into())
+    }
+
+    fn _edit_channel(&mut self, channel_id: ChannelId) -> Result<()> {
+        let map = json!({
+            "channel_id": channel_id,
+        });
+
+        *self = http::edit_webhook(self.id.0, &map)?;
+
+        Ok(())
+    }
+
     /// Executes a webhook with the fields set via the given builder.
     ///
     /// The builder provides a method of setting only the fields you need,
@@ -189,14 +302,21 @@ impl Webhook {
     ///     .embeds(vec![embed]))
     ///     .expect("Error executing");
     /// ```
-    #[inline]
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidWebhookUsername`] if the username is
+    /// overridden with one that Discord would reject.
+    ///
+    /// Returns a [`ModelError::TooManyEmbeds`] if more than 10 embeds are set.
+    ///
+    /// [`ModelError::InvalidWebhookUsername`]: ../error/enum.Error.html#variant.InvalidWebhookUsername
+    /// [`ModelError::TooManyEmbeds`]: ../error/enum.Error.html#variant.TooManyEmbeds
     pub fn execute<F: FnOnce(ExecuteWebhook) -> ExecuteWebhook>(&self,
                                                                 wait: bool,
                                                                 f: F)
                                                                 -> Result<Option<Message>> {
-        let map = utils::vecmap_to_json_map(f(ExecuteWebhook::default()).0);
-
-        http::execute_webhook(self.id.0, &self.token, wait, &map)
+        execute(self.id, &self.token, wait, f)
     }
 
     /// Retrieves the latest information about the webhook, editing the
@@ -216,6 +336,151 @@ impl Webhook {
             Err(why) => Err(why),
         }
     }
+
+    /// Creates a [`WebhookClient`] for this webhook, copying its Id and
+    /// token.
+    ///
+    /// [`WebhookClient`]: struct.WebhookClient.html
+    #[inline]
+    pub fn client(&self) -> WebhookClient { WebhookClient::new(self.id, &*self.token) }
+}
+
+/// A lightweight handle for using a webhook through its Id and token alone.
+///
+/// Unlike a [`Webhook`], no webhook object needs to be retrieved first, and
+/// nothing is cached: each method performs exactly one request against the
+/// webhook's token endpoint. This makes it suitable for relays executing the
+/// same webhook at a high rate.
+///
+/// As only the token is used, authentication is not required.
+///
+/// # Examples
+///
+/// Construct a client once and execute its webhook repeatedly:
+///
+/// ```rust,no_run
+/// use serenity::model::webhook::WebhookClient;
+///
+/// let id = 245037420704169985;
+/// let token = "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+///
+/// let client = WebhookClient::new(id, token);
+///
+/// for line in &["first", "second", "third"] {
+///     let _ = client.execute(false, |w| w.content(line)).expect("Error executing");
+/// }
+/// ```
+///
+/// [`Webhook`]: struct.Webhook.html
+#[cfg(feature = "model")]
+#[derive(Clone, Debug)]
+pub struct WebhookClient {
+    /// The Id of the webhook.
+    pub id: WebhookId,
+    /// The webhook's secure token.
+    pub token: String,
+}
+
+#[cfg(feature = "model")]
+impl WebhookClient {
+    /// Creates a client for the webhook with the given Id and token.
+    pub fn new<I, S>(id: I, token: S) -> Self
+        where I: Into<WebhookId>, S: Into<String> {
+        WebhookClient {
+            id: id.into(),
+            token: token.into(),
+        }
+    }
+
+    /// Deletes a message previously sent by the webhook.
+    ///
+    /// Refer to [`Webhook::delete_message`] for more information.
+    ///
+    /// [`Webhook::delete_message`]: struct.Webhook.html#method.delete_message
+    #[inline]
+    pub fn delete_message<M: Into<MessageId>>(&self, message_id: M) -> Result<()> {
+        delete_message(self.id, &self.token, message_id.into())
+    }
+
+    /// Edits a message previously sent by the webhook.
+    ///
+    /// Refer to [`Webhook::edit_message`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyEmbeds`] if more than 10 embeds are set.
+    ///
+    /// [`ModelError::TooManyEmbeds`]: ../error/enum.Error.html#variant.TooManyEmbeds
+    /// [`Webhook::edit_message`]: struct.Webhook.html#method.edit_message
+    #[inline]
+    pub fn edit_message<F, M>(&self, message_id: M, f: F) -> Result<Message>
+        where F: FnOnce(EditWebhookMessage) -> EditWebhookMessage,
+              M: Into<MessageId> {
+        edit_message(self.id, &self.token, message_id.into(), f)
+    }
+
+    /// Executes the webhook with the fields set via the given builder.
+    ///
+    /// Refer to [`Webhook::execute`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidWebhookUsername`] if the username is
+    /// overridden with one that Discord would reject.
+    ///
+    /// Returns a [`ModelError::TooManyEmbeds`] if more than 10 embeds are set.
+    ///
+    /// [`ModelError::InvalidWebhookUsername`]: ../error/enum.Error.html#variant.InvalidWebhookUsername
+    /// [`ModelError::TooManyEmbeds`]: ../error/enum.Error.html#variant.TooManyEmbeds
+    /// [`Webhook::execute`]: struct.Webhook.html#method.execute
+    #[inline]
+    pub fn execute<F>(&self, wait: bool, f: F) -> Result<Option<Message>>
+        where F: FnOnce(ExecuteWebhook) -> ExecuteWebhook {
+        execute(self.id, &self.token, wait, f)
+    }
+
+    /// Retrieves the webhook object of the client.
+    ///
+    /// As this calls the [`http::get_webhook_with_token`] function,
+    /// authentication is not required.
+    ///
+    /// [`http::get_webhook_with_token`]: ../../http/fn.get_webhook_with_token.html
+    #[inline]
+    pub fn to_webhook(&self) -> Result<Webhook> {
+        http::get_webhook_with_token(self.id.0, &self.token)
+    }
+}
+
+#[cfg(feature = "model")]
+impl<'a> From<&'a Webhook> for WebhookClient {
+    fn from(webhook: &'a Webhook) -> Self { webhook.client() }
+}
+
+#[cfg(feature = "model")]
+fn delete_message(id: WebhookId, token: &str, message_id: MessageId) -> Result<()> {
+    http::delete_webhook_message(id.0, token, message_id.0)
+}
+
+#[cfg(feature = "model")]
+fn edit_message<F>(id: WebhookId, token: &str, message_id: MessageId, f: F) -> Result<Message>
+    where F: FnOnce(EditWebhookMessage) -> EditWebhookMessage {
+    let edit = f(EditWebhookMessage::default());
+    edit.validate()?;
+
+    let map = utils::vecmap_to_json_map(edit.0);
+
+    http::edit_webhook_message(id.0, token, message_id.0, &map)
+}
+
+#[cfg(feature = "model")]
+fn execute<F>(id: WebhookId, token: &str, wait: bool, f: F) -> Result<Option<Message>>
+    where F: FnOnce(ExecuteWebhook) -> ExecuteWebhook {
+    let execute = f(ExecuteWebhook::default());
+    execute.validate()?;
+
+    let map = utils::vecmap_to_json_map(execute.0);
+
+    http::execute_webhook(id.0, token, wait, &map)
 }
 
 #[cfg(feature = "model")]
@@ -41,12 +41,14 @@ macro_rules! colour {
 /// # let role = Role {
 /// #     colour: Colour::BLURPLE,
 /// #     hoist: false,
+/// #     icon: None,
 /// #     id: RoleId(1),
 /// #     managed: false,
 /// #     mentionable: false,
 /// #     name: "test".to_string(),
 /// #     permissions: permissions::PRESET_GENERAL,
 /// #     position: 7,
+/// #     unicode_emoji: None,
 /// # };
 /// #
 /// use serenity::utils::Colour;
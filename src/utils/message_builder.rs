@@ -30,6 +30,7 @@ use std::{
 /// #     managed: false,
 /// #     require_colons: true,
 /// #     roles: vec![],
+/// #     user: None,
 /// # };
 /// #
 /// use serenity::utils::MessageBuilder;
@@ -161,6 +162,7 @@ impl MessageBuilder {
     ///     name: "smugAnimeFace".to_string(),
     ///     require_colons: true,
     ///     roles: vec![],
+    ///     user: None,
     /// };
     ///
     /// let message = MessageBuilder::new()
@@ -1247,6 +1249,7 @@ mod test {
                 managed: false,
                 require_colons: true,
                 roles: vec![],
+                user: None,
             })
             .build();
         let content_mentions = MessageBuilder::new()
@@ -186,6 +186,7 @@ pub fn parse_invite(code: &str) -> &str {
 ///
 /// assert!(parse_username("<@1149413154aa17899012").is_none());
 /// assert!(parse_username("<@!11494131541789a90b1c2").is_none());
+/// assert!(parse_username("<@114941315417899012").is_none());
 /// ```
 ///
 /// [`User`]: ../model/user/struct.User.html
@@ -194,6 +195,10 @@ pub fn parse_username(mention: &str) -> Option<u64> {
         return None;
     }
 
+    if !mention.ends_with('>') {
+        return None;
+    }
+
     if mention.starts_with("<@!") {
         let len = mention.len() - 1;
         mention[3..len].parse::<u64>().ok()
@@ -968,6 +973,7 @@ mod test {
         };
 
         let member = Member {
+            communication_disabled_until: None,
             deaf: false,
             guild_id: guild.id,
             joined_at: None,
@@ -981,11 +987,13 @@ mod test {
             id: RoleId(333333333333333333),
             colour: Colour::ORANGE,
             hoist: true,
+            icon: None,
             managed: false,
             mentionable: true,
             name: "ferris-club-member".to_string(),
             permissions: Permissions::all(),
             position: 0,
+            unicode_emoji: None,
         };
 
         let channel = GuildChannel {
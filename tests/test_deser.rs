@@ -41,6 +41,12 @@ fn emoji_animated() {
     p!(Emoji, "emoji_animated");
 }
 
+#[test]
+fn followed_channel() {
+    let followed = p!(FollowedChannel, "followed_channel_1");
+    assert_eq!(followed.webhook_id, WebhookId(623525868764430346));
+}
+
 // A game with null type.
 #[test]
 fn game() {
@@ -63,6 +69,21 @@ fn guild_channel_1_rest() {
     p!(GuildChannel, "guild_channel_rest_1");
 }
 
+#[test]
+fn guild_thread() {
+    let thread = p!(GuildThread, "guild_thread_1");
+    assert_eq!(thread.kind, ChannelType::PublicThread);
+    assert_eq!(thread.thread_metadata.auto_archive_duration, 1440);
+    assert!(thread.member.is_none());
+}
+
+#[test]
+fn guild_widget() {
+    let widget = p!(GuildWidget, "guild_widget_1");
+    assert_eq!(widget.presence_count, 1);
+    assert_eq!(widget.members[0].status, OnlineStatus::Online);
+}
+
 // A guild that has some application ID.
 #[test]
 fn guild_some_application_id() {
@@ -159,6 +180,13 @@ fn ready() {
     p!(ReadyEvent, "ready_1");
 }
 
+#[test]
+fn stage_instance() {
+    let stage = p!(StageInstance, "stage_instance_1");
+    assert_eq!(stage.channel_id, ChannelId(733488538393510049));
+    assert_eq!(stage.privacy_level, StagePrivacyLevel::GuildOnly);
+}
+
 #[test]
 fn typing_start() {
     p!(TypingStartEvent, "typing_start_1");
@@ -216,3 +244,13 @@ fn decode_footer_deser() {
 
     p!(Message, "message_footer_2");
 }
+
+#[test]
+fn bot_gateway() {
+    let gateway = p!(BotGateway, "bot_gateway_1");
+    assert_eq!(gateway.shards, 9);
+    assert_eq!(gateway.session_start_limit.max_concurrency, 16);
+
+    let gateway = p!(BotGateway, "bot_gateway_2");
+    assert_eq!(gateway.session_start_limit.max_concurrency, 1);
+}